@@ -0,0 +1,450 @@
+//! Key-committing AEADs.
+//!
+//! Common AEAD algorithms such as AES-GCM and ChaCha20Poly1305 aren't
+//! key-committing: it's possible to construct a ciphertext which decrypts
+//! successfully under more than one key. This enables attacks on protocols
+//! which implicitly assume otherwise, e.g. password-based encryption or
+//! message franking.
+//!
+//! The [`CommittingAead`] marker trait identifies algorithms which are
+//! key-committing. When the `committing` feature is enabled, the following
+//! generic transforms from [Efficient Schemes for Committing Authenticated
+//! Encryption][1] are provided, which use a collision-resistant hash function
+//! as a committing PRF:
+//!
+//! - [`PaddedCommitting`]: the UtC ("Universal-to-Committing") transform,
+//!   which makes any AEAD key-committing (CMT-1).
+//! - [`HashThenEncrypt`]: the HtE ("Hash-then-Encrypt") transform, which
+//!   turns a key-committing AEAD into one which also commits to the nonce
+//!   and associated data (CMT-4).
+//!
+//! [1]: https://eprint.iacr.org/2022/268
+
+use crate::AeadCore;
+
+#[cfg(feature = "committing")]
+use {
+    crate::{AeadInPlace, Error, Key, KeyInit, KeySizeUser, Nonce, Result, Tag},
+    core::{fmt, marker::PhantomData, ops::Add},
+    digest::{Digest, Output, OutputSizeUser},
+    generic_array::{
+        typenum::{Sum, Unsigned},
+        ArrayLength,
+    },
+    subtle::ConstantTimeEq,
+    zeroize::Zeroize,
+};
+
+/// Label of the commitment computed by [`PaddedCommitting`].
+#[cfg(feature = "committing")]
+const COMMITMENT_LABEL: u8 = 0x00;
+
+/// Label of the subkeys derived by [`PaddedCommitting`].
+#[cfg(feature = "committing")]
+const UTC_SUBKEY_LABEL: u8 = 0x01;
+
+/// Label of the subkeys derived by [`HashThenEncrypt`].
+#[cfg(feature = "committing")]
+const HTE_SUBKEY_LABEL: u8 = 0x02;
+
+/// Implement the traits shared by the committing wrappers, which store the
+/// master key and zeroize it on drop.
+#[cfg(feature = "committing")]
+macro_rules! impl_committing_wrapper {
+    ($name:ident) => {
+        impl<A: KeySizeUser, D> KeySizeUser for $name<A, D> {
+            type KeySize = A::KeySize;
+        }
+
+        impl<A: KeySizeUser, D> KeyInit for $name<A, D> {
+            fn new(key: &Key<Self>) -> Self {
+                Self {
+                    key: key.clone(),
+                    digest: PhantomData,
+                }
+            }
+        }
+
+        impl<A: KeySizeUser, D> Clone for $name<A, D> {
+            fn clone(&self) -> Self {
+                Self {
+                    key: self.key.clone(),
+                    digest: PhantomData,
+                }
+            }
+        }
+
+        impl<A: KeySizeUser, D> fmt::Debug for $name<A, D> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.debug_struct(stringify!($name)).finish_non_exhaustive()
+            }
+        }
+
+        impl<A: KeySizeUser, D> Drop for $name<A, D> {
+            fn drop(&mut self) {
+                self.key.as_mut_slice().zeroize();
+            }
+        }
+    };
+}
+
+/// Marker trait for key-committing AEAD algorithms, for which it's
+/// infeasible to find a ciphertext which decrypts successfully under more
+/// than one key.
+pub trait CommittingAead: AeadCore {}
+
+/// Key-committing wrapper for any AEAD algorithm `A`, implementing the UtC
+/// transform using the hash function `D`.
+///
+/// For every message, a commitment `P = D(K || N || 0x00)` to the key `K` and
+/// nonce `N` is computed along with a subkey `L` derived from `D(K || N ||
+/// 0x01 || i)`, and the message is encrypted under `L` using `A`. The
+/// commitment is prepended to the tag of `A`, so the ciphertext is
+/// `D::OutputSize` bytes longer than that of the underlying AEAD, and is
+/// incompatible with it.
+///
+/// Decryption checks the commitment before decrypting anything, which binds
+/// the ciphertext to a single key as long as `D` is collision-resistant.
+/// `D` must be a Merkle–Damgård or sponge hash function with an output of
+/// at least 256 bits, e.g. SHA-256.
+#[cfg(feature = "committing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "committing")))]
+pub struct PaddedCommitting<A: KeySizeUser, D> {
+    /// Master key, from which the per-message subkeys are derived.
+    key: Key<A>,
+
+    /// Hash function used as a committing PRF.
+    digest: PhantomData<D>,
+}
+
+#[cfg(feature = "committing")]
+impl<A, D> PaddedCommitting<A, D>
+where
+    A: AeadInPlace + KeyInit,
+    D: Digest,
+{
+    /// Compute the commitment to the key and nonce, and the AEAD instance
+    /// for the subkey used to encrypt a message with the given nonce.
+    fn derive(&self, nonce: &Nonce<A>) -> (Output<D>, A) {
+        let commitment = D::new()
+            .chain_update(&self.key)
+            .chain_update(nonce)
+            .chain_update([COMMITMENT_LABEL])
+            .finalize();
+
+        let aead = derive_aead::<A, D>(&self.key, &[nonce], UTC_SUBKEY_LABEL);
+        (commitment, aead)
+    }
+}
+
+#[cfg(feature = "committing")]
+impl<A, D> AeadCore for PaddedCommitting<A, D>
+where
+    A: AeadInPlace + KeyInit,
+    D: OutputSizeUser,
+    D::OutputSize: Add<A::TagSize>,
+    Sum<D::OutputSize, A::TagSize>: ArrayLength<u8>,
+{
+    type NonceSize = A::NonceSize;
+    type TagSize = Sum<D::OutputSize, A::TagSize>;
+    type CiphertextOverhead = A::CiphertextOverhead;
+}
+
+#[cfg(feature = "committing")]
+impl<A, D> AeadInPlace for PaddedCommitting<A, D>
+where
+    A: AeadInPlace + KeyInit,
+    D: Digest,
+    D::OutputSize: Add<A::TagSize>,
+    Sum<D::OutputSize, A::TagSize>: ArrayLength<u8>,
+{
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<Self>> {
+        let (commitment, aead) = self.derive(nonce);
+        let inner_tag = aead.encrypt_in_place_detached(nonce, associated_data, buffer)?;
+
+        let mut tag = Tag::<Self>::default();
+        let (tag_commitment, tag_inner) = tag.split_at_mut(D::OutputSize::USIZE);
+        tag_commitment.copy_from_slice(&commitment);
+        tag_inner.copy_from_slice(&inner_tag);
+        Ok(tag)
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<Self>,
+    ) -> Result<()> {
+        let (commitment, aead) = self.derive(nonce);
+        let (tag_commitment, tag_inner) = tag.split_at(D::OutputSize::USIZE);
+
+        // Reject ciphertexts committing to another key before decrypting them
+        if !bool::from(commitment.as_slice().ct_eq(tag_commitment)) {
+            return Err(Error);
+        }
+
+        aead.decrypt_in_place_detached(
+            nonce,
+            associated_data,
+            buffer,
+            Tag::<A>::from_slice(tag_inner),
+        )
+    }
+}
+
+#[cfg(feature = "committing")]
+impl<A, D> CommittingAead for PaddedCommitting<A, D>
+where
+    A: AeadInPlace + KeyInit,
+    D: Digest,
+    D::OutputSize: Add<A::TagSize>,
+    Sum<D::OutputSize, A::TagSize>: ArrayLength<u8>,
+{
+}
+
+#[cfg(feature = "committing")]
+impl_committing_wrapper!(PaddedCommitting);
+
+/// Wrapper for a key-committing AEAD algorithm `A` which also commits to
+/// the nonce and associated data, implementing the HtE transform using the
+/// hash function `D`.
+///
+/// Every message is encrypted using `A` under a subkey derived from
+/// `D(K || N || AD || len(AD) || 0x02 || i)`, where `K` is the key, `N` the
+/// nonce, and `AD` the associated data. The ciphertext has the same size as
+/// that of the underlying AEAD, but is incompatible with it.
+///
+/// This only provides commitment if `A` is key-committing: use
+/// `HashThenEncrypt<PaddedCommitting<A, D>, D>` to obtain full commitment
+/// for an AEAD algorithm `A` which isn't. See [`PaddedCommitting`] regarding
+/// the requirements for `D`.
+#[cfg(feature = "committing")]
+#[cfg_attr(docsrs, doc(cfg(feature = "committing")))]
+pub struct HashThenEncrypt<A: KeySizeUser, D> {
+    /// Master key, from which the per-message subkeys are derived.
+    key: Key<A>,
+
+    /// Hash function used as a committing PRF.
+    digest: PhantomData<D>,
+}
+
+#[cfg(feature = "committing")]
+impl<A, D> HashThenEncrypt<A, D>
+where
+    A: AeadInPlace + KeyInit,
+    D: Digest,
+{
+    /// Compute the AEAD instance for the subkey used to encrypt a message
+    /// with the given nonce and associated data.
+    fn derive(&self, nonce: &Nonce<A>, associated_data: &[u8]) -> A {
+        let ad_len = (associated_data.len() as u64).to_le_bytes();
+        derive_aead::<A, D>(
+            &self.key,
+            &[nonce, associated_data, &ad_len],
+            HTE_SUBKEY_LABEL,
+        )
+    }
+}
+
+#[cfg(feature = "committing")]
+impl<A, D> AeadCore for HashThenEncrypt<A, D>
+where
+    A: AeadInPlace + KeyInit,
+{
+    type NonceSize = A::NonceSize;
+    type TagSize = A::TagSize;
+    type CiphertextOverhead = A::CiphertextOverhead;
+}
+
+#[cfg(feature = "committing")]
+impl<A, D> AeadInPlace for HashThenEncrypt<A, D>
+where
+    A: AeadInPlace + KeyInit,
+    D: Digest,
+{
+    fn encrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<Tag<Self>> {
+        self.derive(nonce, associated_data)
+            .encrypt_in_place_detached(nonce, associated_data, buffer)
+    }
+
+    fn decrypt_in_place_detached(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+        tag: &Tag<Self>,
+    ) -> Result<()> {
+        self.derive(nonce, associated_data)
+            .decrypt_in_place_detached(nonce, associated_data, buffer, tag)
+    }
+}
+
+#[cfg(feature = "committing")]
+impl<A, D> CommittingAead for HashThenEncrypt<A, D>
+where
+    A: AeadInPlace + KeyInit + CommittingAead,
+    D: Digest,
+{
+}
+
+#[cfg(feature = "committing")]
+impl_committing_wrapper!(HashThenEncrypt);
+
+/// Derive a subkey for `A` from `D(key || parts || label || i)` for each
+/// block `i` of the subkey, and instantiate `A` with it.
+#[cfg(feature = "committing")]
+fn derive_aead<A, D>(key: &[u8], parts: &[&[u8]], label: u8) -> A
+where
+    A: KeyInit,
+    D: Digest,
+{
+    let mut subkey = Key::<A>::default();
+
+    for (i, chunk) in subkey.chunks_mut(D::OutputSize::USIZE).enumerate() {
+        let mut hasher = D::new_with_prefix(key);
+
+        for part in parts {
+            hasher.update(part);
+        }
+
+        hasher.update([label, i as u8]);
+        let mut block = hasher.finalize();
+        chunk.copy_from_slice(&block[..chunk.len()]);
+        block.as_mut_slice().zeroize();
+    }
+
+    let aead = A::new(&subkey);
+    subkey.as_mut_slice().zeroize();
+    aead
+}
+
+#[cfg(all(test, feature = "alloc", feature = "committing"))]
+mod tests {
+    use super::{CommittingAead, HashThenEncrypt, PaddedCommitting};
+    use crate::{tests::ToyAead, Aead, Error, KeyInit, Nonce, Payload, Result};
+    use sha2::Sha256;
+
+    type Utc = PaddedCommitting<ToyAead, Sha256>;
+    type Hte = HashThenEncrypt<Utc, Sha256>;
+
+    const KEY: [u8; 16] = [0x42; 16];
+    const OTHER_KEY: [u8; 16] = [0x43; 16];
+    const NONCE: [u8; 12] = [0x24; 12];
+    const MSG: &[u8] = b"committed message";
+
+    fn payload<'a>(msg: &'a [u8], aad: &'a [u8]) -> Payload<'a, 'a> {
+        Payload { msg, aad }
+    }
+
+    fn assert_committing<A: CommittingAead>() {}
+
+    #[test]
+    fn committing_impls() {
+        assert_committing::<Utc>();
+        assert_committing::<Hte>();
+    }
+
+    #[test]
+    fn utc_round_trip() -> Result<()> {
+        let aead = Utc::new(&KEY.into());
+        let nonce = Nonce::<Utc>::from(NONCE);
+
+        for msg in [MSG, &[]] {
+            let ciphertext = aead.encrypt(&nonce, payload(msg, b"ad"))?;
+            assert_eq!(ciphertext.len(), msg.len() + 32 + 16);
+            assert_eq!(aead.decrypt(&nonce, payload(&ciphertext, b"ad"))?, msg);
+        }
+
+        // Messages are encrypted under subkeys, not the key itself
+        let ciphertext = aead.encrypt(&nonce, MSG)?;
+        assert_ne!(
+            ToyAead::new(&KEY.into()).encrypt(&nonce, MSG)?[..],
+            ciphertext[..MSG.len() + 16]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn utc_rejects_tampering() -> Result<()> {
+        let aead = Utc::new(&KEY.into());
+        let nonce = Nonce::<Utc>::from(NONCE);
+        let ciphertext = aead.encrypt(&nonce, payload(MSG, b"ad"))?;
+
+        // Tamper with the message, the commitment, and the inner tag
+        for i in [0, MSG.len(), MSG.len() + 32] {
+            let mut tampered = ciphertext.clone();
+            tampered[i] ^= 1;
+            assert_eq!(aead.decrypt(&nonce, payload(&tampered, b"ad")), Err(Error));
+        }
+
+        assert_eq!(
+            aead.decrypt(&nonce, payload(&ciphertext, b"da")),
+            Err(Error)
+        );
+        assert_eq!(aead.decrypt(&nonce, &ciphertext[..47]), Err(Error));
+        assert_eq!(
+            aead.decrypt(&[0; 12].into(), payload(&ciphertext, b"ad")),
+            Err(Error)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn utc_rejects_other_key() -> Result<()> {
+        let nonce = Nonce::<Utc>::from(NONCE);
+        let ciphertext = Utc::new(&KEY.into()).encrypt(&nonce, MSG)?;
+        assert_eq!(
+            Utc::new(&OTHER_KEY.into()).decrypt(&nonce, &ciphertext[..]),
+            Err(Error)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn hte_round_trip() -> Result<()> {
+        let aead = Hte::new(&KEY.into());
+        let nonce = Nonce::<Hte>::from(NONCE);
+
+        for msg in [MSG, &[]] {
+            let ciphertext = aead.encrypt(&nonce, payload(msg, b"ad"))?;
+            assert_eq!(ciphertext.len(), msg.len() + 32 + 16);
+            assert_eq!(aead.decrypt(&nonce, payload(&ciphertext, b"ad"))?, msg);
+        }
+
+        // Derived subkeys differ from those of the underlying UtC instance
+        let ciphertext = aead.encrypt(&nonce, MSG)?;
+        assert_ne!(Utc::new(&KEY.into()).encrypt(&nonce, MSG)?, ciphertext);
+        Ok(())
+    }
+
+    #[test]
+    fn hte_rejects_tampering() -> Result<()> {
+        let aead = Hte::new(&KEY.into());
+        let nonce = Nonce::<Hte>::from(NONCE);
+        let ciphertext = aead.encrypt(&nonce, payload(MSG, b"ad"))?;
+
+        for i in [0, MSG.len(), ciphertext.len() - 1] {
+            let mut tampered = ciphertext.clone();
+            tampered[i] ^= 1;
+            assert_eq!(aead.decrypt(&nonce, payload(&tampered, b"ad")), Err(Error));
+        }
+
+        assert_eq!(aead.decrypt(&nonce, payload(&ciphertext, b"")), Err(Error));
+        assert_eq!(
+            Hte::new(&OTHER_KEY.into()).decrypt(&nonce, payload(&ciphertext, b"ad")),
+            Err(Error)
+        );
+        Ok(())
+    }
+}
@@ -32,6 +32,13 @@ extern crate std;
 #[cfg_attr(docsrs, doc(cfg(feature = "dev")))]
 pub mod dev;
 
+pub mod committing;
+pub mod nonce;
+
+#[cfg(feature = "session")]
+#[cfg_attr(docsrs, doc(cfg(feature = "session")))]
+pub mod session;
+
 #[cfg(feature = "stream")]
 #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
 pub mod stream;
@@ -43,6 +50,10 @@ pub use generic_array::{self, typenum::consts};
 #[cfg_attr(docsrs, doc(cfg(feature = "bytes")))]
 pub use bytes;
 
+#[cfg(feature = "digest")]
+#[cfg_attr(docsrs, doc(cfg(feature = "digest")))]
+pub use digest;
+
 #[cfg(feature = "getrandom")]
 #[cfg_attr(docsrs, doc(cfg(feature = "getrandom")))]
 pub use crypto_common::rand_core::OsRng;
@@ -139,7 +150,8 @@ pub trait AeadCore {
     /// reach it should consider alternatives to purely random nonces, like
     /// a counter or a combination of a random nonce + counter.
     ///
-    /// See the [`stream`] module for a ready-made implementation of the latter.
+    /// See the [`stream`] module for a ready-made implementation of the latter,
+    /// and the [`nonce`] module for policies which enforce these limits.
     ///
     /// [NIST SP 800-38D]: https://csrc.nist.gov/publications/detail/sp/800-38d/final
     #[cfg(feature = "rand_core")]
@@ -318,6 +330,36 @@ pub trait AeadInPlace: AeadCore {
     ) -> Result<()>;
 }
 
+/// In-place AEAD trait for messages split across multiple buffers, e.g. a
+/// packet header and body, which allows zero-copy network stacks to encrypt
+/// them without concatenating the buffers first.
+///
+/// This trait is both object safe and has no dependencies on `alloc` or `std`.
+pub trait AeadInPlaceScatter: AeadCore {
+    /// Encrypt the message in-place, returning the authentication tag.
+    ///
+    /// The message is the concatenation of `buffers`.
+    fn encrypt_in_place_scatter(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffers: &mut [&mut [u8]],
+    ) -> Result<Tag<Self>>;
+
+    /// Decrypt the message in-place, returning an error in the event the
+    /// provided authentication tag does not match the given ciphertext.
+    ///
+    /// The message is the concatenation of `buffers`, which are left
+    /// unmodified in the event of an error.
+    fn decrypt_in_place_scatter(
+        &self,
+        nonce: &Nonce<Self>,
+        associated_data: &[u8],
+        buffers: &mut [&mut [u8]],
+        tag: &Tag<Self>,
+    ) -> Result<()>;
+}
+
 /// In-place stateful AEAD trait.
 ///
 /// This trait is both object safe and has no dependencies on `alloc` or `std`.
@@ -561,16 +603,200 @@ impl<const N: usize> Buffer for heapless::Vec<u8, N> {
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
     use super::*;
 
+    /// Toy AEAD for testing, which XORs the message with a keystream derived
+    /// from the key and nonce, and authenticates it with a 128-bit FNV-1a
+    /// hash. Not secure in any way!
+    #[cfg(feature = "alloc")]
+    #[derive(Clone, Debug)]
+    pub(crate) struct ToyAead {
+        key: Key<Self>,
+    }
+
+    #[cfg(feature = "alloc")]
+    impl ToyAead {
+        /// XOR the keystream into the part of the message at `offset`.
+        fn keystream(&self, nonce: &Nonce<Self>, offset: usize, buffer: &mut [u8]) {
+            for (i, byte) in (offset..).zip(buffer.iter_mut()) {
+                *byte ^= self.key[i % 16] ^ nonce[i % 12] ^ (i as u8).rotate_left(3);
+            }
+        }
+
+        /// Compute the tag of the message consisting of the given parts.
+        fn tag<'a>(
+            &self,
+            nonce: &Nonce<Self>,
+            associated_data: &[u8],
+            parts: impl IntoIterator<Item = &'a [u8]>,
+        ) -> Tag<Self> {
+            let mut state = 0x6c62_272e_07bb_0142_62b8_2175_6295_c58du128;
+            let mut absorb = |bytes: &[u8]| {
+                for &byte in bytes {
+                    state = (state ^ u128::from(byte)).wrapping_mul(0x100_0000_0000_0000_0000_013b);
+                }
+            };
+
+            absorb(&self.key);
+            absorb(nonce);
+            absorb(associated_data);
+
+            let mut msg_len = 0u64;
+            for part in parts {
+                absorb(part);
+                msg_len += part.len() as u64;
+            }
+
+            absorb(&(associated_data.len() as u64).to_le_bytes());
+            absorb(&msg_len.to_le_bytes());
+            state.to_be_bytes().into()
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl KeySizeUser for ToyAead {
+        type KeySize = consts::U16;
+    }
+
+    #[cfg(feature = "alloc")]
+    impl KeyInit for ToyAead {
+        fn new(key: &Key<Self>) -> Self {
+            Self { key: *key }
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl AeadCore for ToyAead {
+        type NonceSize = consts::U12;
+        type TagSize = consts::U16;
+        type CiphertextOverhead = consts::U0;
+    }
+
+    #[cfg(feature = "alloc")]
+    impl AeadInPlace for ToyAead {
+        fn encrypt_in_place_detached(
+            &self,
+            nonce: &Nonce<Self>,
+            associated_data: &[u8],
+            buffer: &mut [u8],
+        ) -> Result<Tag<Self>> {
+            self.keystream(nonce, 0, buffer);
+            Ok(self.tag(nonce, associated_data, [&*buffer]))
+        }
+
+        fn decrypt_in_place_detached(
+            &self,
+            nonce: &Nonce<Self>,
+            associated_data: &[u8],
+            buffer: &mut [u8],
+            tag: &Tag<Self>,
+        ) -> Result<()> {
+            if self.tag(nonce, associated_data, [&*buffer]) != *tag {
+                return Err(Error);
+            }
+
+            self.keystream(nonce, 0, buffer);
+            Ok(())
+        }
+    }
+
+    /// Processes each buffer in turn, without concatenating them.
+    #[cfg(feature = "alloc")]
+    impl AeadInPlaceScatter for ToyAead {
+        fn encrypt_in_place_scatter(
+            &self,
+            nonce: &Nonce<Self>,
+            associated_data: &[u8],
+            buffers: &mut [&mut [u8]],
+        ) -> Result<Tag<Self>> {
+            let mut offset = 0;
+            for buf in buffers.iter_mut() {
+                self.keystream(nonce, offset, buf);
+                offset += buf.len();
+            }
+
+            Ok(self.tag(nonce, associated_data, buffers.iter().map(|buf| &**buf)))
+        }
+
+        fn decrypt_in_place_scatter(
+            &self,
+            nonce: &Nonce<Self>,
+            associated_data: &[u8],
+            buffers: &mut [&mut [u8]],
+            tag: &Tag<Self>,
+        ) -> Result<()> {
+            if self.tag(nonce, associated_data, buffers.iter().map(|buf| &**buf)) != *tag {
+                return Err(Error);
+            }
+
+            let mut offset = 0;
+            for buf in buffers.iter_mut() {
+                self.keystream(nonce, offset, buf);
+                offset += buf.len();
+            }
+
+            Ok(())
+        }
+    }
+
     /// Ensure that `AeadInPlace` is object-safe
     #[allow(dead_code)]
     type DynAeadInPlace<N, T, O> =
         dyn AeadInPlace<NonceSize = N, TagSize = T, CiphertextOverhead = O>;
 
+    /// Ensure that `AeadInPlaceScatter` is object-safe
+    #[allow(dead_code)]
+    type DynAeadInPlaceScatter<N, T, O> =
+        dyn AeadInPlaceScatter<NonceSize = N, TagSize = T, CiphertextOverhead = O>;
+
     /// Ensure that `AeadMutInPlace` is object-safe
     #[allow(dead_code)]
     type DynAeadMutInPlace<N, T, O> =
         dyn AeadMutInPlace<NonceSize = N, TagSize = T, CiphertextOverhead = O>;
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn scatter_gather_round_trip() -> Result<()> {
+        let aead = ToyAead::new(&[0x42; 16].into());
+        let nonce = Nonce::<ToyAead>::default();
+        let msg = b"header and a longer body";
+
+        let (mut header, mut body) = (*b"header ", *b"and a longer body");
+        let tag = aead.encrypt_in_place_scatter(&nonce, b"ad", &mut [&mut header, &mut body])?;
+
+        // Matches encrypting the gathered message
+        let mut expected = *msg;
+        let expected_tag = aead.encrypt_in_place_detached(&nonce, b"ad", &mut expected)?;
+        assert_eq!(tag, expected_tag);
+        assert_eq!([&header[..], &body[..]].concat(), expected);
+
+        // Can be decrypted with a different split, including an empty buffer
+        let mut ciphertext = expected;
+        let (first, rest) = ciphertext.split_at_mut(3);
+        let (empty, rest) = rest.split_at_mut(0);
+        aead.decrypt_in_place_scatter(&nonce, b"ad", &mut [first, empty, rest], &tag)?;
+        assert_eq!(&ciphertext, msg);
+        Ok(())
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn scatter_decrypt_rejects_tampering() -> Result<()> {
+        let aead = ToyAead::new(&[0x42; 16].into());
+        let nonce = Nonce::<ToyAead>::default();
+
+        let (mut header, mut body) = (*b"header ", *b"body");
+        let tag = aead.encrypt_in_place_scatter(&nonce, &[], &mut [&mut header, &mut body])?;
+
+        body[0] ^= 1;
+        let (tampered_header, tampered_body) = (header, body);
+        assert!(aead
+            .decrypt_in_place_scatter(&nonce, &[], &mut [&mut header, &mut body], &tag)
+            .is_err());
+
+        // Buffers are left unmodified
+        assert_eq!((header, body), (tampered_header, tampered_body));
+        Ok(())
+    }
 }
@@ -0,0 +1,476 @@
+//! Nonce management policies.
+//!
+//! AEAD algorithms often fail catastrophically if a nonce is ever repeated
+//! under the same key. The [`NonceManager`] trait encapsulates a policy for
+//! choosing nonces which prevents this, and [`AeadWriter`] applies a policy
+//! to every message it encrypts, so that callers never handle nonces
+//! themselves.
+
+use crate::{AeadCore, AeadInPlace, Buffer, Error, Nonce, Result, Tag};
+
+#[cfg(feature = "alloc")]
+use {crate::Payload, alloc::vec::Vec};
+
+#[cfg(any(feature = "alloc", feature = "rand_core"))]
+use generic_array::typenum::Unsigned;
+
+#[cfg(feature = "rand_core")]
+use {
+    crate::rand_core::{CryptoRng, RngCore},
+    core::marker::PhantomData,
+    generic_array::typenum::{IsGreaterOrEqual, True, U24},
+};
+
+/// Policy for choosing the nonces used by the AEAD algorithm `A`.
+pub trait NonceManager<A: AeadCore + ?Sized> {
+    /// Obtain a nonce which hasn't been returned before.
+    ///
+    /// Returns an error once no more nonces can be safely produced, after
+    /// which the key must be changed.
+    fn next_nonce(&mut self) -> Result<Nonce<A>>;
+}
+
+/// Counter-based nonces: each nonce is the previous one incremented as a big
+/// endian integer.
+///
+/// This guarantees that nonces are unique for as long as the counter state
+/// isn't lost or shared, e.g. between processes encrypting with the same key.
+pub struct CounterNonce<A: AeadCore + ?Sized> {
+    /// Next nonce to be returned, or `None` if exhausted.
+    next: Option<Nonce<A>>,
+}
+
+impl<A: AeadCore + ?Sized> CounterNonce<A> {
+    /// Create a counter starting at the given nonce.
+    pub fn new(initial: Nonce<A>) -> Self {
+        Self {
+            next: Some(initial),
+        }
+    }
+}
+
+impl<A: AeadCore + ?Sized> Default for CounterNonce<A> {
+    /// Create a counter starting at zero.
+    fn default() -> Self {
+        Self::new(Nonce::<A>::default())
+    }
+}
+
+impl<A: AeadCore + ?Sized> NonceManager<A> for CounterNonce<A> {
+    fn next_nonce(&mut self) -> Result<Nonce<A>> {
+        let nonce = self.next.take().ok_or(Error)?;
+        let mut next = nonce.clone();
+
+        for byte in next.iter_mut().rev() {
+            let (sum, carry) = byte.overflowing_add(1);
+            *byte = sum;
+
+            if !carry {
+                self.next = Some(next);
+                break;
+            }
+        }
+
+        Ok(nonce)
+    }
+}
+
+/// Random nonces, limited to a number of messages such that the probability
+/// of a nonce collision due to the birthday bound stays below 2^-32.
+///
+/// For 96-bit nonces this allows 2^32 messages, matching the recommendation
+/// of [NIST SP 800-38D].
+///
+/// [NIST SP 800-38D]: https://csrc.nist.gov/publications/detail/sp/800-38d/final
+#[cfg(feature = "rand_core")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand_core")))]
+pub struct RandomNonce<A: AeadCore + ?Sized, R> {
+    /// Random number generator.
+    rng: R,
+
+    /// Number of nonces which can still be produced.
+    remaining: u64,
+
+    /// AEAD algorithm.
+    aead: PhantomData<A>,
+}
+
+#[cfg(feature = "rand_core")]
+impl<A: AeadCore + ?Sized, R: CryptoRng + RngCore> RandomNonce<A, R> {
+    /// Create a new random nonce policy using the given RNG.
+    pub fn new(rng: R) -> Self {
+        // Collision probability is roughly q^2 / 2^(n+1) for q nonces of n bits
+        let bits = A::NonceSize::to_usize() * 8;
+        let log2_limit = bits.saturating_sub(32) / 2;
+        let remaining = 1u64.checked_shl(log2_limit as u32).unwrap_or(u64::MAX);
+        Self::with_limit(rng, remaining)
+    }
+
+    /// Create a new random nonce policy using the given RNG which produces at
+    /// most `limit` nonces.
+    ///
+    /// The limit must be chosen carefully with regard to the nonce size, as the
+    /// default limit computed by [`RandomNonce::new`] is typically the highest
+    /// safe value.
+    pub fn with_limit(rng: R, limit: u64) -> Self {
+        Self {
+            rng,
+            remaining: limit,
+            aead: PhantomData,
+        }
+    }
+
+    /// Number of nonces which can still be produced.
+    pub fn remaining(&self) -> u64 {
+        self.remaining
+    }
+}
+
+#[cfg(feature = "rand_core")]
+impl<A: AeadCore + ?Sized, R: CryptoRng + RngCore> NonceManager<A> for RandomNonce<A, R> {
+    fn next_nonce(&mut self) -> Result<Nonce<A>> {
+        self.remaining = self.remaining.checked_sub(1).ok_or(Error)?;
+        let mut nonce = Nonce::<A>::default();
+        self.rng.try_fill_bytes(&mut nonce).map_err(|_| Error)?;
+        Ok(nonce)
+    }
+}
+
+/// Random nonces for extended-nonce ("XAEAD") constructions such as
+/// XChaCha20Poly1305, which have nonces of at least 192 bits.
+///
+/// Nonces this large can safely be generated at random for any practical
+/// number of messages, which this policy enforces at compile time.
+#[cfg(feature = "rand_core")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rand_core")))]
+pub struct ExtendedNonce<A: AeadCore + ?Sized, R>(RandomNonce<A, R>);
+
+#[cfg(feature = "rand_core")]
+impl<A, R> ExtendedNonce<A, R>
+where
+    A: AeadCore + ?Sized,
+    A::NonceSize: IsGreaterOrEqual<U24, Output = True>,
+    R: CryptoRng + RngCore,
+{
+    /// Create a new extended nonce policy using the given RNG.
+    pub fn new(rng: R) -> Self {
+        Self(RandomNonce::new(rng))
+    }
+}
+
+#[cfg(feature = "rand_core")]
+impl<A, R> NonceManager<A> for ExtendedNonce<A, R>
+where
+    A: AeadCore + ?Sized,
+    A::NonceSize: IsGreaterOrEqual<U24, Output = True>,
+    R: CryptoRng + RngCore,
+{
+    fn next_nonce(&mut self) -> Result<Nonce<A>> {
+        self.0.next_nonce()
+    }
+}
+
+/// AEAD encryptor which obtains the nonce for every message from a
+/// [`NonceManager`].
+///
+/// The nonce used for each message is returned alongside (or prepended to)
+/// the ciphertext, as it's needed for decryption.
+pub struct AeadWriter<A: AeadInPlace, N: NonceManager<A>> {
+    /// AEAD algorithm.
+    aead: A,
+
+    /// Nonce policy.
+    nonces: N,
+}
+
+impl<A: AeadInPlace, N: NonceManager<A>> AeadWriter<A, N> {
+    /// Create a new writer from the given AEAD algorithm and nonce policy.
+    pub fn new(aead: A, nonces: N) -> Self {
+        Self { aead, nonces }
+    }
+
+    /// Encrypt the given buffer containing a plaintext message in-place,
+    /// returning the nonce which was used.
+    ///
+    /// See [`AeadInPlace::encrypt_in_place`].
+    pub fn encrypt_in_place(
+        &mut self,
+        associated_data: &[u8],
+        buffer: &mut dyn Buffer,
+    ) -> Result<Nonce<A>> {
+        let nonce = self.nonces.next_nonce()?;
+        self.aead
+            .encrypt_in_place(&nonce, associated_data, buffer)?;
+        Ok(nonce)
+    }
+
+    /// Encrypt the data in-place, returning the nonce which was used and the
+    /// authentication tag.
+    pub fn encrypt_in_place_detached(
+        &mut self,
+        associated_data: &[u8],
+        buffer: &mut [u8],
+    ) -> Result<(Nonce<A>, Tag<A>)> {
+        let nonce = self.nonces.next_nonce()?;
+        let tag = self
+            .aead
+            .encrypt_in_place_detached(&nonce, associated_data, buffer)?;
+        Ok((nonce, tag))
+    }
+
+    /// Encrypt the given plaintext payload, returning the nonce which was
+    /// used followed by the ciphertext as a vector of bytes.
+    ///
+    /// The ciphertext is always laid out as the encrypted message followed by
+    /// the authentication tag, as produced by the default implementation of
+    /// [`AeadInPlace::encrypt_in_place`]. Algorithms which override it to use
+    /// a different layout (e.g. a prefix tag) must decrypt the output of this
+    /// method with [`AeadInPlace::decrypt_in_place_detached`], rather than by
+    /// passing it to [`Aead::decrypt`][`crate::Aead::decrypt`].
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn encrypt<'msg, 'aad>(
+        &mut self,
+        plaintext: impl Into<Payload<'msg, 'aad>>,
+    ) -> Result<Vec<u8>> {
+        let payload = plaintext.into();
+        let nonce_len = A::NonceSize::to_usize();
+        let mut buffer = Vec::with_capacity(nonce_len + payload.msg.len() + A::TagSize::to_usize());
+        buffer.resize(nonce_len, 0);
+        buffer.extend_from_slice(payload.msg);
+
+        let (nonce, tag) = self.encrypt_in_place_detached(payload.aad, &mut buffer[nonce_len..])?;
+        buffer[..nonce_len].copy_from_slice(&nonce);
+        buffer.extend_from_slice(&tag);
+        Ok(buffer)
+    }
+
+    /// Get a reference to the nonce policy.
+    pub fn nonce_manager(&self) -> &N {
+        &self.nonces
+    }
+
+    /// Consume the writer, returning the AEAD algorithm and nonce policy.
+    pub fn into_inner(self) -> (A, N) {
+        (self.aead, self.nonces)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CounterNonce, NonceManager};
+    use crate::{consts::*, AeadCore, Nonce};
+    use core::marker::PhantomData;
+    use generic_array::ArrayLength;
+
+    #[cfg(feature = "alloc")]
+    use {
+        super::AeadWriter,
+        crate::{tests::ToyAead, Aead, AeadInPlace, KeyInit, Result},
+    };
+
+    #[cfg(feature = "rand_core")]
+    use {
+        super::RandomNonce,
+        crate::rand_core::{self, CryptoRng, RngCore},
+    };
+
+    #[cfg(all(feature = "alloc", feature = "rand_core"))]
+    use super::ExtendedNonce;
+
+    struct TestAead<N = U2>(PhantomData<N>);
+
+    impl<N: ArrayLength<u8>> AeadCore for TestAead<N> {
+        type NonceSize = N;
+        type TagSize = U0;
+        type CiphertextOverhead = U0;
+    }
+
+    /// Extended-nonce variant of [`ToyAead`], which folds the 192-bit nonce
+    /// into a 96-bit one.
+    #[cfg(all(feature = "alloc", feature = "rand_core"))]
+    struct ToyXAead(ToyAead);
+
+    #[cfg(all(feature = "alloc", feature = "rand_core"))]
+    impl ToyXAead {
+        fn inner_nonce(nonce: &Nonce<Self>) -> Nonce<ToyAead> {
+            let mut inner = Nonce::<ToyAead>::clone_from_slice(&nonce[..12]);
+            inner
+                .iter_mut()
+                .zip(&nonce[12..])
+                .for_each(|(a, b)| *a ^= b);
+            inner
+        }
+    }
+
+    #[cfg(all(feature = "alloc", feature = "rand_core"))]
+    impl AeadCore for ToyXAead {
+        type NonceSize = U24;
+        type TagSize = U16;
+        type CiphertextOverhead = U0;
+    }
+
+    #[cfg(all(feature = "alloc", feature = "rand_core"))]
+    impl AeadInPlace for ToyXAead {
+        fn encrypt_in_place_detached(
+            &self,
+            nonce: &Nonce<Self>,
+            associated_data: &[u8],
+            buffer: &mut [u8],
+        ) -> Result<crate::Tag<Self>> {
+            self.0
+                .encrypt_in_place_detached(&Self::inner_nonce(nonce), associated_data, buffer)
+        }
+
+        fn decrypt_in_place_detached(
+            &self,
+            nonce: &Nonce<Self>,
+            associated_data: &[u8],
+            buffer: &mut [u8],
+            tag: &crate::Tag<Self>,
+        ) -> Result<()> {
+            self.0.decrypt_in_place_detached(
+                &Self::inner_nonce(nonce),
+                associated_data,
+                buffer,
+                tag,
+            )
+        }
+    }
+
+    /// Deterministic RNG which outputs an incrementing byte counter.
+    #[cfg(feature = "rand_core")]
+    struct CountingRng(u8);
+
+    #[cfg(feature = "rand_core")]
+    impl RngCore for CountingRng {
+        fn next_u32(&mut self) -> u32 {
+            rand_core::impls::next_u32_via_fill(self)
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            rand_core::impls::next_u64_via_fill(self)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            for byte in dest {
+                self.0 = self.0.wrapping_add(1);
+                *byte = self.0;
+            }
+        }
+
+        fn try_fill_bytes(
+            &mut self,
+            dest: &mut [u8],
+        ) -> core::result::Result<(), rand_core::Error> {
+            self.fill_bytes(dest);
+            Ok(())
+        }
+    }
+
+    #[cfg(feature = "rand_core")]
+    impl CryptoRng for CountingRng {}
+
+    #[test]
+    fn counter_nonce_exhaustion() {
+        let mut nonces = CounterNonce::<TestAead>::new([0xff, 0xfe].into());
+        assert_eq!(
+            nonces.next_nonce(),
+            Ok(Nonce::<TestAead>::from([0xff, 0xfe]))
+        );
+        assert_eq!(
+            nonces.next_nonce(),
+            Ok(Nonce::<TestAead>::from([0xff, 0xff]))
+        );
+        assert!(nonces.next_nonce().is_err());
+    }
+
+    #[cfg(feature = "rand_core")]
+    #[test]
+    fn random_nonce_limits() {
+        // 2^((n - 32) / 2) nonces of n bits, saturating at `u64::MAX`
+        assert_eq!(
+            RandomNonce::<TestAead, _>::new(CountingRng(0)).remaining(),
+            1
+        );
+        assert_eq!(
+            RandomNonce::<TestAead<U12>, _>::new(CountingRng(0)).remaining(),
+            1 << 32
+        );
+        assert_eq!(
+            RandomNonce::<TestAead<U24>, _>::new(CountingRng(0)).remaining(),
+            u64::MAX
+        );
+
+        let mut nonces = RandomNonce::<TestAead, _>::with_limit(CountingRng(0), 2);
+        assert_eq!(nonces.next_nonce(), Ok(Nonce::<TestAead>::from([1, 2])));
+        assert_eq!(nonces.next_nonce(), Ok(Nonce::<TestAead>::from([3, 4])));
+        assert_eq!(nonces.remaining(), 0);
+        assert!(nonces.next_nonce().is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn writer_round_trip() -> Result<()> {
+        let aead = ToyAead::new(&[0x42; 16].into());
+        let mut writer = AeadWriter::new(aead.clone(), CounterNonce::default());
+
+        // Nonce is prepended to the ciphertext
+        let ciphertext = writer.encrypt(b"message".as_ref())?;
+        let (nonce, ciphertext) = ciphertext.split_at(12);
+        assert_eq!(nonce, [0; 12]);
+        assert_eq!(aead.decrypt(nonce.into(), ciphertext)?, b"message");
+
+        let mut buffer = b"in place".to_vec();
+        let nonce = writer.encrypt_in_place(b"ad", &mut buffer)?;
+        assert_eq!(nonce[11], 1);
+        aead.decrypt_in_place(&nonce, b"ad", &mut buffer)?;
+        assert_eq!(buffer, b"in place");
+
+        let mut buffer = *b"detached";
+        let (nonce, tag) = writer.encrypt_in_place_detached(&[], &mut buffer)?;
+        assert_eq!(nonce[11], 2);
+        aead.decrypt_in_place_detached(&nonce, &[], &mut buffer, &tag)?;
+        assert_eq!(&buffer, b"detached");
+
+        let (_, nonces) = writer.into_inner();
+        assert_eq!(
+            nonces.next,
+            Some(Nonce::<ToyAead>::from([0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3]))
+        );
+        Ok(())
+    }
+
+    #[cfg(all(feature = "alloc", feature = "rand_core"))]
+    #[test]
+    fn writer_random_nonce_round_trip() -> Result<()> {
+        let aead = ToyAead::new(&[0x42; 16].into());
+        let mut writer = AeadWriter::new(aead.clone(), RandomNonce::new(CountingRng(0)));
+
+        let first = writer.encrypt(b"message".as_ref())?;
+        let second = writer.encrypt(b"message".as_ref())?;
+        assert_ne!(first[..12], second[..12]);
+        assert_eq!(writer.nonce_manager().remaining(), (1 << 32) - 2);
+
+        for ciphertext in [first, second] {
+            let (nonce, ciphertext) = ciphertext.split_at(12);
+            assert_eq!(aead.decrypt(nonce.into(), ciphertext)?, b"message");
+        }
+
+        Ok(())
+    }
+
+    #[cfg(all(feature = "alloc", feature = "rand_core"))]
+    #[test]
+    fn writer_extended_nonce_round_trip() -> Result<()> {
+        let aead = ToyXAead(ToyAead::new(&[0x42; 16].into()));
+        let mut writer = AeadWriter::new(aead, ExtendedNonce::new(CountingRng(0)));
+
+        let ciphertext = writer.encrypt(b"message".as_ref())?;
+        let (aead, _) = writer.into_inner();
+        let (nonce, ciphertext) = ciphertext.split_at(24);
+        assert_eq!(nonce, (1..=24).collect::<alloc::vec::Vec<u8>>());
+        assert_eq!(aead.decrypt(nonce.into(), ciphertext)?, b"message");
+        Ok(())
+    }
+}
@@ -0,0 +1,444 @@
+//! Stateful sessions for record protocols such as TLS, QUIC, or Noise.
+//!
+//! A session consists of independent state for each [`Direction`], each
+//! with its own key, IV, and sequence number. The nonce of every record is
+//! computed from the IV and sequence number as in [TLS 1.3], and so is
+//! never reused as long as the keys for both directions are distinct, which
+//! sessions check whenever they're created or rekeyed.
+//!
+//! Sessions signal when a direction [needs to be rekeyed][`AeadSession::needs_rekey`]
+//! after a configurable number of records, and refuse to process further
+//! records in that direction until it has been.
+//!
+//! [TLS 1.3]: https://www.rfc-editor.org/rfc/rfc8446#section-5.3
+
+use crate::{AeadInPlace, Buffer, Error, Key, KeyInit, Nonce, Result};
+use core::fmt;
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+/// Direction of records in a session.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum Direction {
+    /// Records sent to the peer, which are sealed.
+    Send,
+
+    /// Records received from the peer, which are opened.
+    Receive,
+}
+
+/// Stateful AEAD session which tracks sequence numbers for each direction.
+pub trait AeadSession {
+    /// Underlying AEAD algorithm.
+    type Aead: AeadInPlace + KeyInit;
+
+    /// Encrypt the next record to send in-place, returning its sequence
+    /// number.
+    fn seal_in_place(&mut self, associated_data: &[u8], buffer: &mut dyn Buffer) -> Result<u64>;
+
+    /// Decrypt the next received record in-place, returning its sequence
+    /// number.
+    ///
+    /// Records must be opened in the order in which they were sealed.
+    fn open_in_place(&mut self, associated_data: &[u8], buffer: &mut dyn Buffer) -> Result<u64>;
+
+    /// Has the given direction reached its record limit, requiring it to be
+    /// rekeyed before processing any further records?
+    fn needs_rekey(&self, direction: Direction) -> bool;
+
+    /// Replace the key and IV of the given direction, resetting its sequence
+    /// number.
+    ///
+    /// Returns an error, leaving the session unmodified, if the new key is
+    /// the same as the current key of either direction, as nonces would
+    /// otherwise be reused.
+    fn rekey(
+        &mut self,
+        direction: Direction,
+        key: &Key<Self::Aead>,
+        iv: Nonce<Self::Aead>,
+    ) -> Result<()>;
+}
+
+/// State of a single direction of a session.
+///
+/// The key is retained so that sessions can check that both directions use
+/// distinct keys, and is zeroized on drop.
+#[derive(Clone)]
+pub struct DirectionalState<A: AeadInPlace + KeyInit> {
+    /// AEAD algorithm, instantiated with this direction's key.
+    aead: A,
+
+    /// Key of this direction.
+    key: Key<A>,
+
+    /// IV which is combined with the sequence number to compute nonces.
+    iv: Nonce<A>,
+
+    /// Sequence number of the next record.
+    sequence: u64,
+
+    /// Number of records after which this direction must be rekeyed.
+    rekey_after: u64,
+}
+
+impl<A: AeadInPlace + KeyInit> DirectionalState<A> {
+    /// Create the state of a direction from its key and IV, which must be
+    /// rekeyed after `rekey_after` records.
+    pub fn new(key: &Key<A>, iv: Nonce<A>, rekey_after: u64) -> Self {
+        Self {
+            aead: A::new(key),
+            key: key.clone(),
+            iv,
+            sequence: 0,
+            rekey_after,
+        }
+    }
+
+    /// Sequence number of the next record.
+    pub fn sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    /// Has this direction reached its record limit?
+    pub fn needs_rekey(&self) -> bool {
+        self.sequence >= self.rekey_after
+    }
+
+    /// Replace the key and IV, resetting the sequence number.
+    ///
+    /// Callers must check that the new key differs from the current key of
+    /// both directions.
+    fn rekey(&mut self, key: &Key<A>, iv: Nonce<A>) {
+        self.aead = A::new(key);
+        self.key.zeroize();
+        self.key = key.clone();
+        self.iv = iv;
+        self.sequence = 0;
+    }
+
+    /// Does this direction use the given key?
+    ///
+    /// The comparison is performed in constant time.
+    fn uses_key(&self, key: &Key<A>) -> bool {
+        self.key.as_slice().ct_eq(key.as_slice()).into()
+    }
+
+    /// Compute the nonce of the next record.
+    fn nonce(&self) -> Result<Nonce<A>> {
+        if self.needs_rekey() {
+            return Err(Error);
+        }
+
+        let mut nonce = self.iv.clone();
+        let offset = nonce.len().checked_sub(8).ok_or(Error)?;
+
+        for (byte, seq_byte) in nonce[offset..].iter_mut().zip(self.sequence.to_be_bytes()) {
+            *byte ^= seq_byte;
+        }
+
+        Ok(nonce)
+    }
+
+    /// Advance to the next record, returning the sequence number of the
+    /// current one.
+    fn advance(&mut self) -> u64 {
+        // Note: can't overflow as `needs_rekey` is checked by `nonce`
+        let sequence = self.sequence;
+        self.sequence += 1;
+        sequence
+    }
+}
+
+impl<A: AeadInPlace + KeyInit> fmt::Debug for DirectionalState<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DirectionalState")
+            .field("sequence", &self.sequence)
+            .field("rekey_after", &self.rekey_after)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<A: AeadInPlace + KeyInit> Drop for DirectionalState<A> {
+    fn drop(&mut self) {
+        self.key.zeroize();
+    }
+}
+
+/// Session with independent [`DirectionalState`] for sending and receiving.
+#[derive(Clone, Debug)]
+pub struct Session<A: AeadInPlace + KeyInit> {
+    /// State for sealing sent records.
+    send: DirectionalState<A>,
+
+    /// State for opening received records.
+    receive: DirectionalState<A>,
+}
+
+impl<A: AeadInPlace + KeyInit> Session<A> {
+    /// Create a session from the state of each direction.
+    ///
+    /// Returns an error if the keys of both directions are the same, as
+    /// nonces would otherwise be reused.
+    pub fn new(send: DirectionalState<A>, receive: DirectionalState<A>) -> Result<Self> {
+        if send.uses_key(&receive.key) {
+            return Err(Error);
+        }
+
+        Ok(Self { send, receive })
+    }
+
+    /// Create a session from the key and IV of each direction, which must
+    /// both be rekeyed after `rekey_after` records.
+    ///
+    /// Returns an error if the keys of both directions are the same.
+    pub fn from_keys(
+        send_key: &Key<A>,
+        send_iv: Nonce<A>,
+        receive_key: &Key<A>,
+        receive_iv: Nonce<A>,
+        rekey_after: u64,
+    ) -> Result<Self> {
+        Self::new(
+            DirectionalState::new(send_key, send_iv, rekey_after),
+            DirectionalState::new(receive_key, receive_iv, rekey_after),
+        )
+    }
+
+    /// Get the state of the given direction.
+    pub fn state(&self, direction: Direction) -> &DirectionalState<A> {
+        match direction {
+            Direction::Send => &self.send,
+            Direction::Receive => &self.receive,
+        }
+    }
+}
+
+impl<A: AeadInPlace + KeyInit> AeadSession for Session<A> {
+    type Aead = A;
+
+    fn seal_in_place(&mut self, associated_data: &[u8], buffer: &mut dyn Buffer) -> Result<u64> {
+        let nonce = self.send.nonce()?;
+        self.send
+            .aead
+            .encrypt_in_place(&nonce, associated_data, buffer)?;
+        Ok(self.send.advance())
+    }
+
+    fn open_in_place(&mut self, associated_data: &[u8], buffer: &mut dyn Buffer) -> Result<u64> {
+        let nonce = self.receive.nonce()?;
+        self.receive
+            .aead
+            .decrypt_in_place(&nonce, associated_data, buffer)?;
+
+        // Note: the sequence number is only advanced for authentic records
+        Ok(self.receive.advance())
+    }
+
+    fn needs_rekey(&self, direction: Direction) -> bool {
+        self.state(direction).needs_rekey()
+    }
+
+    fn rekey(&mut self, direction: Direction, key: &Key<A>, iv: Nonce<A>) -> Result<()> {
+        let (state, other) = match direction {
+            Direction::Send => (&mut self.send, &self.receive),
+            Direction::Receive => (&mut self.receive, &self.send),
+        };
+
+        if state.uses_key(key) || other.uses_key(key) {
+            return Err(Error);
+        }
+
+        state.rekey(key, iv);
+        Ok(())
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::{AeadSession, Direction, DirectionalState, Session};
+    use crate::{tests::ToyAead, AeadInPlace, Error, Key, KeyInit, Nonce, Result};
+    use generic_array::GenericArray;
+
+    const KEY_A: [u8; 16] = [0xaa; 16];
+    const KEY_B: [u8; 16] = [0xbb; 16];
+    const IV_A: [u8; 12] = [0x0a; 12];
+    const IV_B: [u8; 12] = [0x0b; 12];
+
+    /// Create the sessions of both peers, the first of which sends with
+    /// `KEY_A` and the second with `KEY_B`.
+    fn sessions(rekey_after: u64) -> Result<(Session<ToyAead>, Session<ToyAead>)> {
+        let (key_a, key_b) = (KEY_A.into(), KEY_B.into());
+        Ok((
+            Session::from_keys(&key_a, IV_A.into(), &key_b, IV_B.into(), rekey_after)?,
+            Session::from_keys(&key_b, IV_B.into(), &key_a, IV_A.into(), rekey_after)?,
+        ))
+    }
+
+    #[test]
+    fn nonce_xors_sequence_into_iv() -> Result<()> {
+        let mut state = DirectionalState::<ToyAead>::new(&KEY_A.into(), IV_A.into(), 1 << 20);
+        assert_eq!(state.nonce()?, Nonce::<ToyAead>::from(IV_A));
+
+        for _ in 0..0x0102 {
+            state.advance();
+        }
+
+        let mut expected = IV_A;
+        expected[10] ^= 0x01;
+        expected[11] ^= 0x02;
+        assert_eq!(state.nonce()?, Nonce::<ToyAead>::from(expected));
+        Ok(())
+    }
+
+    #[test]
+    fn seal_and_open() -> Result<()> {
+        let (mut alice, mut bob) = sessions(16)?;
+
+        for sequence in 0..3 {
+            let mut record = b"hello".to_vec();
+            assert_eq!(alice.seal_in_place(b"ad", &mut record)?, sequence);
+            assert_eq!(bob.open_in_place(b"ad", &mut record)?, sequence);
+            assert_eq!(record, b"hello");
+        }
+
+        assert_eq!(alice.state(Direction::Send).sequence(), 3);
+        assert_eq!(alice.state(Direction::Receive).sequence(), 0);
+        assert_eq!(bob.state(Direction::Receive).sequence(), 3);
+
+        // Each record is sealed with a different nonce
+        let mut first = b"hello".to_vec();
+        let mut second = b"hello".to_vec();
+        alice.seal_in_place(&[], &mut first)?;
+        alice.seal_in_place(&[], &mut second)?;
+        assert_ne!(first, second);
+        Ok(())
+    }
+
+    #[test]
+    fn open_rejects_unauthentic_records() -> Result<()> {
+        let (mut alice, mut bob) = sessions(16)?;
+
+        let mut first = b"first".to_vec();
+        let mut second = b"second".to_vec();
+        alice.seal_in_place(&[], &mut first)?;
+        alice.seal_in_place(&[], &mut second)?;
+
+        // Out of order records and tampered records are rejected, without
+        // advancing the sequence number
+        assert_eq!(bob.open_in_place(&[], &mut second.clone()), Err(Error));
+
+        let mut tampered = first.clone();
+        tampered[0] ^= 1;
+        assert_eq!(bob.open_in_place(&[], &mut tampered), Err(Error));
+        assert_eq!(bob.open_in_place(b"ad", &mut first.clone()), Err(Error));
+        assert_eq!(bob.state(Direction::Receive).sequence(), 0);
+
+        assert_eq!(bob.open_in_place(&[], &mut first)?, 0);
+        assert_eq!(bob.open_in_place(&[], &mut second)?, 1);
+        Ok(())
+    }
+
+    #[test]
+    fn record_limit() -> Result<()> {
+        let (mut alice, mut bob) = sessions(2)?;
+
+        for _ in 0..2 {
+            let mut record = b"hello".to_vec();
+            alice.seal_in_place(&[], &mut record)?;
+            bob.open_in_place(&[], &mut record)?;
+        }
+
+        assert!(alice.needs_rekey(Direction::Send));
+        assert!(!alice.needs_rekey(Direction::Receive));
+        assert!(bob.needs_rekey(Direction::Receive));
+
+        let mut record = b"hello".to_vec();
+        assert_eq!(alice.seal_in_place(&[], &mut record), Err(Error));
+        assert_eq!(record, b"hello");
+
+        // A record sealed under the old key can't be opened either
+        let aead = ToyAead::new(&KEY_A.into());
+        aead.encrypt_in_place(&IV_A.into(), &[], &mut record)?;
+        assert_eq!(bob.open_in_place(&[], &mut record), Err(Error));
+        Ok(())
+    }
+
+    #[test]
+    fn rekey() -> Result<()> {
+        let (mut alice, mut bob) = sessions(1)?;
+        let key_c = Key::<ToyAead>::from([0xcc; 16]);
+        let iv_c = Nonce::<ToyAead>::from([0x0c; 12]);
+
+        let mut record = b"hello".to_vec();
+        alice.seal_in_place(&[], &mut record)?;
+        bob.open_in_place(&[], &mut record)?;
+        assert!(alice.needs_rekey(Direction::Send));
+
+        alice.rekey(Direction::Send, &key_c, iv_c)?;
+        bob.rekey(Direction::Receive, &key_c, iv_c)?;
+        assert!(!alice.needs_rekey(Direction::Send));
+        assert_eq!(alice.state(Direction::Send).sequence(), 0);
+
+        let mut record = b"hello".to_vec();
+        assert_eq!(alice.seal_in_place(&[], &mut record)?, 0);
+        assert_eq!(bob.open_in_place(&[], &mut record)?, 0);
+        assert_eq!(record, b"hello");
+        Ok(())
+    }
+
+    #[test]
+    fn reject_same_key_for_both_directions() -> Result<()> {
+        let key = Key::<ToyAead>::from(KEY_A);
+        assert!(Session::<ToyAead>::from_keys(&key, IV_A.into(), &key, IV_B.into(), 16).is_err());
+        assert!(Session::new(
+            DirectionalState::<ToyAead>::new(&key, IV_A.into(), 16),
+            DirectionalState::new(&key, IV_B.into(), 16),
+        )
+        .is_err());
+
+        // Rekeying one direction with the other direction's key is rejected,
+        // leaving the session unmodified
+        let (mut alice, _) = sessions(16)?;
+        let mut record = b"hello".to_vec();
+        alice.seal_in_place(&[], &mut record)?;
+
+        assert_eq!(
+            alice.rekey(Direction::Send, &KEY_B.into(), IV_A.into()),
+            Err(Error)
+        );
+        assert_eq!(
+            alice.rekey(Direction::Receive, &GenericArray::from(KEY_A), IV_B.into()),
+            Err(Error)
+        );
+        assert_eq!(alice.state(Direction::Send).sequence(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn reject_rekey_with_current_key() -> Result<()> {
+        let (mut alice, _) = sessions(16)?;
+        let mut first = b"hello".to_vec();
+        alice.seal_in_place(&[], &mut first)?;
+
+        // Rekeying a direction with its own key would reset the sequence
+        // number, reusing nonces, even with a different IV
+        for iv in [IV_A, [0x0c; 12]] {
+            assert_eq!(
+                alice.rekey(Direction::Send, &KEY_A.into(), iv.into()),
+                Err(Error)
+            );
+        }
+
+        assert_eq!(
+            alice.rekey(Direction::Receive, &KEY_B.into(), IV_B.into()),
+            Err(Error)
+        );
+        assert_eq!(alice.state(Direction::Send).sequence(), 1);
+
+        let mut second = b"hello".to_vec();
+        assert_eq!(alice.seal_in_place(&[], &mut second)?, 1);
+        assert_ne!(first, second);
+        Ok(())
+    }
+}
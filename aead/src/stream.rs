@@ -28,6 +28,14 @@
 //! - 𝐂: ciphertext
 //! - 𝜏: MAC tag
 //!
+//! ## Segments
+//!
+//! Messages which are available in their entirety can be split into
+//! equally-sized segments with [`Encryptor::encrypt_segmented`] and
+//! reassembled with [`Decryptor::decrypt_segmented`]. Both sides must agree
+//! on the [`SegmentSize`], e.g. by exchanging their preferred size ahead of
+//! time and using the result of [`SegmentSize::negotiate`].
+//!
 //! [1]: https://eprint.iacr.org/2015/189.pdf
 
 #![allow(clippy::upper_case_acronyms)]
@@ -42,6 +50,60 @@ use generic_array::{
 #[cfg(feature = "alloc")]
 use {crate::Payload, alloc::vec::Vec};
 
+/// Size of the plaintext of each segment of a segmented STREAM message,
+/// except the last one which may be shorter.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct SegmentSize(u32);
+
+impl SegmentSize {
+    /// Default segment size: 64 KiB.
+    pub const DEFAULT: Self = Self(0x10000);
+
+    /// Create a new segment size from the given number of plaintext bytes.
+    ///
+    /// Returns an error if `size` is zero.
+    pub fn new(size: u32) -> Result<Self> {
+        if size == 0 {
+            return Err(Error);
+        }
+
+        Ok(Self(size))
+    }
+
+    /// Decode a segment size from its big endian serialization.
+    pub fn from_be_bytes(bytes: [u8; 4]) -> Result<Self> {
+        Self::new(u32::from_be_bytes(bytes))
+    }
+
+    /// Serialize this segment size as a big endian integer.
+    pub fn to_be_bytes(self) -> [u8; 4] {
+        self.0.to_be_bytes()
+    }
+
+    /// Negotiate the segment size to use with a peer which prefers `other`,
+    /// i.e. the smaller of the two.
+    pub fn negotiate(self, other: Self) -> Self {
+        self.min(other)
+    }
+
+    /// Size of the plaintext of each segment in bytes.
+    pub fn plaintext_len(self) -> usize {
+        self.0 as usize
+    }
+
+    /// Size of the ciphertext of each segment in bytes when encrypted using
+    /// the AEAD `A`, which is assumed to use a postfix tag.
+    pub fn ciphertext_len<A: AeadCore>(self) -> usize {
+        self.plaintext_len() + A::TagSize::to_usize()
+    }
+}
+
+impl Default for SegmentSize {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
 /// Nonce as used by a given AEAD construction and STREAM primitive.
 pub type Nonce<A, S> = GenericArray<u8, NonceSize<A, S>>;
 
@@ -358,6 +420,89 @@ impl_stream_object!(
     "𝒟 STREAM decryptor"
 );
 
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<A, S> Encryptor<A, S>
+where
+    A: AeadInPlace,
+    S: StreamPrimitive<A>,
+    A::NonceSize: Sub<<S as StreamPrimitive<A>>::NonceOverhead>,
+    NonceSize<A, S>: ArrayLength<u8>,
+{
+    /// Encrypt the given plaintext as a sequence of segments of the given
+    /// [`SegmentSize`], flagging the final segment as the last block,
+    /// and return the concatenated ciphertexts.
+    ///
+    /// The associated data is authenticated with every segment.
+    pub fn encrypt_segmented(
+        mut self,
+        segment_size: SegmentSize,
+        associated_data: &[u8],
+        plaintext: &[u8],
+    ) -> Result<Vec<u8>> {
+        let segment_len = segment_size.plaintext_len();
+        let segments = (plaintext.len() / segment_len) + 1;
+        let mut ciphertext =
+            Vec::with_capacity(plaintext.len() + segments * A::TagSize::to_usize());
+        let mut remaining = plaintext;
+
+        while remaining.len() > segment_len {
+            let (msg, rest) = remaining.split_at(segment_len);
+            let aad = associated_data;
+            ciphertext.extend_from_slice(&self.encrypt_next(Payload { msg, aad })?);
+            remaining = rest;
+        }
+
+        let aad = associated_data;
+        ciphertext.extend_from_slice(&self.encrypt_last(Payload {
+            msg: remaining,
+            aad,
+        })?);
+        Ok(ciphertext)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<A, S> Decryptor<A, S>
+where
+    A: AeadInPlace,
+    S: StreamPrimitive<A>,
+    A::NonceSize: Sub<<S as StreamPrimitive<A>>::NonceOverhead>,
+    NonceSize<A, S>: ArrayLength<u8>,
+{
+    /// Decrypt the concatenated ciphertexts of a message encrypted with
+    /// [`Encryptor::encrypt_segmented`] using the same [`SegmentSize`],
+    /// returning the plaintext.
+    ///
+    /// Returns an error if any segment is unauthentic, or if the message was
+    /// truncated or reordered.
+    pub fn decrypt_segmented(
+        mut self,
+        segment_size: SegmentSize,
+        associated_data: &[u8],
+        ciphertext: &[u8],
+    ) -> Result<Vec<u8>> {
+        let segment_len = segment_size.ciphertext_len::<A>();
+        let mut plaintext = Vec::with_capacity(ciphertext.len());
+        let mut remaining = ciphertext;
+
+        while remaining.len() > segment_len {
+            let (msg, rest) = remaining.split_at(segment_len);
+            let aad = associated_data;
+            plaintext.extend_from_slice(&self.decrypt_next(Payload { msg, aad })?);
+            remaining = rest;
+        }
+
+        let aad = associated_data;
+        plaintext.extend_from_slice(&self.decrypt_last(Payload {
+            msg: remaining,
+            aad,
+        })?);
+        Ok(plaintext)
+    }
+}
+
 /// The original "Rogaway-flavored" STREAM as described in the paper
 /// [Online Authenticated-Encryption and its Nonce-Reuse Misuse-Resistance][1].
 ///
@@ -540,3 +685,132 @@ where
         Ok(result)
     }
 }
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::{Decryptor, Encryptor, SegmentSize, StreamBE32, StreamLE31};
+    use crate::{tests::ToyAead, Error, KeyInit, Result};
+    use alloc::vec::Vec;
+
+    const KEY: [u8; 16] = [0x42; 16];
+    const AAD: &[u8] = b"segmented";
+    const SEGMENT_LEN: usize = 8;
+    const CIPHERTEXT_SEGMENT_LEN: usize = SEGMENT_LEN + 16;
+
+    fn segment_size() -> SegmentSize {
+        SegmentSize::new(SEGMENT_LEN as u32).expect("nonzero segment size")
+    }
+
+    fn encrypt(plaintext: &[u8]) -> Result<Vec<u8>> {
+        Encryptor::<ToyAead, StreamBE32<ToyAead>>::from_aead(
+            ToyAead::new(&KEY.into()),
+            &[0x24; 7].into(),
+        )
+        .encrypt_segmented(segment_size(), AAD, plaintext)
+    }
+
+    fn decrypt(ciphertext: &[u8]) -> Result<Vec<u8>> {
+        Decryptor::<ToyAead, StreamBE32<ToyAead>>::from_aead(
+            ToyAead::new(&KEY.into()),
+            &[0x24; 7].into(),
+        )
+        .decrypt_segmented(segment_size(), AAD, ciphertext)
+    }
+
+    fn plaintext(len: usize) -> Vec<u8> {
+        (0..len).map(|i| i as u8).collect()
+    }
+
+    #[test]
+    fn round_trip_empty() -> Result<()> {
+        let ciphertext = encrypt(&[])?;
+        assert_eq!(ciphertext.len(), 16);
+        assert_eq!(decrypt(&ciphertext)?, []);
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip_exact_segments() -> Result<()> {
+        for segments in 1..=3 {
+            let msg = plaintext(segments * SEGMENT_LEN);
+            let ciphertext = encrypt(&msg)?;
+            assert_eq!(ciphertext.len(), segments * CIPHERTEXT_SEGMENT_LEN);
+            assert_eq!(decrypt(&ciphertext)?, msg);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip_partial_segment() -> Result<()> {
+        for len in [1, SEGMENT_LEN - 1, SEGMENT_LEN + 1, 3 * SEGMENT_LEN - 3] {
+            let msg = plaintext(len);
+            let ciphertext = encrypt(&msg)?;
+            let segments = (len + SEGMENT_LEN - 1) / SEGMENT_LEN;
+            assert_eq!(ciphertext.len(), len + segments * 16);
+            assert_eq!(decrypt(&ciphertext)?, msg);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn round_trip_le31() -> Result<()> {
+        let msg = plaintext(2 * SEGMENT_LEN + 5);
+        let nonce = [0x24; 8].into();
+        let ciphertext = Encryptor::<ToyAead, StreamLE31<ToyAead>>::new(&KEY.into(), &nonce)
+            .encrypt_segmented(segment_size(), AAD, &msg)?;
+        let decrypted = Decryptor::<ToyAead, StreamLE31<ToyAead>>::new(&KEY.into(), &nonce)
+            .decrypt_segmented(segment_size(), AAD, &ciphertext)?;
+        assert_eq!(decrypted, msg);
+        Ok(())
+    }
+
+    #[test]
+    fn reject_truncated() -> Result<()> {
+        let ciphertext = encrypt(&plaintext(2 * SEGMENT_LEN + 5))?;
+
+        for len in [0, 15, ciphertext.len() - 1, CIPHERTEXT_SEGMENT_LEN + 1] {
+            assert_eq!(decrypt(&ciphertext[..len]), Err(Error));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn reject_reordered() -> Result<()> {
+        let ciphertext = encrypt(&plaintext(3 * SEGMENT_LEN))?;
+        let mut reordered = ciphertext.clone();
+        reordered[..2 * CIPHERTEXT_SEGMENT_LEN].rotate_left(CIPHERTEXT_SEGMENT_LEN);
+        assert_eq!(decrypt(&reordered), Err(Error));
+
+        // Moving the final segment forward is rejected as well
+        let mut reordered = ciphertext;
+        reordered[CIPHERTEXT_SEGMENT_LEN..].rotate_left(CIPHERTEXT_SEGMENT_LEN);
+        assert_eq!(decrypt(&reordered), Err(Error));
+        Ok(())
+    }
+
+    #[test]
+    fn reject_dropped_final_segment() -> Result<()> {
+        for msg in [plaintext(2 * SEGMENT_LEN), plaintext(2 * SEGMENT_LEN + 5)] {
+            let ciphertext = encrypt(&msg)?;
+            let non_final = ciphertext.len() - (ciphertext.len() - 1) % CIPHERTEXT_SEGMENT_LEN - 1;
+            assert_eq!(decrypt(&ciphertext[..non_final]), Err(Error));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn reject_tampered_associated_data() -> Result<()> {
+        let ciphertext = encrypt(&plaintext(SEGMENT_LEN + 1))?;
+        let decrypted = Decryptor::<ToyAead, StreamBE32<ToyAead>>::from_aead(
+            ToyAead::new(&KEY.into()),
+            &[0x24; 7].into(),
+        )
+        .decrypt_segmented(segment_size(), b"other", &ciphertext);
+        assert_eq!(decrypted, Err(Error));
+        Ok(())
+    }
+}
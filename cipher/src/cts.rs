@@ -0,0 +1,398 @@
+//! Ciphertext stealing (CTS) modes of operation.
+//!
+//! Ciphertext stealing allows encrypting messages which aren't a multiple of
+//! the block size without padding, so the ciphertext has the same length as
+//! the plaintext. Messages must be at least one block long.
+//!
+//! The CBC-CS1, CBC-CS2, and CBC-CS3 variants defined in the
+//! [addendum to NIST SP 800-38A][1] differ only in the order of the last two
+//! ciphertext blocks. CBC-CS3 is used by Kerberos ([RFC 3962][2]).
+//!
+//! [1]: https://csrc.nist.gov/publications/detail/sp/800-38a/addendum/final
+//! [2]: https://www.rfc-editor.org/rfc/rfc3962
+
+use crate::{
+    Block, BlockDecrypt, BlockEncrypt, BlockSizeUser, CtsError, InnerIvInit, Iv, IvSizeUser,
+};
+use core::marker::PhantomData;
+use crypto_common::{typenum::Unsigned, InnerUser};
+
+/// Encrypt-only functionality for block modes with ciphertext stealing.
+pub trait BlockModeCtsEncrypt: BlockSizeUser + Sized {
+    /// Encrypt a message in-place.
+    ///
+    /// Returns [`CtsError`] if the message is shorter than one block.
+    fn encrypt_cts(self, buf: &mut [u8]) -> Result<(), CtsError>;
+
+    /// Encrypt a message buffer-to-buffer.
+    ///
+    /// Returns [`CtsError`] if the message is shorter than one block, or if
+    /// the buffers have different lengths.
+    #[inline]
+    fn encrypt_cts_b2b(self, msg: &[u8], out_buf: &mut [u8]) -> Result<(), CtsError> {
+        if msg.len() != out_buf.len() {
+            return Err(CtsError);
+        }
+        out_buf.copy_from_slice(msg);
+        self.encrypt_cts(out_buf)
+    }
+}
+
+/// Decrypt-only functionality for block modes with ciphertext stealing.
+pub trait BlockModeCtsDecrypt: BlockSizeUser + Sized {
+    /// Decrypt a message in-place.
+    ///
+    /// Returns [`CtsError`] if the message is shorter than one block.
+    fn decrypt_cts(self, buf: &mut [u8]) -> Result<(), CtsError>;
+
+    /// Decrypt a message buffer-to-buffer.
+    ///
+    /// Returns [`CtsError`] if the message is shorter than one block, or if
+    /// the buffers have different lengths.
+    #[inline]
+    fn decrypt_cts_b2b(self, msg: &[u8], out_buf: &mut [u8]) -> Result<(), CtsError> {
+        if msg.len() != out_buf.len() {
+            return Err(CtsError);
+        }
+        out_buf.copy_from_slice(msg);
+        self.decrypt_cts(out_buf)
+    }
+}
+
+/// Variant of CBC mode with ciphertext stealing, which determines the order
+/// of the last two ciphertext blocks.
+pub trait CtsVariant {
+    /// Whether the last two ciphertext blocks are swapped, given the length
+    /// of the last (possibly partial) plaintext block and the block size.
+    fn swap_last_blocks(tail_len: usize, block_size: usize) -> bool;
+}
+
+/// CBC-CS1: the last two ciphertext blocks are never swapped.
+#[derive(Clone, Copy, Debug)]
+pub struct Cs1;
+
+/// CBC-CS2: the last two ciphertext blocks are swapped unless the message is
+/// a multiple of the block size, i.e. it's compatible with CBC mode.
+#[derive(Clone, Copy, Debug)]
+pub struct Cs2;
+
+/// CBC-CS3: the last two ciphertext blocks are always swapped.
+#[derive(Clone, Copy, Debug)]
+pub struct Cs3;
+
+impl CtsVariant for Cs1 {
+    #[inline]
+    fn swap_last_blocks(_tail_len: usize, _block_size: usize) -> bool {
+        false
+    }
+}
+
+impl CtsVariant for Cs2 {
+    #[inline]
+    fn swap_last_blocks(tail_len: usize, block_size: usize) -> bool {
+        tail_len != block_size
+    }
+}
+
+impl CtsVariant for Cs3 {
+    #[inline]
+    fn swap_last_blocks(_tail_len: usize, _block_size: usize) -> bool {
+        true
+    }
+}
+
+/// CBC-CS1 mode encryptor.
+pub type CbcCs1Encrypt<C> = CbcCtsEncrypt<C, Cs1>;
+/// CBC-CS1 mode decryptor.
+pub type CbcCs1Decrypt<C> = CbcCtsDecrypt<C, Cs1>;
+/// CBC-CS2 mode encryptor.
+pub type CbcCs2Encrypt<C> = CbcCtsEncrypt<C, Cs2>;
+/// CBC-CS2 mode decryptor.
+pub type CbcCs2Decrypt<C> = CbcCtsDecrypt<C, Cs2>;
+/// CBC-CS3 mode encryptor.
+pub type CbcCs3Encrypt<C> = CbcCtsEncrypt<C, Cs3>;
+/// CBC-CS3 mode decryptor.
+pub type CbcCs3Decrypt<C> = CbcCtsDecrypt<C, Cs3>;
+
+/// Generic CBC mode encryptor with ciphertext stealing.
+#[derive(Clone)]
+pub struct CbcCtsEncrypt<C: BlockEncrypt, V: CtsVariant> {
+    cipher: C,
+    iv: Block<C>,
+    variant: PhantomData<V>,
+}
+
+/// Generic CBC mode decryptor with ciphertext stealing.
+#[derive(Clone)]
+pub struct CbcCtsDecrypt<C: BlockDecrypt, V: CtsVariant> {
+    cipher: C,
+    iv: Block<C>,
+    variant: PhantomData<V>,
+}
+
+/// Split a message into the length of the full blocks preceding the last two
+/// blocks, and the length of the last (possibly partial) block.
+#[inline]
+fn split_lens(len: usize, block_size: usize) -> Result<(usize, usize), CtsError> {
+    if len < block_size {
+        return Err(CtsError);
+    }
+    let tail_len = match len % block_size {
+        0 => block_size,
+        n => n,
+    };
+    Ok((len.saturating_sub(tail_len + block_size), tail_len))
+}
+
+#[inline]
+fn xor(out: &mut [u8], val: &[u8]) {
+    for (a, b) in out.iter_mut().zip(val) {
+        *a ^= *b;
+    }
+}
+
+macro_rules! impl_cts_common {
+    ($name:ident, $cipher_trait:ident) => {
+        impl<C: $cipher_trait, V: CtsVariant> BlockSizeUser for $name<C, V> {
+            type BlockSize = C::BlockSize;
+        }
+
+        impl<C: $cipher_trait, V: CtsVariant> InnerUser for $name<C, V> {
+            type Inner = C;
+        }
+
+        impl<C: $cipher_trait, V: CtsVariant> IvSizeUser for $name<C, V> {
+            type IvSize = C::BlockSize;
+        }
+
+        impl<C: $cipher_trait, V: CtsVariant> InnerIvInit for $name<C, V> {
+            #[inline]
+            fn inner_iv_init(cipher: C, iv: &Iv<Self>) -> Self {
+                Self {
+                    cipher,
+                    iv: iv.clone(),
+                    variant: PhantomData,
+                }
+            }
+        }
+    };
+}
+
+impl_cts_common!(CbcCtsEncrypt, BlockEncrypt);
+impl_cts_common!(CbcCtsDecrypt, BlockDecrypt);
+
+impl<C: BlockEncrypt, V: CtsVariant> BlockModeCtsEncrypt for CbcCtsEncrypt<C, V> {
+    fn encrypt_cts(self, buf: &mut [u8]) -> Result<(), CtsError> {
+        let bs = C::BlockSize::USIZE;
+        let (head_len, tail_len) = split_lens(buf.len(), bs)?;
+        let mut chain = self.iv;
+
+        if buf.len() == bs {
+            xor(&mut chain, buf);
+            self.cipher.encrypt_block(&mut chain);
+            buf.copy_from_slice(&chain);
+            return Ok(());
+        }
+
+        let (head, tail) = buf.split_at_mut(head_len);
+        for block in head.chunks_exact_mut(bs) {
+            xor(&mut chain, block);
+            self.cipher.encrypt_block(&mut chain);
+            block.copy_from_slice(&chain);
+        }
+
+        // Penultimate ciphertext block
+        xor(&mut chain, &tail[..bs]);
+        self.cipher.encrypt_block(&mut chain);
+
+        // Last ciphertext block, encrypted from the zero-padded plaintext
+        let mut last = chain.clone();
+        xor(&mut last, &tail[bs..]);
+        self.cipher.encrypt_block(&mut last);
+
+        if V::swap_last_blocks(tail_len, bs) {
+            tail[bs..].copy_from_slice(&chain[..tail_len]);
+            tail[..bs].copy_from_slice(&last);
+        } else {
+            tail[..tail_len].copy_from_slice(&chain[..tail_len]);
+            tail[tail_len..].copy_from_slice(&last);
+        }
+
+        Ok(())
+    }
+}
+
+impl<C: BlockDecrypt, V: CtsVariant> BlockModeCtsDecrypt for CbcCtsDecrypt<C, V> {
+    fn decrypt_cts(self, buf: &mut [u8]) -> Result<(), CtsError> {
+        let bs = C::BlockSize::USIZE;
+        let (head_len, tail_len) = split_lens(buf.len(), bs)?;
+        let mut chain = self.iv;
+
+        if buf.len() == bs {
+            let mut block = Block::<C>::clone_from_slice(buf);
+            self.cipher.decrypt_block(&mut block);
+            xor(&mut block, &chain);
+            buf.copy_from_slice(&block);
+            return Ok(());
+        }
+
+        let (head, tail) = buf.split_at_mut(head_len);
+        for block in head.chunks_exact_mut(bs) {
+            let ciphertext = Block::<C>::clone_from_slice(block);
+            let mut plaintext = ciphertext.clone();
+            self.cipher.decrypt_block(&mut plaintext);
+            xor(&mut plaintext, &chain);
+            block.copy_from_slice(&plaintext);
+            chain = ciphertext;
+        }
+
+        // Reassemble the last two ciphertext blocks in CS1 order
+        let (mut stolen, mut last) = (Block::<C>::default(), Block::<C>::default());
+        if V::swap_last_blocks(tail_len, bs) {
+            last.copy_from_slice(&tail[..bs]);
+            stolen[..tail_len].copy_from_slice(&tail[bs..]);
+        } else {
+            stolen[..tail_len].copy_from_slice(&tail[..tail_len]);
+            last.copy_from_slice(&tail[tail_len..]);
+        }
+
+        // Decrypting the last block yields the zero-padded last plaintext
+        // block XORed with the penultimate ciphertext block, whose stolen
+        // bytes can be recovered from the padding
+        self.cipher.decrypt_block(&mut last);
+        stolen[tail_len..].copy_from_slice(&last[tail_len..]);
+        xor(&mut last, &stolen);
+
+        self.cipher.decrypt_block(&mut stolen);
+        xor(&mut stolen, &chain);
+
+        tail[..bs].copy_from_slice(&stolen);
+        tail[bs..].copy_from_slice(&last[..tail_len]);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests::ToyBlockCipher, BlockEncrypt, KeyInit, KeyIvInit};
+    use std::{vec, vec::Vec};
+
+    const KEY: [u8; 16] = [0x42; 16];
+    const IV: [u8; 16] = [0x24; 16];
+
+    /// Lengths exercised by the tests: exactly one block, exact multiples of
+    /// the block size, and partial final blocks.
+    const LENS: &[usize] = &[16, 17, 31, 32, 33, 47, 48, 63, 64];
+
+    fn plaintext(len: usize) -> Vec<u8> {
+        (0..len).map(|i| i as u8).collect()
+    }
+
+    /// CBC-CS1 as specified in the addendum to NIST SP 800-38A: CBC over the
+    /// zero-padded message, with the penultimate ciphertext block truncated
+    /// to the length of the last plaintext block.
+    fn reference_cs1(msg: &[u8]) -> Vec<u8> {
+        let cipher = ToyBlockCipher::new(&KEY.into());
+        let tail_len = match msg.len() % 16 {
+            0 => 16,
+            n => n,
+        };
+
+        let mut padded = msg.to_vec();
+        padded.resize(msg.len() - tail_len + 16, 0);
+
+        let mut chain = Block::<ToyBlockCipher>::from(IV);
+        for block in padded.chunks_exact_mut(16) {
+            xor(&mut chain, block);
+            cipher.encrypt_block(&mut chain);
+            block.copy_from_slice(&chain);
+        }
+
+        if msg.len() > 16 {
+            let penultimate = padded.len() - 32;
+            padded.copy_within(penultimate + 16.., penultimate + tail_len);
+            padded.truncate(msg.len());
+        }
+
+        padded
+    }
+
+    /// Swap the last two blocks of a CBC-CS1 ciphertext, which yields
+    /// CBC-CS3 order.
+    fn swap_last_blocks(cs1: &[u8]) -> Vec<u8> {
+        if cs1.len() == 16 {
+            return cs1.to_vec();
+        }
+
+        let tail_len = match cs1.len() % 16 {
+            0 => 16,
+            n => n,
+        };
+        let head_len = cs1.len() - tail_len - 16;
+
+        let mut swapped = cs1[..head_len].to_vec();
+        swapped.extend_from_slice(&cs1[head_len + tail_len..]);
+        swapped.extend_from_slice(&cs1[head_len..head_len + tail_len]);
+        swapped
+    }
+
+    fn check<V: CtsVariant>(expected: impl Fn(&[u8]) -> Vec<u8>) {
+        for &len in LENS {
+            let msg = plaintext(len);
+            let mut buf = msg.clone();
+            CbcCtsEncrypt::<ToyBlockCipher, V>::new(&KEY.into(), &IV.into())
+                .encrypt_cts(&mut buf)
+                .unwrap();
+            assert_eq!(buf, expected(&msg), "length {}", len);
+
+            let mut out = vec![0; len];
+            CbcCtsDecrypt::<ToyBlockCipher, V>::new(&KEY.into(), &IV.into())
+                .decrypt_cts_b2b(&buf, &mut out)
+                .unwrap();
+            assert_eq!(out, msg, "length {}", len);
+        }
+    }
+
+    #[test]
+    fn cs1() {
+        check::<Cs1>(reference_cs1);
+    }
+
+    #[test]
+    fn cs2() {
+        check::<Cs2>(|msg| match msg.len() % 16 {
+            0 => reference_cs1(msg),
+            _ => swap_last_blocks(&reference_cs1(msg)),
+        });
+    }
+
+    #[test]
+    fn cs3() {
+        check::<Cs3>(|msg| swap_last_blocks(&reference_cs1(msg)));
+    }
+
+    fn reject_short<V: CtsVariant>() {
+        for len in [0, 1, 15] {
+            let mut buf = vec![0; len];
+            let encryptor = CbcCtsEncrypt::<ToyBlockCipher, V>::new(&KEY.into(), &IV.into());
+            assert!(encryptor.encrypt_cts(&mut buf).is_err());
+            let decryptor = CbcCtsDecrypt::<ToyBlockCipher, V>::new(&KEY.into(), &IV.into());
+            assert!(decryptor.decrypt_cts(&mut buf).is_err());
+        }
+
+        // Buffer-to-buffer operation requires buffers of equal length
+        let mut out = [0; 17];
+        let encryptor = CbcCtsEncrypt::<ToyBlockCipher, V>::new(&KEY.into(), &IV.into());
+        assert!(encryptor.encrypt_cts_b2b(&[0; 16], &mut out).is_err());
+        let decryptor = CbcCtsDecrypt::<ToyBlockCipher, V>::new(&KEY.into(), &IV.into());
+        assert!(decryptor.decrypt_cts_b2b(&[0; 18], &mut out).is_err());
+    }
+
+    #[test]
+    fn reject_short_inputs() {
+        reject_short::<Cs1>();
+        reject_short::<Cs2>();
+        reject_short::<Cs3>();
+    }
+}
@@ -21,6 +21,52 @@ impl fmt::Display for StreamCipherError {
 #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
 impl std::error::Error for StreamCipherError {}
 
+/// The error type returned by the [`BlockModeCtsEncrypt`][crate::BlockModeCtsEncrypt]
+/// and [`BlockModeCtsDecrypt`][crate::BlockModeCtsDecrypt] trait methods when
+/// the message is shorter than one block.
+#[derive(Copy, Clone, Debug)]
+pub struct CtsError;
+
+impl fmt::Display for CtsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_str("message too short for ciphertext stealing")
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for CtsError {}
+
+/// The error type returned by the [`FpeCipher`][crate::FpeCipher] trait
+/// methods when the radix, numeral string, or tweak is unsupported.
+#[derive(Copy, Clone, Debug)]
+pub struct FpeError;
+
+impl fmt::Display for FpeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_str("invalid format-preserving encryption input")
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for FpeError {}
+
+/// The error type returned by the [`DiskCipher`][crate::DiskCipher] trait
+/// methods when the sector size is unsupported.
+#[derive(Copy, Clone, Debug)]
+pub struct SectorSizeError;
+
+impl fmt::Display for SectorSizeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_str("unsupported sector size")
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for SectorSizeError {}
+
 /// The error type returned when a cipher position can not be represented
 /// by the requested type.
 #[derive(Copy, Clone, Debug)]
@@ -0,0 +1,450 @@
+//! Format-preserving encryption (FPE).
+//!
+//! Format-preserving encryption maps a string of numerals in a given radix
+//! (e.g. the decimal digits of a credit card number) to a ciphertext string
+//! of the same length and radix, which is useful for tokenizing data whose
+//! format can't be changed.
+//!
+//! This module provides a generic implementation of the FF1 mode from
+//! [NIST SP 800-38G][1] over any block cipher with a 128-bit block size.
+//!
+//! [1]: https://csrc.nist.gov/publications/detail/sp/800-38g/final
+
+use crate::{consts::U16, Block, BlockEncrypt, FpeError};
+
+/// Maximum length in bytes of the integer representing half of a numeral
+/// string, which bounds the length of messages supported by [`Ff1`].
+const MAX_NUM_LEN: usize = 64;
+
+/// Minimum size of the message domain, i.e. `radix^len`.
+const MIN_DOMAIN_SIZE: u64 = 1_000_000;
+
+/// Format-preserving encryption of numeral strings with tweaks.
+///
+/// Numerals are represented as `u16` values which must be smaller than the
+/// [radix][`FpeCipher::radix`]. Tweaks are public values which change the
+/// permutation, similarly to a nonce, but may be reused.
+pub trait FpeCipher {
+    /// Radix of the numeral strings processed by this cipher.
+    fn radix(&self) -> u32;
+
+    /// Encrypt the numeral string in-place using the given tweak.
+    ///
+    /// Returns [`FpeError`] if the length of the string or tweak is
+    /// unsupported, or if any numeral isn't smaller than the radix.
+    fn encrypt_fpe(&self, tweak: &[u8], numerals: &mut [u16]) -> Result<(), FpeError>;
+
+    /// Decrypt the numeral string in-place using the given tweak.
+    ///
+    /// Returns [`FpeError`] if the length of the string or tweak is
+    /// unsupported, or if any numeral isn't smaller than the radix.
+    fn decrypt_fpe(&self, tweak: &[u8], numerals: &mut [u16]) -> Result<(), FpeError>;
+}
+
+/// FF1 format-preserving encryption mode over a block cipher with a 128-bit
+/// block size.
+///
+/// Strings must be at least long enough that `radix^len >= 1_000_000`,
+/// and short enough that `radix^v < 2^512`, where `v` is the length of the
+/// longer half of the string.
+#[derive(Clone)]
+pub struct Ff1<C: BlockEncrypt<BlockSize = U16>> {
+    cipher: C,
+    radix: u32,
+}
+
+impl<C: BlockEncrypt<BlockSize = U16>> Ff1<C> {
+    /// Create a new FF1 instance for the given radix, which must be in the
+    /// range `2..=65536`.
+    pub fn new(cipher: C, radix: u32) -> Result<Self, FpeError> {
+        if !(2..=0x10000).contains(&radix) {
+            return Err(FpeError);
+        }
+        Ok(Self { cipher, radix })
+    }
+
+    /// Validate the numerals, and compute the byte length `b` of the
+    /// integer representing the longer half of the string.
+    fn check(&self, tweak: &[u8], numerals: &[u16]) -> Result<usize, FpeError> {
+        let radix = self.radix;
+        if u32::try_from(tweak.len()).is_err() || u32::try_from(numerals.len()).is_err() {
+            return Err(FpeError);
+        }
+        if numerals.iter().any(|&x| u32::from(x) >= radix) {
+            return Err(FpeError);
+        }
+
+        let mut domain_size = 1u64;
+        for _ in 0..numerals.len() {
+            domain_size = domain_size.saturating_mul(radix.into());
+        }
+        if domain_size < MIN_DOMAIN_SIZE {
+            return Err(FpeError);
+        }
+
+        // `ceil(v * log2(radix))` is the bit length of `radix^v - 1`
+        let v = numerals.len() - numerals.len() / 2;
+        let mut max = [0u8; MAX_NUM_LEN];
+        max[MAX_NUM_LEN - 1] = 1;
+        for _ in 0..v {
+            if mul_add(&mut max, radix, 0) != 0 {
+                return Err(FpeError);
+            }
+        }
+        sub_one(&mut max);
+
+        let bits = match max.iter().position(|&byte| byte != 0) {
+            Some(i) => (MAX_NUM_LEN - i) * 8 - max[i].leading_zeros() as usize,
+            None => 0,
+        };
+        Ok((bits + 7) / 8)
+    }
+
+    /// Compute the round function of round `i` over one half of the string,
+    /// writing `y mod radix^m` as numerals into `out`, where `m` is the
+    /// length of `out`.
+    fn round(&self, tweak: &[u8], n: usize, b: usize, i: u8, half: &[u16], out: &mut [u16]) {
+        let u = n / 2;
+        let radix = self.radix.to_be_bytes();
+        let mut mac = CbcMac::new(&self.cipher);
+
+        mac.update(&[1, 2, 1, radix[1], radix[2], radix[3], 10, u as u8]);
+        mac.update(&(n as u32).to_be_bytes());
+        mac.update(&(tweak.len() as u32).to_be_bytes());
+        mac.update(tweak);
+        let pad_len = (16 - (tweak.len() + b + 1) % 16) % 16;
+        mac.update(&[0u8; 16][..pad_len]);
+        mac.update(&[i]);
+
+        let mut num = [0u8; MAX_NUM_LEN];
+        for &x in half {
+            mul_add(&mut num, self.radix, x.into());
+        }
+        mac.update(&num[MAX_NUM_LEN - b..]);
+        let r = mac.finalize();
+
+        // `S` consists of `R` followed by encryptions of `R` XOR a counter
+        let d = 4 * ((b + 3) / 4) + 4;
+        let mut s = [0u8; MAX_NUM_LEN + 16];
+        for (j, chunk) in s[..d].chunks_mut(16).enumerate() {
+            let mut block = r;
+            if j > 0 {
+                for (a, c) in block[8..].iter_mut().zip((j as u64).to_be_bytes()) {
+                    *a ^= c;
+                }
+                self.cipher.encrypt_block(&mut block);
+            }
+            chunk.copy_from_slice(&block[..chunk.len()]);
+        }
+
+        for y in out.iter_mut().rev() {
+            *y = div_rem(&mut s[..d], self.radix) as u16;
+        }
+    }
+}
+
+impl<C: BlockEncrypt<BlockSize = U16>> FpeCipher for Ff1<C> {
+    fn radix(&self) -> u32 {
+        self.radix
+    }
+
+    fn encrypt_fpe(&self, tweak: &[u8], numerals: &mut [u16]) -> Result<(), FpeError> {
+        let b = self.check(tweak, numerals)?;
+        let n = numerals.len();
+        let (mut a, mut b_half) = numerals.split_at_mut(n / 2);
+        let mut y = [0u16; MAX_NUM_LEN * 8];
+
+        for i in 0..10 {
+            let m = a.len();
+            self.round(tweak, n, b, i, b_half, &mut y[..m]);
+            add_mod(a, &y[..m], self.radix);
+            core::mem::swap(&mut a, &mut b_half);
+        }
+
+        Ok(())
+    }
+
+    fn decrypt_fpe(&self, tweak: &[u8], numerals: &mut [u16]) -> Result<(), FpeError> {
+        let b = self.check(tweak, numerals)?;
+        let n = numerals.len();
+        let (mut a, mut b_half) = numerals.split_at_mut(n / 2);
+        let mut y = [0u16; MAX_NUM_LEN * 8];
+
+        for i in (0..10).rev() {
+            let m = b_half.len();
+            self.round(tweak, n, b, i, a, &mut y[..m]);
+            sub_mod(b_half, &y[..m], self.radix);
+            core::mem::swap(&mut a, &mut b_half);
+        }
+
+        Ok(())
+    }
+}
+
+/// CBC-MAC with a zero IV, as used by the FF1 PRF.
+struct CbcMac<'a, C: BlockEncrypt<BlockSize = U16>> {
+    cipher: &'a C,
+    state: Block<C>,
+    pos: usize,
+}
+
+impl<'a, C: BlockEncrypt<BlockSize = U16>> CbcMac<'a, C> {
+    fn new(cipher: &'a C) -> Self {
+        Self {
+            cipher,
+            state: Default::default(),
+            pos: 0,
+        }
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        for &byte in data {
+            self.state[self.pos] ^= byte;
+            self.pos += 1;
+            if self.pos == 16 {
+                self.cipher.encrypt_block(&mut self.state);
+                self.pos = 0;
+            }
+        }
+    }
+
+    /// Return the MAC. FF1 always inputs a multiple of the block size.
+    fn finalize(self) -> Block<C> {
+        debug_assert_eq!(self.pos, 0);
+        self.state
+    }
+}
+
+/// Compute `num = num * mul + add` for a big endian integer, returning the
+/// overflow.
+fn mul_add(num: &mut [u8], mul: u32, add: u32) -> u32 {
+    let mut carry = u64::from(add);
+    for byte in num.iter_mut().rev() {
+        let val = u64::from(*byte) * u64::from(mul) + carry;
+        *byte = val as u8;
+        carry = val >> 8;
+    }
+    carry as u32
+}
+
+/// Compute `num = num / div` for a big endian integer, returning the
+/// remainder.
+fn div_rem(num: &mut [u8], div: u32) -> u32 {
+    let mut rem = 0u64;
+    for byte in num.iter_mut() {
+        let val = (rem << 8) | u64::from(*byte);
+        *byte = (val / u64::from(div)) as u8;
+        rem = val % u64::from(div);
+    }
+    rem as u32
+}
+
+/// Compute `num = num - 1` for a nonzero big endian integer.
+fn sub_one(num: &mut [u8]) {
+    for byte in num.iter_mut().rev() {
+        let (val, borrow) = byte.overflowing_sub(1);
+        *byte = val;
+        if !borrow {
+            break;
+        }
+    }
+}
+
+/// Compute `x = (x + y) mod radix^len` for numeral strings of equal length.
+fn add_mod(x: &mut [u16], y: &[u16], radix: u32) {
+    let mut carry = 0;
+    for (a, &b) in x.iter_mut().zip(y).rev() {
+        let val = u32::from(*a) + u32::from(b) + carry;
+        carry = (val >= radix) as u32;
+        *a = (val - carry * radix) as u16;
+    }
+}
+
+/// Compute `x = (x - y) mod radix^len` for numeral strings of equal length.
+fn sub_mod(x: &mut [u16], y: &[u16], radix: u32) {
+    let mut borrow = 0;
+    for (a, &b) in x.iter_mut().zip(y).rev() {
+        let val = (radix + u32::from(*a)) - u32::from(b) - borrow;
+        borrow = (val < radix) as u32;
+        *a = (val - (1 - borrow) * radix) as u16;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{tests::ToyBlockCipher, KeyInit};
+    use std::{vec, vec::Vec};
+
+    const KEY: [u8; 16] = [0x42; 16];
+
+    fn cipher() -> ToyBlockCipher {
+        ToyBlockCipher::new(&KEY.into())
+    }
+
+    fn num(radix: u32, x: &[u16]) -> u128 {
+        x.iter()
+            .fold(0, |acc, &d| acc * u128::from(radix) + u128::from(d))
+    }
+
+    fn str_m(radix: u32, m: usize, mut x: u128) -> Vec<u16> {
+        let mut out = vec![0; m];
+        for d in out.iter_mut().rev() {
+            *d = (x % u128::from(radix)) as u16;
+            x /= u128::from(radix);
+        }
+        out
+    }
+
+    /// FF1.Encrypt transcribed from NIST SP 800-38G (Algorithm 7), for
+    /// strings with `radix^v <= 2^64`, so all integers fit in a `u128`.
+    fn reference_encrypt(radix: u32, tweak: &[u8], x: &[u16]) -> Vec<u16> {
+        let cipher = cipher();
+        let (n, t) = (x.len(), tweak.len());
+        let (u, v) = (n / 2, n - n / 2);
+        let (mut a, mut b_half) = (x[..u].to_vec(), x[u..].to_vec());
+
+        let radix_v = u128::from(radix).pow(v as u32);
+        assert!(radix_v <= 1 << 64);
+        let b = (128 - (radix_v - 1).leading_zeros() as usize + 7) / 8;
+        let d = 4 * ((b + 3) / 4) + 4;
+        assert!(d <= 16);
+
+        let mut p = vec![1, 2, 1];
+        p.extend_from_slice(&radix.to_be_bytes()[1..]);
+        p.extend_from_slice(&[10, u as u8]);
+        p.extend_from_slice(&(n as u32).to_be_bytes());
+        p.extend_from_slice(&(t as u32).to_be_bytes());
+
+        for i in 0..10u8 {
+            let mut q = tweak.to_vec();
+            q.resize(t + (16 - (t + b + 1) % 16) % 16, 0);
+            q.push(i);
+            q.extend_from_slice(&num(radix, &b_half).to_be_bytes()[16 - b..]);
+
+            let mut r = Block::<ToyBlockCipher>::default();
+            for block in p.chunks(16).chain(q.chunks(16)) {
+                r.iter_mut().zip(block).for_each(|(r, x)| *r ^= x);
+                cipher.encrypt_block(&mut r);
+            }
+
+            let y = r[..d]
+                .iter()
+                .fold(0u128, |acc, &x| acc << 8 | u128::from(x));
+            let m = if i % 2 == 0 { u } else { v };
+            let radix_m = u128::from(radix).pow(m as u32);
+            let c = (num(radix, &a) % radix_m + y % radix_m) % radix_m;
+
+            a = b_half;
+            b_half = str_m(radix, m, c);
+        }
+
+        a.extend_from_slice(&b_half);
+        a
+    }
+
+    #[test]
+    fn matches_reference() {
+        let tweaks: [&[u8]; 4] = [&[], b"0123456789", b"tweak-12345", &[0xAA; 17]];
+
+        for (radix, len) in [
+            (2, 20),
+            (2, 64),
+            (10, 6),
+            (10, 10),
+            (10, 19),
+            (36, 19),
+            (0x10000, 2),
+        ] {
+            let ff1 = Ff1::new(cipher(), radix).unwrap();
+            let pt: Vec<u16> = (0..len)
+                .map(|i| ((i * 7 + 3) % radix as usize) as u16)
+                .collect();
+
+            for tweak in tweaks {
+                let mut buf = pt.clone();
+                ff1.encrypt_fpe(tweak, &mut buf).unwrap();
+                assert_eq!(buf, reference_encrypt(radix, tweak, &pt));
+            }
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        for (radix, len) in [
+            (2, 20),
+            (2, 300),
+            (10, 6),
+            (10, 7),
+            (10, 100),
+            (36, 31),
+            (0x10000, 2),
+        ] {
+            let ff1 = Ff1::new(cipher(), radix).unwrap();
+            assert_eq!(ff1.radix(), radix);
+            let pt: Vec<u16> = (0..len)
+                .map(|i| ((i * 7 + 3) % radix as usize) as u16)
+                .collect();
+
+            for tweak in [&[][..], b"tweak", &[0xAA; 17]] {
+                let mut buf = pt.clone();
+                ff1.encrypt_fpe(tweak, &mut buf).unwrap();
+                assert_ne!(buf, pt);
+                assert!(buf.iter().all(|&x| u32::from(x) < radix));
+                ff1.decrypt_fpe(tweak, &mut buf).unwrap();
+                assert_eq!(buf, pt);
+            }
+        }
+    }
+
+    #[test]
+    fn tweak_changes_ciphertext() {
+        let ff1 = Ff1::new(cipher(), 10).unwrap();
+        let (mut x, mut y) = ([1; 10], [1; 10]);
+        ff1.encrypt_fpe(b"a", &mut x).unwrap();
+        ff1.encrypt_fpe(b"b", &mut y).unwrap();
+        assert_ne!(x, y);
+    }
+
+    #[test]
+    fn reject_invalid_radix() {
+        for radix in [0, 1, 0x10001, u32::MAX] {
+            assert!(Ff1::new(cipher(), radix).is_err());
+        }
+
+        for radix in [2, 0x10000] {
+            assert!(Ff1::new(cipher(), radix).is_ok());
+        }
+    }
+
+    #[test]
+    fn reject_small_domain() {
+        // `radix^len` must be at least 10^6
+        for (radix, min_len) in [(2, 20), (10, 6), (36, 4), (1000, 2)] {
+            let ff1 = Ff1::new(cipher(), radix).unwrap();
+
+            for len in 0..min_len {
+                let mut buf = vec![0; len];
+                assert!(ff1.encrypt_fpe(&[], &mut buf).is_err());
+                assert!(ff1.decrypt_fpe(&[], &mut buf).is_err());
+            }
+
+            let mut buf = vec![0; min_len];
+            assert!(ff1.encrypt_fpe(&[], &mut buf).is_ok());
+        }
+    }
+
+    #[test]
+    fn reject_out_of_range_numerals() {
+        for radix in [2, 10, 36] {
+            let ff1 = Ff1::new(cipher(), radix).unwrap();
+
+            for i in [0, 9, 19] {
+                let mut buf = vec![0; 20];
+                buf[i] = radix as u16;
+                assert!(ff1.encrypt_fpe(&[], &mut buf).is_err());
+                assert!(ff1.decrypt_fpe(&[], &mut buf).is_err());
+            }
+        }
+    }
+}
@@ -20,7 +20,7 @@ pub use inout;
 #[cfg(all(feature = "block-padding", feature = "alloc"))]
 extern crate alloc;
 
-#[cfg(feature = "std")]
+#[cfg(any(feature = "std", test))]
 extern crate std;
 
 #[cfg(feature = "rand_core")]
@@ -39,14 +39,19 @@ pub use zeroize;
 pub use blobby;
 
 mod block;
+mod cts;
 #[cfg(feature = "dev")]
 mod dev;
 mod errors;
+mod fpe;
 mod stream;
 mod stream_core;
 mod stream_wrapper;
+mod tweak;
 
-pub use crate::{block::*, errors::*, stream::*, stream_core::*, stream_wrapper::*};
+pub use crate::{
+    block::*, cts::*, errors::*, fpe::*, stream::*, stream_core::*, stream_wrapper::*, tweak::*,
+};
 pub use crypto_common::{
     generic_array,
     typenum::{self, consts},
@@ -59,3 +64,83 @@ pub trait IvState: IvSizeUser {
     /// Returns current IV state.
     fn iv_state(&self) -> Iv<Self>;
 }
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use crate::{consts::U16, Block, Key, KeyInit, KeySizeUser};
+
+    /// Toy 128-bit block cipher for testing modes of operation: an 8-round
+    /// Feistel network whose round function is FNV-1a over the key, round
+    /// index and right half. It's a permutation, but not secure.
+    #[derive(Clone)]
+    pub(crate) struct ToyBlockCipher {
+        key: Key<Self>,
+    }
+
+    impl ToyBlockCipher {
+        fn round(&self, i: u8, half: &[u8]) -> u64 {
+            self.key
+                .iter()
+                .chain(&[i])
+                .chain(half)
+                .fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+                    (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+                })
+        }
+
+        fn encrypt(&self, block: &mut Block<Self>) {
+            for i in 0..8 {
+                let f = self.round(i, &block[8..]).to_be_bytes();
+                let (left, right) = block.split_at_mut(8);
+                left.iter_mut().zip(f).for_each(|(a, b)| *a ^= b);
+                left.swap_with_slice(right);
+            }
+        }
+
+        fn decrypt(&self, block: &mut Block<Self>) {
+            for i in (0..8).rev() {
+                let (left, right) = block.split_at_mut(8);
+                left.swap_with_slice(right);
+                let f = self.round(i, &block[8..]).to_be_bytes();
+                block[..8].iter_mut().zip(f).for_each(|(a, b)| *a ^= b);
+            }
+        }
+    }
+
+    impl KeySizeUser for ToyBlockCipher {
+        type KeySize = U16;
+    }
+
+    impl KeyInit for ToyBlockCipher {
+        fn new(key: &Key<Self>) -> Self {
+            Self { key: *key }
+        }
+    }
+
+    crate::impl_simple_block_encdec!(
+        ToyBlockCipher, U16, cipher, block,
+        encrypt: {
+            let mut b = block.clone_in();
+            cipher.encrypt(&mut b);
+            *block.get_out() = b;
+        }
+        decrypt: {
+            let mut b = block.clone_in();
+            cipher.decrypt(&mut b);
+            *block.get_out() = b;
+        }
+    );
+
+    #[test]
+    fn toy_block_cipher_round_trip() {
+        use crate::{BlockDecrypt, BlockEncrypt};
+
+        let cipher = ToyBlockCipher::new(&[0x42; 16].into());
+        let plaintext = Block::<ToyBlockCipher>::from([7; 16]);
+        let mut block = plaintext;
+        cipher.encrypt_block(&mut block);
+        assert_ne!(block, plaintext);
+        cipher.decrypt_block(&mut block);
+        assert_eq!(block, plaintext);
+    }
+}
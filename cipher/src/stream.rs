@@ -144,7 +144,17 @@ pub trait StreamCipher {
 ///
 /// Methods of this trait are generic over the [`SeekNum`] trait, which is
 /// implemented for primitive numeric types, i.e.: `i32`, `u32`, `u64`,
-/// `u128`, and `usize`.
+/// `u128`, and `usize`. The `*_u64` and `*_u128` methods are provided for
+/// callers which want to be explicit about the position type, e.g. to handle
+/// streams longer than 4 GiB on 32-bit targets.
+///
+/// # Overflow behavior
+///
+/// Positions are measured in bytes from the start of the keystream. The
+/// fallible methods never wrap around: seeking to a position which can't be
+/// represented by the cipher's block counter returns an error and leaves the
+/// current position unchanged, and getting a position which can't be
+/// represented by the requested type returns [`OverflowError`].
 pub trait StreamCipherSeek {
     /// Try to get current keystream position
     ///
@@ -172,6 +182,54 @@ pub trait StreamCipherSeek {
     fn seek<T: SeekNum>(&mut self, pos: T) {
         self.try_seek(pos).unwrap()
     }
+
+    /// Try to get current keystream position as a `u64`.
+    ///
+    /// Returns [`OverflowError`] if position can not be represented by `u64`
+    #[inline]
+    fn try_current_pos_u64(&self) -> Result<u64, OverflowError> {
+        self.try_current_pos()
+    }
+
+    /// Try to get current keystream position as a `u128`.
+    ///
+    /// Returns [`OverflowError`] if position can not be represented by `u128`
+    #[inline]
+    fn try_current_pos_u128(&self) -> Result<u128, OverflowError> {
+        self.try_current_pos()
+    }
+
+    /// Try to seek to the given `u64` position.
+    ///
+    /// Returns [`StreamCipherError`] if provided position value is bigger than
+    /// keystream length.
+    #[inline]
+    fn try_seek_u64(&mut self, pos: u64) -> Result<(), StreamCipherError> {
+        self.try_seek(pos)
+    }
+
+    /// Try to seek to the given `u128` position.
+    ///
+    /// Returns [`StreamCipherError`] if provided position value is bigger than
+    /// keystream length.
+    #[inline]
+    fn try_seek_u128(&mut self, pos: u128) -> Result<(), StreamCipherError> {
+        self.try_seek(pos)
+    }
+
+    /// Try to seek to the start of the keystream block with the given index.
+    ///
+    /// This avoids computing the byte position, which may overflow for long
+    /// keystreams.
+    ///
+    /// Returns [`StreamCipherError`] if provided block index is bigger than
+    /// keystream length, or if the cipher doesn't support block-level seeking,
+    /// as is the case for the default implementation.
+    #[inline]
+    fn try_seek_blocks(&mut self, block: u128) -> Result<(), StreamCipherError> {
+        let _ = block;
+        Err(StreamCipherError)
+    }
 }
 
 impl<C: StreamCipher> StreamCipher for &mut C {
@@ -163,6 +163,13 @@ where
         *pos = byte_pos;
         Ok(())
     }
+
+    fn try_seek_blocks(&mut self, block: u128) -> Result<(), StreamCipherError> {
+        let block_pos = T::Counter::try_from(block).map_err(|_| StreamCipherError)?;
+        self.core.set_block_pos(block_pos);
+        self.pos = 0;
+        Ok(())
+    }
 }
 
 // Note: ideally we would only implement the InitInner trait and everything
@@ -0,0 +1,119 @@
+//! Traits for tweakable block ciphers and disk encryption modes.
+//!
+//! Tweakable block ciphers take an additional public input, the "tweak",
+//! which selects one of a family of permutations. Disk encryption modes such
+//! as XTS and Adiantum use the index of a disk sector as the tweak, so that
+//! identical sectors encrypt differently depending on their location.
+
+use crate::{Block, BlockSizeUser, SectorSizeError};
+use crypto_common::generic_array::{ArrayLength, GenericArray};
+
+/// Types which use a tweak.
+pub trait TweakSizeUser {
+    /// Tweak size in bytes.
+    type TweakSize: ArrayLength<u8>;
+}
+
+/// Tweak used by [`TweakableBlockCipher`].
+pub type Tweak<T> = GenericArray<u8, <T as TweakSizeUser>::TweakSize>;
+
+/// Block cipher which takes a tweak in addition to the key.
+pub trait TweakableBlockCipher: BlockSizeUser + TweakSizeUser {
+    /// Encrypt a single block in-place using the given tweak.
+    fn encrypt_block_with_tweak(&self, tweak: &Tweak<Self>, block: &mut Block<Self>);
+
+    /// Decrypt a single block in-place using the given tweak.
+    fn decrypt_block_with_tweak(&self, tweak: &Tweak<Self>, block: &mut Block<Self>);
+
+    /// Encrypt blocks in-place using the given tweak.
+    #[inline]
+    fn encrypt_blocks_with_tweak(&self, tweak: &Tweak<Self>, blocks: &mut [Block<Self>]) {
+        for block in blocks {
+            self.encrypt_block_with_tweak(tweak, block);
+        }
+    }
+
+    /// Decrypt blocks in-place using the given tweak.
+    #[inline]
+    fn decrypt_blocks_with_tweak(&self, tweak: &Tweak<Self>, blocks: &mut [Block<Self>]) {
+        for block in blocks {
+            self.decrypt_block_with_tweak(tweak, block);
+        }
+    }
+}
+
+/// Sector-oriented disk encryption, e.g. XTS or Adiantum, which encrypts
+/// each sector independently using its index as the tweak.
+///
+/// Encryption is length-preserving, so the sector size is determined by the
+/// length of the provided buffer.
+pub trait DiskCipher {
+    /// Minimum supported sector size in bytes.
+    const MIN_SECTOR_SIZE: usize;
+
+    /// Encrypt the sector with the given index in-place.
+    ///
+    /// Returns [`SectorSizeError`] if the sector size is unsupported.
+    fn encrypt_sector(&self, index: u128, sector: &mut [u8]) -> Result<(), SectorSizeError>;
+
+    /// Decrypt the sector with the given index in-place.
+    ///
+    /// Returns [`SectorSizeError`] if the sector size is unsupported.
+    fn decrypt_sector(&self, index: u128, sector: &mut [u8]) -> Result<(), SectorSizeError>;
+
+    /// Encrypt consecutive sectors of `sector_size` bytes in-place, the first
+    /// of which has the index `first_index`.
+    ///
+    /// Returns [`SectorSizeError`] if the sector size is unsupported, if the
+    /// length of `data` isn't a multiple of it, or if the sector index
+    /// overflows.
+    #[inline]
+    fn encrypt_sectors(
+        &self,
+        first_index: u128,
+        sector_size: usize,
+        data: &mut [u8],
+    ) -> Result<(), SectorSizeError> {
+        for (index, sector) in sectors(first_index, sector_size, data)? {
+            self.encrypt_sector(index, sector)?;
+        }
+        Ok(())
+    }
+
+    /// Decrypt consecutive sectors of `sector_size` bytes in-place, the first
+    /// of which has the index `first_index`.
+    ///
+    /// Returns [`SectorSizeError`] if the sector size is unsupported, if the
+    /// length of `data` isn't a multiple of it, or if the sector index
+    /// overflows.
+    #[inline]
+    fn decrypt_sectors(
+        &self,
+        first_index: u128,
+        sector_size: usize,
+        data: &mut [u8],
+    ) -> Result<(), SectorSizeError> {
+        for (index, sector) in sectors(first_index, sector_size, data)? {
+            self.decrypt_sector(index, sector)?;
+        }
+        Ok(())
+    }
+}
+
+/// Split `data` into sectors along with their indices.
+fn sectors(
+    first_index: u128,
+    sector_size: usize,
+    data: &mut [u8],
+) -> Result<impl Iterator<Item = (u128, &mut [u8])>, SectorSizeError> {
+    if sector_size == 0 || data.len() % sector_size != 0 {
+        return Err(SectorSizeError);
+    }
+
+    let count = (data.len() / sector_size) as u128;
+    if count > 0 && first_index.checked_add(count - 1).is_none() {
+        return Err(SectorSizeError);
+    }
+
+    Ok((first_index..).zip(data.chunks_exact_mut(sector_size)))
+}
@@ -0,0 +1,96 @@
+//! Traits for key derivation functions (KDFs), e.g. HKDF or KMAC-based KDFs.
+//!
+//! These allow protocol implementations to be generic over the KDF in use,
+//! rather than hard-coding a particular one.
+
+use crate::InvalidLength;
+
+/// Maximum length of the label and context of [`ExpandLabeled`].
+const MAX_LABEL_LEN: usize = 255;
+
+/// One-shot key derivation function: derive output keying material from
+/// input keying material, a salt, and context-specific information.
+pub trait Kdf {
+    /// Derive output keying material filling `okm` from the input keying
+    /// material `ikm`, using the given `salt` and `info`.
+    ///
+    /// Returns [`InvalidLength`] if the length of `okm` is unsupported.
+    fn derive_key(
+        ikm: &[u8],
+        salt: &[u8],
+        info: &[u8],
+        okm: &mut [u8],
+    ) -> Result<(), InvalidLength>;
+}
+
+/// Extraction step of an extract-then-expand KDF such as HKDF, which
+/// condenses input keying material into a pseudorandom key.
+pub trait Extract: Sized {
+    /// Extract a pseudorandom key from the input keying material `ikm` using
+    /// the given `salt`, which may be empty, returning an instance which can
+    /// expand it.
+    fn extract(salt: &[u8], ikm: &[u8]) -> Self;
+}
+
+/// Expansion step of an extract-then-expand KDF such as HKDF, which expands
+/// a pseudorandom key into output keying material.
+pub trait Expand: Sized {
+    /// Create an instance from an existing pseudorandom key, e.g. a secret
+    /// derived earlier in a key schedule.
+    ///
+    /// Returns [`InvalidLength`] if `prk` is too short.
+    fn from_prk(prk: &[u8]) -> Result<Self, InvalidLength>;
+
+    /// Expand the pseudorandom key into output keying material filling `okm`,
+    /// using the given context-specific `info`.
+    ///
+    /// Returns [`InvalidLength`] if `okm` is too long.
+    fn expand(&self, info: &[u8], okm: &mut [u8]) -> Result<(), InvalidLength>;
+}
+
+/// Labeled expansion in the style of `HKDF-Expand-Label` from [TLS 1.3],
+/// which binds the output to a label and context.
+///
+/// The provided implementation calls [`Expand::expand`] with the
+/// `HkdfLabel` structure as `info`: the length of `okm` as a 16-bit big
+/// endian integer, followed by the label and context each prefixed by their
+/// length as a single byte.
+///
+/// [TLS 1.3]: https://www.rfc-editor.org/rfc/rfc8446#section-7.1
+pub trait ExpandLabeled: Expand {
+    /// Expand the pseudorandom key into output keying material filling `okm`,
+    /// using the given `label` and `context`.
+    ///
+    /// The `label` must include any protocol-specific prefix, e.g.
+    /// `b"tls13 key"` for TLS 1.3.
+    ///
+    /// Returns [`InvalidLength`] if `okm` is too long, or if `label` or
+    /// `context` is longer than 255 bytes.
+    fn expand_label(
+        &self,
+        label: &[u8],
+        context: &[u8],
+        okm: &mut [u8],
+    ) -> Result<(), InvalidLength> {
+        let okm_len = u16::try_from(okm.len()).map_err(|_| InvalidLength)?;
+        if label.len() > MAX_LABEL_LEN || context.len() > MAX_LABEL_LEN {
+            return Err(InvalidLength);
+        }
+
+        let mut info = [0u8; 4 + 2 * MAX_LABEL_LEN];
+        let mut pos = 0;
+
+        for part in [
+            &okm_len.to_be_bytes()[..],
+            &[label.len() as u8],
+            label,
+            &[context.len() as u8],
+            context,
+        ] {
+            info[pos..][..part.len()].copy_from_slice(part);
+            pos += part.len();
+        }
+
+        self.expand(&info[..pos], okm)
+    }
+}
@@ -14,10 +14,18 @@ extern crate std;
 
 #[cfg(feature = "rand_core")]
 pub use rand_core;
+#[cfg(feature = "secret")]
+pub use {subtle, zeroize};
 
 pub use generic_array;
 pub use generic_array::typenum;
 
+pub mod kdf;
+pub mod opaque;
+#[cfg(feature = "secret")]
+#[cfg_attr(docsrs, doc(cfg(feature = "secret")))]
+pub mod secret;
+
 use core::fmt;
 use generic_array::{typenum::Unsigned, ArrayLength, GenericArray};
 #[cfg(feature = "rand_core")]
@@ -0,0 +1,35 @@
+//! Traits for keys which are referenced by an opaque handle rather than by
+//! their bytes, e.g. keys stored in a hardware security module (HSM) or an
+//! OS keystore.
+//!
+//! Types implementing these traits never expose key material: all
+//! operations are performed by the backend holding the key, and the type
+//! only stores a token identifying it. Since [`OpaqueKey`] extends
+//! [`KeySizeUser`], such types can still implement the cipher, AEAD and
+//! signature traits which are generic over the key size, but they should
+//! not implement [`KeyInit`][crate::KeyInit] or otherwise allow keys to be
+//! imported from or exported to bytes.
+
+use crate::KeySizeUser;
+
+/// Key referenced by an opaque handle (e.g. a PKCS#11 object handle or a
+/// keystore alias) rather than by its bytes.
+///
+/// [`KeySizeUser::KeySize`] is the size of the key held by the backend.
+pub trait OpaqueKey: KeySizeUser {
+    /// Handle which identifies the key to its backend.
+    type Handle: ?Sized;
+
+    /// Get the handle which identifies this key.
+    fn key_handle(&self) -> &Self::Handle;
+}
+
+/// Types which can be initialized from the handle of an existing key.
+pub trait OpaqueKeyInit: OpaqueKey + Sized {
+    /// Error returned when the handle doesn't refer to a usable key, e.g.
+    /// because it doesn't exist or has a different size or algorithm.
+    type Error;
+
+    /// Look up the key referenced by `handle`.
+    fn from_handle(handle: &Self::Handle) -> Result<Self, Self::Error>;
+}
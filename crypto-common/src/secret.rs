@@ -0,0 +1,67 @@
+//! Traits for serializing and deserializing secret values, e.g. private keys.
+//!
+//! Implementing these traits for a secret type provides a single, vetted
+//! path for converting it to and from bytes:
+//!
+//! - serialized secrets are always returned in a [`Zeroizing`] container,
+//!   so they are erased from memory when dropped;
+//! - decoding is performed in constant time via the
+//!   [`DeserializableSecret::from_secret_bytes_ct`] hook, which returns a
+//!   [`CtOption`] instead of branching on the validity of the secret.
+
+use core::fmt;
+use generic_array::{typenum::Unsigned, ArrayLength, GenericArray};
+use subtle::CtOption;
+use zeroize::Zeroizing;
+
+/// Serialized form of a [`SerializableSecret`], which is zeroized on drop.
+pub type SecretBytes<T> = Zeroizing<GenericArray<u8, <T as SerializableSecret>::SecretSize>>;
+
+/// Secret values which can be serialized as a fixed-size byte string.
+pub trait SerializableSecret {
+    /// Size of the serialized secret in bytes.
+    type SecretSize: ArrayLength<u8>;
+
+    /// Serialize this secret into a buffer which is zeroized on drop.
+    fn to_secret_bytes(&self) -> SecretBytes<Self>;
+}
+
+/// Secret values which can be deserialized from the byte string produced by
+/// [`SerializableSecret::to_secret_bytes`].
+pub trait DeserializableSecret: SerializableSecret + Sized {
+    /// Decode a secret in constant time.
+    ///
+    /// Implementations must not branch on the contents of `bytes`, including
+    /// when checking whether they are a valid encoding: the result is
+    /// instead returned as a [`CtOption`] which is none for invalid
+    /// encodings.
+    fn from_secret_bytes_ct(bytes: &GenericArray<u8, Self::SecretSize>) -> CtOption<Self>;
+
+    /// Decode a secret from a byte slice.
+    ///
+    /// Only the length of `bytes` and whether they are a valid encoding are
+    /// revealed through timing, the latter only once decoding is complete.
+    fn from_secret_slice(bytes: &[u8]) -> Result<Self, InvalidSecret> {
+        if bytes.len() != Self::SecretSize::USIZE {
+            return Err(InvalidSecret);
+        }
+
+        let bytes = Zeroizing::new(GenericArray::clone_from_slice(bytes));
+        Option::from(Self::from_secret_bytes_ct(&bytes)).ok_or(InvalidSecret)
+    }
+}
+
+/// The error type returned when a secret couldn't be deserialized, either
+/// because of a length mismatch or because it is an invalid encoding.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct InvalidSecret;
+
+impl fmt::Display for InvalidSecret {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
+        f.write_str("Invalid Secret")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InvalidSecret {}
@@ -0,0 +1,181 @@
+//! Adapters which hash data as it's written to or read from an I/O object.
+
+use crate::{FixedOutput, Output, Update};
+use std::io;
+
+#[cfg(feature = "futures-io")]
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Writer which hashes all data written through it to the inner writer.
+///
+/// Only data which the inner writer accepts is hashed, so the digest always
+/// corresponds to the data which was actually written.
+#[derive(Clone, Debug, Default)]
+pub struct HashWriter<D, W> {
+    hasher: D,
+    inner: W,
+}
+
+impl<D, W> HashWriter<D, W> {
+    /// Create a new writer which hashes data written to `inner` using
+    /// `hasher`.
+    pub fn new(hasher: D, inner: W) -> Self {
+        Self { hasher, inner }
+    }
+
+    /// Get a reference to the hasher.
+    pub fn hasher(&self) -> &D {
+        &self.hasher
+    }
+
+    /// Get a reference to the inner writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner writer.
+    ///
+    /// Data written directly to the inner writer isn't hashed.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Consume the writer, returning the hasher and the inner writer.
+    pub fn into_parts(self) -> (D, W) {
+        (self.hasher, self.inner)
+    }
+
+    /// Consume the writer, returning the inner writer and the digest of the
+    /// data written to it.
+    ///
+    /// Note that the inner writer isn't flushed.
+    pub fn finalize(self) -> (W, Output<D>)
+    where
+        D: FixedOutput,
+    {
+        (self.inner, self.hasher.finalize_fixed())
+    }
+}
+
+impl<D: Update, W: io::Write> io::Write for HashWriter<D, W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.hasher.update(&buf[..n]);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(feature = "futures-io")]
+#[cfg_attr(docsrs, doc(cfg(feature = "futures-io")))]
+impl<D, W> futures_io::AsyncWrite for HashWriter<D, W>
+where
+    D: Update + Unpin,
+    W: futures_io::AsyncWrite + Unpin,
+{
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        let result = Pin::new(&mut this.inner).poll_write(cx, buf);
+
+        if let Poll::Ready(Ok(n)) = result {
+            this.hasher.update(&buf[..n]);
+        }
+
+        result
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_close(cx)
+    }
+}
+
+/// Reader which hashes all data read through it from the inner reader.
+#[derive(Clone, Debug, Default)]
+pub struct HashReader<D, R> {
+    hasher: D,
+    inner: R,
+}
+
+impl<D, R> HashReader<D, R> {
+    /// Create a new reader which hashes data read from `inner` using
+    /// `hasher`.
+    pub fn new(hasher: D, inner: R) -> Self {
+        Self { hasher, inner }
+    }
+
+    /// Get a reference to the hasher.
+    pub fn hasher(&self) -> &D {
+        &self.hasher
+    }
+
+    /// Get a reference to the inner reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Get a mutable reference to the inner reader.
+    ///
+    /// Data read directly from the inner reader isn't hashed.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Consume the reader, returning the hasher and the inner reader.
+    pub fn into_parts(self) -> (D, R) {
+        (self.hasher, self.inner)
+    }
+
+    /// Consume the reader, returning the inner reader and the digest of the
+    /// data read from it.
+    pub fn finalize(self) -> (R, Output<D>)
+    where
+        D: FixedOutput,
+    {
+        (self.inner, self.hasher.finalize_fixed())
+    }
+}
+
+impl<D: Update, R: io::Read> io::Read for HashReader<D, R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.hasher.update(&buf[..n]);
+        Ok(n)
+    }
+}
+
+#[cfg(feature = "futures-io")]
+#[cfg_attr(docsrs, doc(cfg(feature = "futures-io")))]
+impl<D, R> futures_io::AsyncRead for HashReader<D, R>
+where
+    D: Update + Unpin,
+    R: futures_io::AsyncRead + Unpin,
+{
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        let result = Pin::new(&mut this.inner).poll_read(cx, buf);
+
+        if let Poll::Ready(Ok(n)) = result {
+            this.hasher.update(&buf[..n]);
+        }
+
+        result
+    }
+}
@@ -53,8 +53,11 @@ pub mod dev;
 #[cfg_attr(docsrs, doc(cfg(feature = "core-api")))]
 pub mod core_api;
 mod digest;
+#[cfg(feature = "std")]
+mod io;
 #[cfg(feature = "mac")]
 mod mac;
+mod personalized;
 
 #[cfg(feature = "core-api")]
 #[cfg_attr(docsrs, doc(cfg(feature = "core-api")))]
@@ -65,9 +68,15 @@ pub use const_oid;
 pub use crypto_common;
 
 pub use crate::digest::{Digest, DynDigest, HashMarker};
-pub use crypto_common::{generic_array, typenum, typenum::consts, Output, OutputSizeUser, Reset};
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use crate::io::{HashReader, HashWriter};
+pub use crate::personalized::{Personalization, Personalized};
+pub use crypto_common::{
+    generic_array, typenum, typenum::consts, InvalidLength, Output, OutputSizeUser, Reset,
+};
 #[cfg(feature = "mac")]
-pub use crypto_common::{InnerInit, InvalidLength, Key, KeyInit};
+pub use crypto_common::{InnerInit, Key, KeyInit};
 #[cfg(feature = "mac")]
 pub use mac::{CtOutput, Mac, MacError, MacMarker};
 
@@ -199,6 +208,94 @@ pub trait ExtendableOutputReset: ExtendableOutput + Reset {
     }
 }
 
+/// Trait for tree hash functions (e.g. BLAKE3 or KangarooTwelve) whose input
+/// can be split into subtrees which are absorbed independently and then
+/// merged.
+///
+/// This allows hashing large inputs on multiple threads, or using SIMD to
+/// absorb several subtrees at once, while producing the same result as
+/// absorbing the input sequentially with [`Update`]:
+///
+/// 1. Split the input at multiples of [`ParallelDigest::CHUNK_SIZE`] bytes,
+///    and create a state for each part using [`ParallelDigest::subtree`].
+/// 2. Absorb each part into its subtree state, e.g. on a thread pool.
+/// 3. Optionally combine adjacent subtrees using [`ParallelDigest::merge`].
+/// 4. Append the subtrees in input order using
+///    [`ParallelDigest::absorb_subtree`], then finalize as usual.
+///
+/// Tree hashes generally impose further constraints on how the input can be
+/// split, e.g. BLAKE3 requires all subtrees but the last to consist of a
+/// power of two chunks. Implementations may panic if they are violated.
+pub trait ParallelDigest: Update + Sized {
+    /// Hashing state of a subtree.
+    type Subtree: Update + Send;
+
+    /// Size in bytes of the chunks which form the leaves of the tree.
+    const CHUNK_SIZE: usize;
+
+    /// Create the state for a subtree covering the input starting `offset`
+    /// bytes after the data absorbed so far, which must be a multiple of
+    /// [`ParallelDigest::CHUNK_SIZE`].
+    fn subtree(&self, offset: u64) -> Self::Subtree;
+
+    /// Merge the subtree `right` into `left`, which it must immediately
+    /// follow in the input.
+    fn merge(left: &mut Self::Subtree, right: Self::Subtree);
+
+    /// Absorb a subtree, which must immediately follow the data absorbed so
+    /// far.
+    fn absorb_subtree(&mut self, subtree: Self::Subtree);
+}
+
+/// Trait for XOFs able to create multiple independent readers from the same
+/// absorbed state, e.g. to derive several outputs from one transcript, or to
+/// read a large output in parallel.
+///
+/// Each reader returned by [`XofFork::fork_reader`] starts at the beginning
+/// of the output stream for the data absorbed so far: forking neither
+/// consumes nor modifies the hasher, which can continue absorbing data, and
+/// readers don't affect each other. Consequently, readers forked from the
+/// same state produce the same output, so protocols which need distinct
+/// outputs must absorb distinct data (e.g. a label) between forks.
+///
+/// Similarly, cloning a reader creates a copy at the same position in the
+/// output stream, which produces the same subsequent output as the original.
+///
+/// This trait is implemented for all XOFs which implement [`Clone`].
+pub trait XofFork: ExtendableOutput {
+    /// Create a reader for the data absorbed so far without consuming the
+    /// hasher instance.
+    fn fork_reader(&self) -> Self::Reader;
+}
+
+impl<D: ExtendableOutput + Clone> XofFork for D {
+    #[inline]
+    fn fork_reader(&self) -> Self::Reader {
+        self.clone().finalize_xof()
+    }
+}
+
+/// Trait for keyed XOFs which accept keys of variable length, e.g. KMACXOF
+/// or BLAKE3 in keyed mode.
+pub trait KeyedXof: ExtendableOutput {
+    /// Create new keyed XOF instance.
+    ///
+    /// It will return `Err(InvalidLength)` if the key length is unsupported.
+    fn new_keyed(key: &[u8]) -> Result<Self, InvalidLength>;
+
+    /// Compute the keyed XOF of `data` and write it into `output`.
+    fn keyed_xof(
+        key: &[u8],
+        data: impl AsRef<[u8]>,
+        output: &mut [u8],
+    ) -> Result<(), InvalidLength> {
+        let mut hasher = Self::new_keyed(key)?;
+        hasher.update(data.as_ref());
+        hasher.finalize_xof_into(output);
+        Ok(())
+    }
+}
+
 /// Trait for hash functions with variable-size output.
 pub trait VariableOutput: Sized + Update {
     /// Maximum size of output hash.
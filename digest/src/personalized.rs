@@ -0,0 +1,88 @@
+use crate::{
+    ExtendableOutput, FixedOutput, HashMarker, InvalidLength, Output, OutputSizeUser, Update,
+};
+
+/// Trait for hash functions which can be initialized with a personalization
+/// string for domain separation, e.g. the personalization parameter of
+/// BLAKE2 or the customization string of KangarooTwelve.
+///
+/// Hash functions without native support for personalization can be used
+/// via the [`Personalized`] wrapper.
+pub trait Personalization: Sized {
+    /// Create new hasher instance with the given personalization string.
+    ///
+    /// It will return `Err(InvalidLength)` if the hash function doesn't
+    /// support personalization strings of this length.
+    fn new_personalized(personalization: &[u8]) -> Result<Self, InvalidLength>;
+}
+
+/// Wrapper which adds personalization support to any hash function by
+/// prefixing the message with the personalization string.
+///
+/// The prefix consists of the length of the personalization string in bytes
+/// encoded as a 64-bit big endian integer, followed by the string itself, so
+/// that distinct personalization strings never result in the same prefix.
+/// The [`Default`] instance uses an empty personalization string, and so is
+/// distinct from the wrapped hash function.
+///
+/// Note that the resulting digests differ from the native personalization
+/// of hash functions which support it (e.g. BLAKE2).
+#[derive(Clone, Debug)]
+pub struct Personalized<D> {
+    hasher: D,
+}
+
+impl<D: Default + Update> Personalization for Personalized<D> {
+    #[inline]
+    fn new_personalized(personalization: &[u8]) -> Result<Self, InvalidLength> {
+        let len = u64::try_from(personalization.len()).map_err(|_| InvalidLength)?;
+        let mut hasher = D::default();
+        hasher.update(&len.to_be_bytes());
+        hasher.update(personalization);
+        Ok(Self { hasher })
+    }
+}
+
+impl<D: Default + Update> Default for Personalized<D> {
+    #[inline]
+    fn default() -> Self {
+        Self::new_personalized(&[]).expect("empty personalization is valid")
+    }
+}
+
+impl<D> Personalized<D> {
+    /// Unwrap the inner hasher, which has absorbed the personalization prefix.
+    #[inline]
+    pub fn into_inner(self) -> D {
+        self.hasher
+    }
+}
+
+impl<D: HashMarker> HashMarker for Personalized<D> {}
+
+impl<D: Update> Update for Personalized<D> {
+    #[inline]
+    fn update(&mut self, data: &[u8]) {
+        self.hasher.update(data);
+    }
+}
+
+impl<D: OutputSizeUser> OutputSizeUser for Personalized<D> {
+    type OutputSize = D::OutputSize;
+}
+
+impl<D: FixedOutput> FixedOutput for Personalized<D> {
+    #[inline]
+    fn finalize_into(self, out: &mut Output<Self>) {
+        self.hasher.finalize_into(out);
+    }
+}
+
+impl<D: ExtendableOutput> ExtendableOutput for Personalized<D> {
+    type Reader = D::Reader;
+
+    #[inline]
+    fn finalize_xof(self) -> Self::Reader {
+        self.hasher.finalize_xof()
+    }
+}
@@ -0,0 +1,255 @@
+//! Tests for the I/O adapters.
+#![cfg(feature = "std")]
+
+use digest::{consts::U8, FixedOutput, HashReader, HashWriter, Output, OutputSizeUser, Update};
+use std::io::{self, Read, Write};
+
+/// Hasher which records all data passed to it, and outputs its FNV-1a hash.
+#[derive(Clone, Debug, Default)]
+struct Recorder(Vec<u8>);
+
+impl Update for Recorder {
+    fn update(&mut self, data: &[u8]) {
+        self.0.extend_from_slice(data);
+    }
+}
+
+impl OutputSizeUser for Recorder {
+    type OutputSize = U8;
+}
+
+impl FixedOutput for Recorder {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        out.copy_from_slice(&fnv1a(&self.0).to_le_bytes());
+    }
+}
+
+fn fnv1a(data: &[u8]) -> u64 {
+    data.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// I/O object which accepts or returns at most `limit` bytes at a time, and
+/// fails once `fail_after` bytes were transferred.
+#[derive(Debug, Default)]
+struct Limited {
+    data: Vec<u8>,
+    pos: usize,
+    limit: usize,
+    fail_after: usize,
+}
+
+impl Limited {
+    fn new(data: &[u8], limit: usize) -> Self {
+        Self {
+            data: data.to_vec(),
+            pos: 0,
+            limit,
+            fail_after: usize::MAX,
+        }
+    }
+
+    fn check(&self, transferred: usize) -> io::Result<()> {
+        if transferred >= self.fail_after {
+            return Err(io::ErrorKind::Other.into());
+        }
+        Ok(())
+    }
+}
+
+impl Write for Limited {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.check(self.data.len())?;
+        let n = buf.len().min(self.limit);
+        self.data.extend_from_slice(&buf[..n]);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Read for Limited {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.check(self.pos)?;
+        let n = buf.len().min(self.limit).min(self.data.len() - self.pos);
+        buf[..n].copy_from_slice(&self.data[self.pos..self.pos + n]);
+        self.pos += n;
+        Ok(n)
+    }
+}
+
+const MSG: &[u8] = b"The quick brown fox jumps over the lazy dog";
+
+#[test]
+fn writer() {
+    let mut writer = HashWriter::new(Recorder::default(), Vec::new());
+    writer.write_all(MSG).unwrap();
+    writer.flush().unwrap();
+    assert_eq!(writer.hasher().0, MSG);
+
+    let (inner, digest) = writer.finalize();
+    assert_eq!(inner, MSG);
+    assert_eq!(digest[..], fnv1a(MSG).to_le_bytes());
+}
+
+#[test]
+fn writer_partial_write() {
+    let mut writer = HashWriter::new(Recorder::default(), Limited::new(&[], 5));
+
+    // Only the bytes accepted by the inner writer are hashed
+    assert_eq!(writer.write(MSG).unwrap(), 5);
+    assert_eq!(writer.hasher().0, &MSG[..5]);
+    assert_eq!(writer.get_ref().data, &MSG[..5]);
+
+    writer.write_all(&MSG[5..]).unwrap();
+    let (hasher, inner) = writer.into_parts();
+    assert_eq!(hasher.0, MSG);
+    assert_eq!(inner.data, MSG);
+}
+
+#[test]
+fn writer_error() {
+    let mut inner = Limited::new(&[], 5);
+    inner.fail_after = 10;
+    let mut writer = HashWriter::new(Recorder::default(), inner);
+
+    assert!(writer.write_all(MSG).is_err());
+    assert_eq!(writer.hasher().0, &MSG[..10]);
+
+    // Data written directly to the inner writer isn't hashed
+    writer.get_mut().fail_after = usize::MAX;
+    writer.get_mut().write_all(b"unhashed").unwrap();
+    assert_eq!(writer.hasher().0, &MSG[..10]);
+}
+
+#[test]
+fn reader() {
+    let mut reader = HashReader::new(Recorder::default(), MSG);
+    let mut buf = Vec::new();
+    reader.read_to_end(&mut buf).unwrap();
+    assert_eq!(buf, MSG);
+
+    let (inner, digest) = reader.finalize();
+    assert!(inner.is_empty());
+    assert_eq!(digest[..], fnv1a(MSG).to_le_bytes());
+}
+
+#[test]
+fn reader_partial_read() {
+    let mut reader = HashReader::new(Recorder::default(), Limited::new(MSG, 5));
+    let mut buf = [0; 16];
+
+    // Only the bytes returned by the inner reader are hashed
+    assert_eq!(reader.read(&mut buf).unwrap(), 5);
+    assert_eq!(reader.hasher().0, &MSG[..5]);
+
+    let mut rest = Vec::new();
+    reader.read_to_end(&mut rest).unwrap();
+    assert_eq!(rest, &MSG[5..]);
+    assert_eq!(reader.into_parts().0 .0, MSG);
+}
+
+#[test]
+fn reader_error() {
+    let mut inner = Limited::new(MSG, 5);
+    inner.fail_after = 10;
+    let mut reader = HashReader::new(Recorder::default(), inner);
+
+    let mut buf = Vec::new();
+    assert!(reader.read_to_end(&mut buf).is_err());
+    assert!(buf.len() >= 10);
+    assert_eq!(reader.hasher().0, buf);
+    assert_eq!(buf, &MSG[..buf.len()]);
+}
+
+#[cfg(feature = "futures-io")]
+mod futures {
+    use super::{Limited, Recorder, MSG};
+    use digest::{HashReader, HashWriter};
+    use futures_io::{AsyncRead, AsyncWrite};
+    use std::{
+        io,
+        pin::Pin,
+        sync::Arc,
+        task::{Context, Poll, Wake, Waker},
+    };
+
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    impl AsyncWrite for Limited {
+        fn poll_write(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<io::Result<usize>> {
+            Poll::Ready(io::Write::write(self.get_mut(), buf))
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Poll::Ready(Ok(()))
+        }
+    }
+
+    impl AsyncRead for Limited {
+        fn poll_read(
+            self: Pin<&mut Self>,
+            _cx: &mut Context<'_>,
+            buf: &mut [u8],
+        ) -> Poll<io::Result<usize>> {
+            Poll::Ready(io::Read::read(self.get_mut(), buf))
+        }
+    }
+
+    #[test]
+    fn async_writer_partial_write() {
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+        let mut writer = HashWriter::new(Recorder::default(), Limited::new(&[], 5));
+
+        let result = Pin::new(&mut writer).poll_write(&mut cx, MSG);
+        assert!(matches!(result, Poll::Ready(Ok(5))));
+        assert_eq!(writer.hasher().0, &MSG[..5]);
+
+        writer.get_mut().fail_after = 5;
+        let result = Pin::new(&mut writer).poll_write(&mut cx, &MSG[5..]);
+        assert!(matches!(result, Poll::Ready(Err(_))));
+        assert_eq!(writer.hasher().0, &MSG[..5]);
+
+        assert!(matches!(
+            Pin::new(&mut writer).poll_flush(&mut cx),
+            Poll::Ready(Ok(()))
+        ));
+        assert!(matches!(
+            Pin::new(&mut writer).poll_close(&mut cx),
+            Poll::Ready(Ok(()))
+        ));
+    }
+
+    #[test]
+    fn async_reader_partial_read() {
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+        let mut reader = HashReader::new(Recorder::default(), Limited::new(MSG, 5));
+        let mut buf = [0; 16];
+
+        let result = Pin::new(&mut reader).poll_read(&mut cx, &mut buf);
+        assert!(matches!(result, Poll::Ready(Ok(5))));
+        assert_eq!(reader.hasher().0, &MSG[..5]);
+
+        reader.get_mut().fail_after = 5;
+        let result = Pin::new(&mut reader).poll_read(&mut cx, &mut buf);
+        assert!(matches!(result, Poll::Ready(Err(_))));
+        assert_eq!(reader.hasher().0, &MSG[..5]);
+    }
+}
@@ -0,0 +1,147 @@
+//! Tests for the `ParallelDigest` trait.
+
+use digest::{consts::U8, FixedOutput, Output, OutputSizeUser, ParallelDigest, Update};
+use std::thread;
+
+const CHUNK_SIZE: usize = 4;
+
+/// Toy tree hash which XORs together an FNV-1a hash of each chunk and its
+/// index, and so can absorb chunks in any order.
+#[derive(Clone, Debug, Default)]
+struct ToyTree {
+    /// Offset of the first byte absorbed by this state.
+    start: u64,
+
+    /// Number of bytes absorbed.
+    len: u64,
+
+    /// XOR of the hashes of all complete chunks.
+    acc: u64,
+
+    /// Trailing partial chunk.
+    buf: Vec<u8>,
+}
+
+impl ToyTree {
+    fn leaf(index: u64, chunk: &[u8]) -> u64 {
+        index
+            .to_le_bytes()
+            .iter()
+            .chain(chunk)
+            .fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+            })
+    }
+
+    fn append(&mut self, other: Self) {
+        assert!(
+            self.buf.is_empty(),
+            "subtree must start at a chunk boundary"
+        );
+        assert_eq!(
+            self.start + self.len,
+            other.start,
+            "subtrees must be adjacent"
+        );
+        self.len += other.len;
+        self.acc ^= other.acc;
+        self.buf = other.buf;
+    }
+}
+
+impl Update for ToyTree {
+    fn update(&mut self, data: &[u8]) {
+        for &byte in data {
+            self.buf.push(byte);
+            self.len += 1;
+
+            if self.buf.len() == CHUNK_SIZE {
+                let index = (self.start + self.len) / CHUNK_SIZE as u64 - 1;
+                self.acc ^= Self::leaf(index, &self.buf);
+                self.buf.clear();
+            }
+        }
+    }
+}
+
+impl ParallelDigest for ToyTree {
+    type Subtree = Self;
+
+    const CHUNK_SIZE: usize = CHUNK_SIZE;
+
+    fn subtree(&self, offset: u64) -> Self {
+        assert_eq!(offset % CHUNK_SIZE as u64, 0);
+        Self {
+            start: self.start + self.len + offset,
+            ..Default::default()
+        }
+    }
+
+    fn merge(left: &mut Self, right: Self) {
+        left.append(right);
+    }
+
+    fn absorb_subtree(&mut self, subtree: Self) {
+        self.append(subtree);
+    }
+}
+
+impl OutputSizeUser for ToyTree {
+    type OutputSize = U8;
+}
+
+impl FixedOutput for ToyTree {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        let index = (self.start + self.len) / CHUNK_SIZE as u64;
+        let hash = self.acc ^ Self::leaf(index, &self.buf) ^ self.len;
+        out.copy_from_slice(&hash.to_le_bytes());
+    }
+}
+
+fn data() -> Vec<u8> {
+    (0..37).collect()
+}
+
+#[test]
+fn subtrees_match_sequential_update() {
+    let data = data();
+    let expected = ToyTree::default().chain(&data).finalize_fixed();
+
+    // Absorb a prefix sequentially, then the rest in three subtrees, the
+    // first two of which are merged
+    let mut hasher = ToyTree::default().chain(&data[..8]);
+    let subtree = |start: usize, end: usize| {
+        hasher
+            .subtree(start as u64)
+            .chain(&data[8 + start..8 + end])
+    };
+    let (mut left, right, last) = (subtree(0, 12), subtree(12, 24), subtree(24, 29));
+
+    ToyTree::merge(&mut left, right);
+    hasher.absorb_subtree(left);
+    hasher.absorb_subtree(last);
+    assert_eq!(hasher.finalize_fixed(), expected);
+}
+
+#[test]
+fn subtrees_on_threads() {
+    let data = data();
+    let expected = ToyTree::default().chain(&data).finalize_fixed();
+
+    let mut hasher = ToyTree::default();
+    let handles: Vec<_> = data
+        .chunks(2 * CHUNK_SIZE)
+        .enumerate()
+        .map(|(i, part)| {
+            let subtree = hasher.subtree((i * 2 * CHUNK_SIZE) as u64);
+            let part = part.to_vec();
+            thread::spawn(move || subtree.chain(part))
+        })
+        .collect();
+
+    for handle in handles {
+        hasher.absorb_subtree(handle.join().unwrap());
+    }
+
+    assert_eq!(hasher.finalize_fixed(), expected);
+}
@@ -0,0 +1,66 @@
+//! Tests for the `Personalized` wrapper.
+
+use digest::{
+    consts::U8, FixedOutput, Output, OutputSizeUser, Personalization, Personalized, Update,
+};
+
+/// Hasher which records all data passed to it, and outputs its FNV-1a hash.
+#[derive(Clone, Debug, Default)]
+struct Recorder(Vec<u8>);
+
+impl Update for Recorder {
+    fn update(&mut self, data: &[u8]) {
+        self.0.extend_from_slice(data);
+    }
+}
+
+impl OutputSizeUser for Recorder {
+    type OutputSize = U8;
+}
+
+impl FixedOutput for Recorder {
+    fn finalize_into(self, out: &mut Output<Self>) {
+        let hash = self.0.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        });
+        out.copy_from_slice(&hash.to_le_bytes());
+    }
+}
+
+fn digest(personalization: &[u8], data: &[u8]) -> Output<Recorder> {
+    Personalized::<Recorder>::new_personalized(personalization)
+        .unwrap()
+        .chain(data)
+        .finalize_fixed()
+}
+
+#[test]
+fn prefix_encoding() {
+    let hasher = Personalized::<Recorder>::new_personalized(b"abc")
+        .unwrap()
+        .chain(b"data");
+    assert_eq!(hasher.into_inner().0, b"\0\0\0\0\0\0\0\x03abcdata");
+}
+
+#[test]
+fn distinct_personalizations() {
+    let plain = Recorder::default().chain(b"data").finalize_fixed();
+    assert_ne!(digest(b"one", b"data"), digest(b"two", b"data"));
+    assert_ne!(digest(b"one", b"data"), plain);
+
+    // The boundary between the personalization and the message is
+    // unambiguous
+    assert_ne!(digest(b"ab", b"c"), digest(b"a", b"bc"));
+    assert_ne!(digest(b"", b"\0\0\0\0\0\0\0\0"), digest(b"\0", b""));
+}
+
+#[test]
+fn default_is_empty_personalization() {
+    let default = Personalized::<Recorder>::default().chain(b"data");
+    assert_eq!(default.clone().into_inner().0, b"\0\0\0\0\0\0\0\0data");
+    assert_eq!(default.finalize_fixed(), digest(&[], b"data"));
+    assert_ne!(
+        digest(&[], b"data"),
+        Recorder::default().chain(b"data").finalize_fixed()
+    );
+}
@@ -0,0 +1,148 @@
+//! Tests for the `XofFork` and `KeyedXof` traits.
+
+use digest::{ExtendableOutput, InvalidLength, KeyedXof, Update, XofFork, XofReader};
+
+/// Toy XOF which absorbs data into an FNV-1a hash, and outputs a SplitMix64
+/// stream seeded with it.
+#[derive(Clone, Debug)]
+struct ToyXof(u64);
+
+impl Default for ToyXof {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Update for ToyXof {
+    fn update(&mut self, data: &[u8]) {
+        for &byte in data {
+            self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+}
+
+impl ExtendableOutput for ToyXof {
+    type Reader = ToyReader;
+
+    fn finalize_xof(self) -> ToyReader {
+        ToyReader {
+            seed: self.0,
+            pos: 0,
+        }
+    }
+}
+
+impl KeyedXof for ToyXof {
+    fn new_keyed(key: &[u8]) -> Result<Self, InvalidLength> {
+        if key.is_empty() || key.len() > 32 {
+            return Err(InvalidLength);
+        }
+
+        // Domain separate keyed and unkeyed instances by the key length
+        let mut hasher = Self(!Self::default().0);
+        hasher.update(key);
+        hasher.update(&[key.len() as u8]);
+        Ok(hasher)
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ToyReader {
+    seed: u64,
+    pos: u64,
+}
+
+impl XofReader for ToyReader {
+    fn read(&mut self, buffer: &mut [u8]) {
+        for byte in buffer {
+            let mut z = self
+                .seed
+                .wrapping_add((self.pos / 8 + 1).wrapping_mul(0x9e37_79b9_7f4a_7c15));
+            z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+            *byte = ((z ^ (z >> 31)) >> (8 * (self.pos % 8))) as u8;
+            self.pos += 1;
+        }
+    }
+}
+
+fn xof(data: &[u8]) -> [u8; 32] {
+    let mut out = [0; 32];
+    ToyXof::digest_xof(data, &mut out);
+    out
+}
+
+fn read(reader: &mut impl XofReader) -> [u8; 32] {
+    let mut out = [0; 32];
+    reader.read(&mut out);
+    out
+}
+
+#[test]
+fn fork_reader_does_not_consume_hasher() {
+    let mut hasher = ToyXof::default().chain(b"abc");
+    let mut first = hasher.fork_reader();
+    hasher.update(b"def");
+    let mut second = hasher.fork_reader();
+
+    assert_eq!(read(&mut first), xof(b"abc"));
+    assert_eq!(read(&mut second), xof(b"abcdef"));
+
+    let mut out = [0; 32];
+    hasher.finalize_xof_into(&mut out);
+    assert_eq!(out, xof(b"abcdef"));
+}
+
+#[test]
+fn forked_readers_are_independent() {
+    let hasher = ToyXof::default().chain(b"transcript");
+    let mut first = hasher.fork_reader();
+    let mut second = hasher.fork_reader();
+
+    // Reading from one reader doesn't advance the other: both produce the
+    // same output from the start of the stream
+    let out = read(&mut first);
+    assert_eq!(read(&mut second), out);
+    assert_ne!(read(&mut first), out);
+}
+
+#[test]
+fn cloned_reader_continues_at_same_position() {
+    let mut reader = ToyXof::default().chain(b"transcript").finalize_xof();
+    let mut prefix = [0; 5];
+    reader.read(&mut prefix);
+
+    let mut clone = reader.clone();
+    let rest = read(&mut reader);
+    assert_eq!(read(&mut clone), rest);
+
+    // Together, the reads make up the full output stream
+    let mut full = [0; 37];
+    ToyXof::digest_xof(b"transcript", &mut full);
+    assert_eq!(full[..5], prefix);
+    assert_eq!(full[5..], rest);
+}
+
+#[test]
+fn keyed_xof() {
+    let mut out = [0; 32];
+    ToyXof::keyed_xof(b"key", b"data", &mut out).unwrap();
+
+    let mut hasher = ToyXof::new_keyed(b"key").unwrap();
+    hasher.update(b"data");
+    assert_eq!(read(&mut hasher.finalize_xof()), out);
+
+    let mut other = [0; 32];
+    ToyXof::keyed_xof(b"other key", b"data", &mut other).unwrap();
+    assert_ne!(other, out);
+    assert_ne!(xof(b"data"), out);
+
+    assert_eq!(
+        ToyXof::keyed_xof(&[], b"data", &mut out),
+        Err(InvalidLength)
+    );
+    assert_eq!(
+        ToyXof::keyed_xof(&[0; 33], b"data", &mut out),
+        Err(InvalidLength)
+    );
+}
@@ -4,31 +4,45 @@
 //! the traits in this crate.
 
 use crate::{
-    bigint::{Limb, U256},
+    bigint::{Limb, U256, U512},
+    consts::U48,
     error::{Error, Result},
-    ops::{LinearCombination, MulByGenerator, Reduce, Shr1},
+    ops::{
+        AddMixed, BitShift, Double, FromOkm, LincombVartime, LinearCombination,
+        LinearCombinationExt, MulByGenerator, MulVartime, Reduce, ReduceBytes, ReduceWide, Shr1,
+        Sqrt, SubMixed,
+    },
     pkcs8,
     rand_core::RngCore,
-    scalar::FromUintUnchecked,
-    sec1::{CompressedPoint, FromEncodedPoint, ToEncodedPoint},
+    scalar::{CtOrd, FromCanonical, FromUintUnchecked, ToCanonical},
+    sec1::{CompressedPoint, FromEncodedPoint, Tag, ToEncodedPoint},
     subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption},
     zeroize::DefaultIsZeroes,
-    AffineXCoordinate, AffineYIsOdd, Curve, CurveArithmetic, IsHigh, PrimeCurve,
+    AffineXCoordinate, AffineYCoordinate, AffineYIsOdd, BatchNormalize, ClearCofactor, Curve,
+    CurveArithmetic, CurveParams, DecompressPoint, IsHigh, IsTorsionFree, PrimeCurve,
+    ValidatePoint,
 };
 use core::{
     iter::{Product, Sum},
     ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
 };
 use ff::{Field, PrimeField};
+use generic_array::GenericArray;
 use hex_literal::hex;
 use pkcs8::AssociatedOid;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 #[cfg(feature = "bits")]
 use ff::PrimeFieldBits;
 
 #[cfg(feature = "jwk")]
 use crate::JwkParameters;
 
+#[cfg(feature = "openssh")]
+use crate::OpenSshParameters;
+
 /// Pseudo-coordinate for fixed-based scalar mult output
 pub const PSEUDO_COORDINATE_FIXED_BASE_MUL: [u8; 32] =
     hex!("deadbeef00000000000000000000000000000000000000000000000000000001");
@@ -39,6 +53,9 @@ pub type EncodedPoint = crate::sec1::EncodedPoint<MockCurve>;
 /// Field element bytes.
 pub type FieldBytes = crate::FieldBytes<MockCurve>;
 
+/// Key pair.
+pub type KeyPair = crate::KeyPair<MockCurve>;
+
 /// Non-zero scalar value.
 pub type NonZeroScalar = crate::NonZeroScalar<MockCurve>;
 
@@ -71,6 +88,20 @@ impl Curve for MockCurve {
         U256::from_be_hex("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
 }
 
+impl CurveParams for MockCurve {
+    const FIELD_MODULUS: U256 =
+        U256::from_be_hex("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff");
+    const EQUATION_A: U256 =
+        U256::from_be_hex("ffffffff00000001000000000000000000000000fffffffffffffffffffffffc");
+    const EQUATION_B: U256 =
+        U256::from_be_hex("5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b");
+    const GENERATOR_X: U256 =
+        U256::from_be_hex("6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296");
+    const GENERATOR_Y: U256 =
+        U256::from_be_hex("4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5");
+    const COFACTOR: u64 = 1;
+}
+
 impl PrimeCurve for MockCurve {}
 
 impl CurveArithmetic for MockCurve {
@@ -89,6 +120,11 @@ impl JwkParameters for MockCurve {
     const CRV: &'static str = "P-256";
 }
 
+#[cfg(feature = "openssh")]
+impl OpenSshParameters for MockCurve {
+    const SSH_IDENTIFIER: &'static str = "nistp256";
+}
+
 /// Example scalar type
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd, Ord)]
 pub struct Scalar(ScalarPrimitive);
@@ -114,7 +150,7 @@ impl Field for Scalar {
 
     #[must_use]
     fn square(&self) -> Self {
-        unimplemented!();
+        *self * self
     }
 
     #[must_use]
@@ -123,11 +159,19 @@ impl Field for Scalar {
     }
 
     fn invert(&self) -> CtOption<Self> {
-        unimplemented!();
+        // Fermat's little theorem: a^(n - 2) = a^-1 (mod n)
+        const ORDER_MINUS_TWO: [u64; 4] = [
+            0xf3b9_cac2_fc63_254f,
+            0xbce6_faad_a717_9e84,
+            0xffff_ffff_ffff_ffff,
+            0xffff_ffff_0000_0000,
+        ];
+
+        CtOption::new(self.pow_vartime(ORDER_MINUS_TWO), !self.is_zero())
     }
 
     fn sqrt(&self) -> CtOption<Self> {
-        unimplemented!();
+        self.sqrt_generic()
     }
 
     fn sqrt_ratio(_num: &Self, _div: &Self) -> (Choice, Self) {
@@ -145,8 +189,12 @@ impl PrimeField for Scalar {
     const TWO_INV: Self = Self::ZERO; // BOGUS!
     const MULTIPLICATIVE_GENERATOR: Self = Self::ZERO; // BOGUS! Should be 7
     const S: u32 = 4;
-    const ROOT_OF_UNITY: Self = Self::ZERO; // BOGUS! Should be 0xffc97f062a770992ba807ace842a3dfc1546cad004378daf0592d7fbb41e6602
-    const ROOT_OF_UNITY_INV: Self = Self::ZERO; // BOGUS!
+    const ROOT_OF_UNITY: Self = Self(ScalarPrimitive::from_uint_const(U256::from_be_hex(
+        "ffc97f062a770992ba807ace842a3dfc1546cad004378daf0592d7fbb41e6602",
+    )));
+    const ROOT_OF_UNITY_INV: Self = Self(ScalarPrimitive::from_uint_const(U256::from_be_hex(
+        "a0a66a5562d46f2ac645fa0458131caee3ac117c794c4137379c7f0657c73764",
+    )));
     const DELTA: Self = Self::ZERO; // BOGUS!
 
     fn from_repr(bytes: FieldBytes) -> CtOption<Self> {
@@ -162,6 +210,15 @@ impl PrimeField for Scalar {
     }
 }
 
+impl Sqrt for Scalar {
+    const T_MINUS_ONE_DIV_TWO: &'static [u64] = &[
+        0x279d_ce56_17e3_192a,
+        0xfde7_37d5_6d38_bcf4,
+        0x07ff_ffff_ffff_ffff,
+        0x07ff_ffff_f800_0000,
+    ];
+}
+
 #[cfg(feature = "bits")]
 impl PrimeFieldBits for Scalar {
     #[cfg(target_pointer_width = "32")]
@@ -258,28 +315,29 @@ impl SubAssign<&Scalar> for Scalar {
 impl Mul<Scalar> for Scalar {
     type Output = Scalar;
 
-    fn mul(self, _other: Scalar) -> Scalar {
-        unimplemented!();
+    fn mul(self, other: Scalar) -> Scalar {
+        let (lo, hi) = self.0.as_uint().mul_wide(other.0.as_uint());
+        Self::from_wide_uint_reduced(hi.concat(&lo))
     }
 }
 
 impl Mul<&Scalar> for Scalar {
     type Output = Scalar;
 
-    fn mul(self, _other: &Scalar) -> Scalar {
-        unimplemented!();
+    fn mul(self, other: &Scalar) -> Scalar {
+        self * *other
     }
 }
 
 impl MulAssign<Scalar> for Scalar {
-    fn mul_assign(&mut self, _rhs: Scalar) {
-        unimplemented!();
+    fn mul_assign(&mut self, rhs: Scalar) {
+        *self = *self * rhs;
     }
 }
 
 impl MulAssign<&Scalar> for Scalar {
-    fn mul_assign(&mut self, _rhs: &Scalar) {
-        unimplemented!();
+    fn mul_assign(&mut self, rhs: &Scalar) {
+        *self = *self * rhs;
     }
 }
 
@@ -297,6 +355,16 @@ impl Shr1 for Scalar {
     }
 }
 
+impl BitShift for Scalar {
+    fn shr_assign_bits(&mut self, shift: usize) {
+        self.0.shr_assign_bits(shift);
+    }
+
+    fn shl_assign_bits(&mut self, shift: usize) {
+        self.0.shl_assign_bits(shift);
+    }
+}
+
 impl Sum for Scalar {
     fn sum<I: Iterator<Item = Self>>(_iter: I) -> Self {
         unimplemented!();
@@ -330,6 +398,30 @@ impl Reduce<U256> for Scalar {
     }
 }
 
+impl ReduceBytes for Scalar {
+    fn from_be_slice_reduced(bytes: &[u8]) -> Self {
+        Self(ScalarPrimitive::from_be_slice_reduced(bytes))
+    }
+
+    fn from_le_slice_reduced(bytes: &[u8]) -> Self {
+        Self(ScalarPrimitive::from_le_slice_reduced(bytes))
+    }
+}
+
+impl FromOkm for Scalar {
+    type Length = U48;
+
+    fn from_okm(data: &GenericArray<u8, U48>) -> Self {
+        Self::from_be_slice_reduced(data)
+    }
+}
+
+impl ReduceWide<U512> for Scalar {
+    fn from_wide_uint_reduced(w: U512) -> Self {
+        Self(ScalarPrimitive::from_wide_uint_reduced(w))
+    }
+}
+
 impl From<u64> for Scalar {
     fn from(n: u64) -> Scalar {
         Self(n.into())
@@ -382,6 +474,30 @@ impl From<&Scalar> for FieldBytes {
     }
 }
 
+impl CtOrd for Scalar {
+    fn ct_lt(&self, other: &Self) -> Choice {
+        CtOrd::ct_lt(&self.0, &other.0)
+    }
+
+    fn ct_gt(&self, other: &Self) -> Choice {
+        CtOrd::ct_gt(&self.0, &other.0)
+    }
+}
+
+impl ToCanonical for Scalar {
+    type Canonical = ScalarPrimitive;
+
+    fn to_canonical(&self) -> ScalarPrimitive {
+        self.0
+    }
+}
+
+impl FromCanonical for Scalar {
+    fn from_canonical(canonical: &ScalarPrimitive) -> CtOption<Self> {
+        CtOption::new(Self(*canonical), Choice::from(1))
+    }
+}
+
 impl IsHigh for Scalar {
     fn is_high(&self) -> Choice {
         self.0.is_high()
@@ -404,17 +520,57 @@ pub enum AffinePoint {
     Other(EncodedPoint),
 }
 
+impl AffinePoint {
+    /// Get the discrete log of this point negated if necessary for it not to
+    /// be high, which stands in for the x-coordinate shared by 𝑷 and −𝑷.
+    ///
+    /// Only "points" computed from the generator are supported.
+    fn normalized_discrete_log(&self) -> Scalar {
+        let scalar = ProjectivePoint::from(*self).discrete_log();
+        Scalar::conditional_select(&scalar, &-scalar, scalar.is_high())
+    }
+}
+
 impl AffineXCoordinate for AffinePoint {
     type FieldRepr = FieldBytes;
 
     fn x(&self) -> FieldBytes {
+        match self {
+            Self::Other(point) => *point.x().expect("identity has no x-coordinate"),
+            _ => self.normalized_discrete_log().to_repr(),
+        }
+    }
+}
+
+impl AffineYCoordinate for AffinePoint {
+    type FieldRepr = FieldBytes;
+
+    fn y(&self) -> FieldBytes {
         unimplemented!();
     }
 }
 
+impl DecompressPoint<MockCurve> for AffinePoint {
+    fn decompress(x: &FieldBytes, y_is_odd: Choice) -> CtOption<Self> {
+        let mut bytes = CompressedPoint::<MockCurve>::default();
+        bytes[0] = 0x02 | y_is_odd.unwrap_u8();
+        bytes[1..].copy_from_slice(x);
+
+        // Any x-coordinate is accepted, since this is dev code
+        let point = EncodedPoint::from_bytes(bytes).expect("invalid compressed point");
+        CtOption::new(Self::Other(point), Choice::from(1))
+    }
+}
+
 impl AffineYIsOdd for AffinePoint {
     fn y_is_odd(&self) -> Choice {
-        unimplemented!();
+        match self {
+            Self::Other(point) => {
+                Choice::from(u8::from(point.compress().tag() == Tag::CompressedOddY))
+            }
+            // Negation flips the parity, as it does for actual curve points
+            _ => ProjectivePoint::from(*self).discrete_log().is_high(),
+        }
     }
 }
 
@@ -452,8 +608,16 @@ impl DefaultIsZeroes for AffinePoint {}
 
 impl FromEncodedPoint<MockCurve> for AffinePoint {
     fn from_encoded_point(encoded_point: &EncodedPoint) -> CtOption<Self> {
+        let fixed_base_output = encoded_point
+            .y()
+            .filter(|y| y.as_slice() == PSEUDO_COORDINATE_FIXED_BASE_MUL)
+            .and_then(|_| encoded_point.x())
+            .and_then(|x| Option::from(Scalar::from_repr(*x)));
+
         let point = if encoded_point.is_identity() {
             Self::Identity
+        } else if let Some(scalar) = fixed_base_output {
+            Self::FixedBaseOutput(scalar)
         } else {
             Self::Other(*encoded_point)
         };
@@ -468,7 +632,7 @@ impl ToEncodedPoint<MockCurve> for AffinePoint {
             Self::FixedBaseOutput(scalar) => EncodedPoint::from_affine_coordinates(
                 &scalar.to_repr(),
                 &PSEUDO_COORDINATE_FIXED_BASE_MUL.into(),
-                false,
+                compress,
             ),
             Self::Other(point) => {
                 if compress == point.is_compressed() {
@@ -491,7 +655,7 @@ impl Mul<NonZeroScalar> for AffinePoint {
 }
 
 /// Example projective point type
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq)]
 pub enum ProjectivePoint {
     /// Result of fixed-based scalar multiplication
     FixedBaseOutput(Scalar),
@@ -506,19 +670,37 @@ pub enum ProjectivePoint {
     Other(AffinePoint),
 }
 
+impl ProjectivePoint {
+    /// Get the scalar multiple of the generator this point represents.
+    ///
+    /// Only "points" computed from the generator are supported.
+    fn discrete_log(self) -> Scalar {
+        match self {
+            Self::FixedBaseOutput(scalar) => scalar,
+            Self::Identity => Scalar::ZERO,
+            Self::Generator => Scalar::ONE,
+            Self::Other(_) => unimplemented!(),
+        }
+    }
+}
+
 impl ConstantTimeEq for ProjectivePoint {
     fn ct_eq(&self, other: &Self) -> Choice {
         match (self, other) {
-            (Self::FixedBaseOutput(scalar), Self::FixedBaseOutput(other_scalar)) => {
-                scalar.ct_eq(other_scalar)
-            }
-            (Self::Identity, Self::Identity) | (Self::Generator, Self::Generator) => 1.into(),
             (Self::Other(point), Self::Other(other_point)) => point.ct_eq(other_point),
-            _ => 0.into(),
+            (Self::Other(_), _) | (_, Self::Other(_)) => 0.into(),
+            // e.g. the generator multiplied by zero is the identity
+            _ => self.discrete_log().ct_eq(&other.discrete_log()),
         }
     }
 }
 
+impl PartialEq for ProjectivePoint {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
 impl ConditionallySelectable for ProjectivePoint {
     fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
         if choice.into() {
@@ -587,7 +769,7 @@ impl group::Group for ProjectivePoint {
 
     #[must_use]
     fn double(&self) -> Self {
-        unimplemented!();
+        *self + *self
     }
 }
 
@@ -638,67 +820,109 @@ impl group::Curve for ProjectivePoint {
     fn to_affine(&self) -> AffinePoint {
         match self {
             Self::FixedBaseOutput(scalar) => AffinePoint::FixedBaseOutput(*scalar),
+            Self::Identity => AffinePoint::Identity,
+            Self::Generator => AffinePoint::Generator,
             Self::Other(affine) => *affine,
-            _ => unimplemented!(),
         }
     }
 }
 
+impl<const N: usize> BatchNormalize<[ProjectivePoint; N]> for ProjectivePoint {
+    type Output = [AffinePoint; N];
+
+    fn batch_normalize(points: &[ProjectivePoint; N]) -> [AffinePoint; N] {
+        let mut ret = [AffinePoint::Identity; N];
+        group::Curve::batch_normalize(points, &mut ret);
+        ret
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl BatchNormalize<[ProjectivePoint]> for ProjectivePoint {
+    type Output = Vec<AffinePoint>;
+
+    fn batch_normalize(points: &[ProjectivePoint]) -> Vec<AffinePoint> {
+        let mut ret = vec![AffinePoint::Identity; points.len()];
+        group::Curve::batch_normalize(points, &mut ret);
+        ret
+    }
+}
+
+impl ClearCofactor for ProjectivePoint {}
+
+impl Double for ProjectivePoint {}
+
+impl IsTorsionFree for ProjectivePoint {}
+
+impl ValidatePoint for ProjectivePoint {}
+
 impl LinearCombination for ProjectivePoint {}
 
+impl LincombVartime for ProjectivePoint {}
+
+impl LinearCombinationExt<[(ProjectivePoint, Scalar)]> for ProjectivePoint {}
+
+impl<const N: usize> LinearCombinationExt<[(ProjectivePoint, Scalar); N]> for ProjectivePoint {}
+
 impl Add<ProjectivePoint> for ProjectivePoint {
     type Output = ProjectivePoint;
 
-    fn add(self, _other: ProjectivePoint) -> ProjectivePoint {
-        unimplemented!();
+    fn add(self, other: ProjectivePoint) -> ProjectivePoint {
+        match (self, other) {
+            (Self::Identity, point) | (point, Self::Identity) => point,
+            _ => Self::FixedBaseOutput(self.discrete_log() + other.discrete_log()),
+        }
     }
 }
 
 impl Add<&ProjectivePoint> for ProjectivePoint {
     type Output = ProjectivePoint;
 
-    fn add(self, _other: &ProjectivePoint) -> ProjectivePoint {
-        unimplemented!();
+    fn add(self, other: &ProjectivePoint) -> ProjectivePoint {
+        self + *other
     }
 }
 
 impl AddAssign<ProjectivePoint> for ProjectivePoint {
-    fn add_assign(&mut self, _rhs: ProjectivePoint) {
-        unimplemented!();
+    fn add_assign(&mut self, rhs: ProjectivePoint) {
+        *self = *self + rhs;
     }
 }
 
 impl AddAssign<&ProjectivePoint> for ProjectivePoint {
-    fn add_assign(&mut self, _rhs: &ProjectivePoint) {
-        unimplemented!();
+    fn add_assign(&mut self, rhs: &ProjectivePoint) {
+        *self = *self + rhs;
     }
 }
 
 impl Sub<ProjectivePoint> for ProjectivePoint {
     type Output = ProjectivePoint;
 
-    fn sub(self, _other: ProjectivePoint) -> ProjectivePoint {
-        unimplemented!();
+    fn sub(self, other: ProjectivePoint) -> ProjectivePoint {
+        match (self, other) {
+            (point, Self::Identity) => point,
+            _ => Self::FixedBaseOutput(self.discrete_log() - other.discrete_log()),
+        }
     }
 }
 
 impl Sub<&ProjectivePoint> for ProjectivePoint {
     type Output = ProjectivePoint;
 
-    fn sub(self, _other: &ProjectivePoint) -> ProjectivePoint {
-        unimplemented!();
+    fn sub(self, other: &ProjectivePoint) -> ProjectivePoint {
+        self - *other
     }
 }
 
 impl SubAssign<ProjectivePoint> for ProjectivePoint {
-    fn sub_assign(&mut self, _rhs: ProjectivePoint) {
-        unimplemented!();
+    fn sub_assign(&mut self, rhs: ProjectivePoint) {
+        *self = *self - rhs;
     }
 }
 
 impl SubAssign<&ProjectivePoint> for ProjectivePoint {
-    fn sub_assign(&mut self, _rhs: &ProjectivePoint) {
-        unimplemented!();
+    fn sub_assign(&mut self, rhs: &ProjectivePoint) {
+        *self = *self - rhs;
     }
 }
 
@@ -775,8 +999,11 @@ impl Mul<Scalar> for ProjectivePoint {
 
     fn mul(self, scalar: Scalar) -> ProjectivePoint {
         match self {
-            Self::Generator => Self::FixedBaseOutput(scalar),
-            _ => unimplemented!(),
+            Self::Identity => Self::Identity,
+            Self::Generator | Self::FixedBaseOutput(_) => {
+                Self::FixedBaseOutput(self.discrete_log() * scalar)
+            }
+            Self::Other(_) => unimplemented!(),
         }
     }
 }
@@ -803,14 +1030,41 @@ impl MulAssign<&Scalar> for ProjectivePoint {
 
 impl MulByGenerator for ProjectivePoint {}
 
+impl MulVartime for ProjectivePoint {}
+
+impl AddMixed for ProjectivePoint {}
+
+impl SubMixed for ProjectivePoint {}
+
 impl Neg for ProjectivePoint {
     type Output = ProjectivePoint;
 
     fn neg(self) -> ProjectivePoint {
-        unimplemented!();
+        match self {
+            Self::Identity => Self::Identity,
+            _ => Self::FixedBaseOutput(-self.discrete_log()),
+        }
     }
 }
 
+/// Scalars to test scalar multiplication routines with.
+#[cfg(test)]
+pub(crate) fn test_scalars() -> [Scalar; 4] {
+    [
+        Scalar::ZERO,
+        Scalar::ONE,
+        -Scalar::ONE,
+        Scalar::random(&mut rand_core::OsRng),
+    ]
+}
+
+/// Points to test scalar multiplication routines with.
+#[cfg(test)]
+pub(crate) fn test_points() -> [ProjectivePoint; 2] {
+    let generator = <ProjectivePoint as group::Group>::generator();
+    [generator, generator * Scalar::from(42u64)]
+}
+
 #[cfg(test)]
 mod tests {
     use super::Scalar;
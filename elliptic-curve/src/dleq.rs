@@ -0,0 +1,198 @@
+//! Proofs of discrete logarithm equality (DLEQ).
+//!
+//! A [`DleqProof`] demonstrates knowledge of a secret scalar 𝑘 such that
+//! 𝑨 = 𝑮 × 𝑘 and 𝑩 = 𝑯 × 𝑘 for public points 𝑮, 𝑯, 𝑨 and 𝑩, without
+//! revealing 𝑘, i.e. it proves that log𝑮(𝑨) = log𝑯(𝑩).
+//!
+//! This is the non-interactive Chaum-Pedersen protocol, with the challenge
+//! derived via [`ChallengeScalar`]. It's used e.g. by verifiable OPRFs to
+//! prove that the server evaluated the OPRF with its committed key, and by
+//! VRFs to prove that their output was computed correctly.
+
+use crate::{
+    hash2curve::ExpandMsg, ops::ChallengeScalar, CurveArithmetic, Error, NonZeroScalar,
+    ProjectivePoint, Result, Scalar,
+};
+use ff::Field;
+use group::GroupEncoding;
+use rand_core::CryptoRngCore;
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+/// Non-interactive proof that log𝑮(𝑨) = log𝑯(𝑩).
+///
+/// The proof consists of the challenge 𝑐 and response 𝑠 = 𝑟 − 𝑐 × 𝑘, where
+/// 𝑟 is a random nonce. The challenge is derived from the points 𝑮, 𝑯, 𝑨,
+/// 𝑩, 𝑮 × 𝑟 and 𝑯 × 𝑟 (in that order) using a domain separation tag, which
+/// the prover and verifier must agree on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DleqProof<C>
+where
+    C: CurveArithmetic,
+{
+    /// Challenge 𝑐.
+    challenge: Scalar<C>,
+
+    /// Response 𝑠.
+    response: Scalar<C>,
+}
+
+impl<C> DleqProof<C>
+where
+    C: CurveArithmetic,
+    Scalar<C>: ChallengeScalar,
+    ProjectivePoint<C>: GroupEncoding,
+{
+    /// Create a proof from its challenge and response, e.g. after
+    /// deserializing it.
+    pub fn new(challenge: Scalar<C>, response: Scalar<C>) -> Self {
+        Self {
+            challenge,
+            response,
+        }
+    }
+
+    /// Prove that 𝑮 × `k` and 𝑯 × `k` have the same discrete logarithm with
+    /// respect to `g` and `h`.
+    ///
+    /// Returns an error if `X` rejects the domain separation tag `dst`.
+    pub fn prove<'a, X>(
+        k: &NonZeroScalar<C>,
+        g: &ProjectivePoint<C>,
+        h: &ProjectivePoint<C>,
+        dst: &'a [u8],
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<Self>
+    where
+        X: ExpandMsg<'a>,
+    {
+        let a = *g * k.as_ref();
+        let b = *h * k.as_ref();
+
+        let mut r = *NonZeroScalar::<C>::random(rng);
+        let challenge = challenge::<C, X>(g, h, &a, &b, &(*g * r), &(*h * r), dst)?;
+        let response = r - challenge * k.as_ref();
+        r.zeroize();
+
+        Ok(Self::new(challenge, response))
+    }
+
+    /// Verify that log𝑮(𝑨) = log𝑯(𝑩) for `g`, `h`, `a` and `b` respectively,
+    /// using the domain separation tag `dst` the proof was created with.
+    pub fn verify<'a, X>(
+        &self,
+        g: &ProjectivePoint<C>,
+        h: &ProjectivePoint<C>,
+        a: &ProjectivePoint<C>,
+        b: &ProjectivePoint<C>,
+        dst: &'a [u8],
+    ) -> Result<()>
+    where
+        X: ExpandMsg<'a>,
+    {
+        // 𝑮 × 𝑠 + 𝑨 × 𝑐 = 𝑮 × (𝑟 − 𝑐 × 𝑘) + 𝑮 × 𝑘 × 𝑐 = 𝑮 × 𝑟
+        let r_g = *g * self.response + *a * self.challenge;
+        let r_h = *h * self.response + *b * self.challenge;
+        let challenge = challenge::<C, X>(g, h, a, b, &r_g, &r_h, dst)?;
+
+        if bool::from(challenge.ct_eq(&self.challenge)) {
+            Ok(())
+        } else {
+            Err(Error)
+        }
+    }
+
+    /// Challenge 𝑐.
+    pub fn challenge(&self) -> &Scalar<C> {
+        &self.challenge
+    }
+
+    /// Response 𝑠.
+    pub fn response(&self) -> &Scalar<C> {
+        &self.response
+    }
+}
+
+/// Derive the challenge from the given points.
+fn challenge<'a, C, X>(
+    g: &ProjectivePoint<C>,
+    h: &ProjectivePoint<C>,
+    a: &ProjectivePoint<C>,
+    b: &ProjectivePoint<C>,
+    r_g: &ProjectivePoint<C>,
+    r_h: &ProjectivePoint<C>,
+    dst: &'a [u8],
+) -> Result<Scalar<C>>
+where
+    C: CurveArithmetic,
+    Scalar<C>: ChallengeScalar,
+    ProjectivePoint<C>: GroupEncoding,
+    X: ExpandMsg<'a>,
+{
+    let [g, h, a, b, r_g, r_h] = [g, h, a, b, r_g, r_h].map(GroupEncoding::to_bytes);
+    let msgs = [
+        g.as_ref(),
+        h.as_ref(),
+        a.as_ref(),
+        b.as_ref(),
+        r_g.as_ref(),
+        r_h.as_ref(),
+    ];
+    let challenge = Scalar::<C>::challenge::<X>(&msgs, dst)?;
+
+    // A zero challenge would make the proof independent of the secret.
+    if bool::from(challenge.is_zero()) {
+        return Err(Error);
+    }
+
+    Ok(challenge)
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::DleqProof;
+    use crate::{
+        dev::{MockCurve, NonZeroScalar, ProjectivePoint, Scalar},
+        hash2curve::ExpandMsgXmd,
+    };
+    use group::Group;
+    use rand_core::OsRng;
+    use sha2::Sha256;
+
+    type Proof = DleqProof<MockCurve>;
+    type Xmd = ExpandMsgXmd<Sha256>;
+
+    const DST: &[u8] = b"DLEQ-TEST-V1";
+
+    fn generators() -> (ProjectivePoint, ProjectivePoint) {
+        let g = ProjectivePoint::generator() * Scalar::from(1u64);
+        let h = ProjectivePoint::generator() * Scalar::from(12345u64);
+        (g, h)
+    }
+
+    #[test]
+    fn prove_and_verify() {
+        let (g, h) = generators();
+        let k = NonZeroScalar::random(&mut OsRng);
+        let (a, b) = (g * k.as_ref(), h * k.as_ref());
+
+        let proof = Proof::prove::<Xmd>(&k, &g, &h, DST, &mut OsRng).unwrap();
+        proof.verify::<Xmd>(&g, &h, &a, &b, DST).unwrap();
+
+        let proof = Proof::new(*proof.challenge(), *proof.response());
+        proof.verify::<Xmd>(&g, &h, &a, &b, DST).unwrap();
+        assert!(proof.verify::<Xmd>(&g, &h, &a, &b, b"OTHER-V1").is_err());
+    }
+
+    #[test]
+    fn reject_unequal_logarithms() {
+        let (g, h) = generators();
+        let k = NonZeroScalar::random(&mut OsRng);
+        let (a, b) = (g * k.as_ref(), h * k.as_ref());
+        let proof = Proof::prove::<Xmd>(&k, &g, &h, DST, &mut OsRng).unwrap();
+
+        assert!(proof.verify::<Xmd>(&g, &h, &a, &(b + h), DST).is_err());
+        assert!(proof.verify::<Xmd>(&g, &h, &(a + g), &b, DST).is_err());
+        assert!(proof.verify::<Xmd>(&h, &g, &a, &b, DST).is_err());
+    }
+}
@@ -1,8 +1,8 @@
 //! Elliptic Curve Diffie-Hellman Support.
 //!
 //! This module contains a generic ECDH implementation which is usable with
-//! any elliptic curve which implements the [`CurveArithmetic`] trait (presently
-//! the `k256` and `p256` crates)
+//! any elliptic curve which implements the [`CurveArithmetic`] trait (e.g.
+//! the `k256`, `p256`, and `p384` crates).
 //!
 //! # ECDH Ephemeral (ECDHE) Usage
 //!
@@ -23,17 +23,41 @@
 //! Static ECDH key exchanges are supported via the low-level
 //! [`diffie_hellman`] function.
 //!
+//! # Montgomery curves
+//!
+//! X25519/X448-style x-only key exchanges on curves which impl
+//! [`MontgomeryCurve`][`crate::montgomery::MontgomeryCurve`] are supported
+//! via [`MontgomerySecretKey::diffie_hellman`], which also produces a
+//! [`SharedSecret`].
+//!
+//! [`MontgomerySecretKey::diffie_hellman`]: crate::montgomery::MontgomerySecretKey::diffie_hellman
+//!
+//! # Deriving keys
+//!
+//! The raw x-coordinate of the shared point is not uniformly random and
+//! should not be used (or hashed ad hoc) as a key. Instead, use
+//! [`SharedSecret::extract`] to obtain an HKDF instance, and expand it with
+//! a protocol-specific `info` string into the required key material:
+//!
+//! ```ignore
+//! let shared_secret = alice_secret.diffie_hellman(&bob_public_key);
+//! let hkdf = shared_secret.extract::<sha2::Sha256>(Some(b"salt"));
+//! let mut key = [0u8; 32];
+//! hkdf.expand(b"my protocol v1 encryption key", &mut key)?;
+//! ```
+//!
 //! [AKE]: https://en.wikipedia.org/wiki/Authenticated_Key_Exchange
 //! [SIGMA]: https://webee.technion.ac.il/~hugo/sigma-pdf.pdf
 
 use crate::{
-    AffinePoint, AffineXCoordinate, Curve, CurveArithmetic, FieldBytes, NonZeroScalar,
-    ProjectivePoint, PublicKey,
+    AffinePoint, AffineXCoordinate, Curve, CurveArithmetic, Error, FieldBytes, FieldSize,
+    NonZeroScalar, ProjectivePoint, PublicKey, Result,
 };
-use core::borrow::Borrow;
+use core::{borrow::Borrow, fmt, marker::PhantomData};
 use digest::{crypto_common::BlockSizeUser, Digest};
+use generic_array::typenum::Unsigned;
 use group::Curve as _;
-use hkdf::{hmac::SimpleHmac, Hkdf};
+use hkdf::{hmac::SimpleHmac, Hkdf, HkdfExtract};
 use rand_core::CryptoRngCore;
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
@@ -196,6 +220,15 @@ impl<C: Curve> SharedSecret<C> {
         Hkdf::new(salt, &self.secret_bytes)
     }
 
+    /// Begin combining this shared secret with others using a
+    /// [`SharedSecretBuilder`].
+    pub fn combine_with<D>(&self, salt: Option<&[u8]>) -> SharedSecretBuilder<C, D>
+    where
+        D: BlockSizeUser + Clone + Digest,
+    {
+        SharedSecretBuilder::new(salt).chain(self)
+    }
+
     /// This value contains the raw serialized x-coordinate of the elliptic curve
     /// point computed from a Diffie-Hellman exchange, serialized as bytes.
     ///
@@ -227,10 +260,157 @@ impl<C: Curve> From<FieldBytes<C>> for SharedSecret<C> {
     }
 }
 
+impl<C: Curve> Zeroize for SharedSecret<C> {
+    fn zeroize(&mut self) {
+        self.secret_bytes.zeroize()
+    }
+}
+
 impl<C: Curve> ZeroizeOnDrop for SharedSecret<C> {}
 
 impl<C: Curve> Drop for SharedSecret<C> {
     fn drop(&mut self) {
-        self.secret_bytes.zeroize()
+        self.zeroize();
+    }
+}
+
+/// Combiner for the outputs of multiple Diffie-Hellman exchanges.
+///
+/// Protocols such as Signal's [X3DH] and Noise `IK` compute several ECDH
+/// shared secrets during a single handshake and derive keys from all of
+/// them. This builder feeds the raw secrets into the extract step of [HKDF]
+/// in a fixed order:
+///
+/// ```text
+/// IKM = prefix || DH1 || DH2 || ... || DHn
+/// ```
+///
+/// where `prefix` is empty unless set with [`SharedSecretBuilder::x3dh_prefix`],
+/// and `DH1..DHn` are the raw secret bytes of each [`SharedSecret`] in the
+/// order they were added with [`SharedSecretBuilder::chain`].
+///
+/// [X3DH]: https://signal.org/docs/specifications/x3dh/
+/// [HKDF]: https://en.wikipedia.org/wiki/HKDF
+pub struct SharedSecretBuilder<C, D>
+where
+    C: Curve,
+    D: BlockSizeUser + Clone + Digest,
+{
+    /// HKDF extract state the secrets are fed into
+    extract: HkdfExtract<D, SimpleHmac<D>>,
+
+    /// Whether any input has been added yet
+    empty: bool,
+
+    /// Curve the combined shared secrets were computed over
+    curve: PhantomData<C>,
+}
+
+impl<C, D> SharedSecretBuilder<C, D>
+where
+    C: Curve,
+    D: BlockSizeUser + Clone + Digest,
+{
+    /// Create a new builder using the given HKDF `salt`.
+    pub fn new(salt: Option<&[u8]>) -> Self {
+        Self {
+            extract: HkdfExtract::new(salt),
+            empty: true,
+            curve: PhantomData,
+        }
+    }
+
+    /// Prepend the X3DH padding `F`: a sequence of `0xFF` bytes the length of
+    /// a serialized field element, which ensures the input keying material
+    /// can't collide with the one of a signature scheme using the same keys.
+    ///
+    /// This must be called before any shared secret is added: returns an
+    /// error otherwise.
+    pub fn x3dh_prefix(mut self) -> Result<Self> {
+        if !self.empty {
+            return Err(Error);
+        }
+
+        for _ in 0..FieldSize::<C>::USIZE {
+            self.extract.input_ikm(&[0xFF]);
+        }
+
+        self.empty = false;
+        Ok(self)
+    }
+
+    /// Add the next shared secret to the combined input keying material.
+    pub fn chain(mut self, shared_secret: &SharedSecret<C>) -> Self {
+        self.extract.input_ikm(shared_secret.raw_secret_bytes());
+        self.empty = false;
+        self
+    }
+
+    /// Finish the HKDF extract step, returning an [`Hkdf`] instance which can
+    /// be used to expand the combined secrets into output key material.
+    pub fn finalize(self) -> Hkdf<D, SimpleHmac<D>> {
+        self.extract.finalize().1
+    }
+}
+
+impl<C, D> fmt::Debug for SharedSecretBuilder<C, D>
+where
+    C: Curve,
+    D: BlockSizeUser + Clone + Digest,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedSecretBuilder")
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::{SharedSecret, SharedSecretBuilder};
+    use crate::{dev::MockCurve, FieldBytes};
+    use hex_literal::hex;
+    use hkdf::{hmac::SimpleHmac, Hkdf};
+    use sha2::Sha256;
+
+    const DH1: [u8; 32] = hex!("0101010101010101010101010101010101010101010101010101010101010101");
+    const DH2: [u8; 32] = hex!("0202020202020202020202020202020202020202020202020202020202020202");
+
+    #[test]
+    fn combine_matches_concatenated_ikm() {
+        let dh1 = SharedSecret::<MockCurve>::from(FieldBytes::<MockCurve>::from(DH1));
+        let dh2 = SharedSecret::<MockCurve>::from(FieldBytes::<MockCurve>::from(DH2));
+
+        let mut ikm = [0xFF; 96];
+        ikm[32..64].copy_from_slice(&DH1);
+        ikm[64..].copy_from_slice(&DH2);
+
+        let mut expected = [0u8; 32];
+        Hkdf::<Sha256, SimpleHmac<Sha256>>::new(Some(b"salt"), &ikm)
+            .expand(b"info", &mut expected)
+            .unwrap();
+
+        let mut actual = [0u8; 32];
+        SharedSecretBuilder::<MockCurve, Sha256>::new(Some(b"salt"))
+            .x3dh_prefix()
+            .unwrap()
+            .chain(&dh1)
+            .chain(&dh2)
+            .finalize()
+            .expand(b"info", &mut actual)
+            .unwrap();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn x3dh_prefix_after_secret() {
+        let dh1 = SharedSecret::<MockCurve>::from(FieldBytes::<MockCurve>::from(DH1));
+        let builder = SharedSecretBuilder::<MockCurve, Sha256>::new(None).chain(&dh1);
+        assert!(builder.x3dh_prefix().is_err());
+
+        let builder = SharedSecretBuilder::<MockCurve, Sha256>::new(None)
+            .x3dh_prefix()
+            .unwrap();
+        assert!(builder.x3dh_prefix().is_err());
     }
 }
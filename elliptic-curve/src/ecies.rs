@@ -0,0 +1,225 @@
+//! Elliptic Curve Integrated Encryption Scheme (ECIES).
+//!
+//! This module contains a generic ECIES implementation which combines an
+//! ephemeral-static ECDH exchange with [HKDF] and an [AEAD] cipher, usable
+//! with any elliptic curve which implements the [`CurveArithmetic`] trait.
+//!
+//! # Construction
+//!
+//! To encrypt a message to a recipient's public key `P`:
+//!
+//! 1. Generate an ephemeral secret `e` and compute `R = e·G`.
+//! 2. Compute the ECDH shared secret `Z` between `e` and `P`.
+//! 3. Derive the AEAD key using HKDF with digest `D`, where
+//!    `IKM = Z`, `salt = R` and `info = "elliptic-curve ECIES" || P`,
+//!    with `R` and `P` both in uncompressed SEC1 encoding.
+//! 4. Encrypt the message with the AEAD cipher `A` using an all-zero nonce,
+//!    which is safe since the key is never reused.
+//!
+//! The resulting ciphertext is `R || AEAD(plaintext)`.
+//!
+//! Binding both public keys into the key derivation ensures a ciphertext
+//! can't be reinterpreted under a different ephemeral or recipient key.
+//!
+//! [HKDF]: https://en.wikipedia.org/wiki/HKDF
+//! [AEAD]: https://en.wikipedia.org/wiki/Authenticated_encryption
+
+use crate::{
+    ecdh::{self, EphemeralSecret},
+    sec1::{EncodedPoint, FromEncodedPoint, ModulusSize, ToEncodedPoint, UncompressedPointSize},
+    AffinePoint, CurveArithmetic, Error, FieldSize, PublicKey, Result, SecretKey,
+};
+use aead::{Aead, Key, KeyInit, Nonce};
+use alloc::vec::Vec;
+use digest::{crypto_common::BlockSizeUser, Digest};
+use generic_array::typenum::Unsigned;
+use rand_core::CryptoRngCore;
+use zeroize::Zeroize;
+
+/// Domain separation string used in the HKDF `info` parameter.
+const DOMAIN: &[u8] = b"elliptic-curve ECIES";
+
+/// Encrypt messages to the holder of a secret key using ECIES with the AEAD
+/// cipher `A` and the digest `D` used with HKDF.
+///
+/// See the [module-level documentation][self] for the construction.
+pub trait EciesEncrypt<A, D> {
+    /// Encrypt the given plaintext, returning the serialized ciphertext.
+    fn ecies_encrypt(&self, rng: &mut impl CryptoRngCore, plaintext: &[u8]) -> Result<Vec<u8>>;
+}
+
+/// Decrypt messages encrypted with [`EciesEncrypt`], using the same AEAD
+/// cipher `A` and digest `D`.
+pub trait EciesDecrypt<A, D> {
+    /// Decrypt the given serialized ciphertext, returning the plaintext.
+    fn ecies_decrypt(&self, ciphertext: &[u8]) -> Result<Vec<u8>>;
+}
+
+impl<C, A, D> EciesEncrypt<A, D> for PublicKey<C>
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldSize<C>: ModulusSize,
+    A: Aead + KeyInit,
+    D: BlockSizeUser + Clone + Digest,
+{
+    fn ecies_encrypt(&self, rng: &mut impl CryptoRngCore, plaintext: &[u8]) -> Result<Vec<u8>> {
+        let ephemeral_secret = EphemeralSecret::<C>::random(rng);
+        let ephemeral_point = ephemeral_secret.public_key().to_encoded_point(false);
+        let shared_secret = ephemeral_secret.diffie_hellman(self);
+
+        let cipher = derive_cipher::<C, A, D>(&shared_secret, &ephemeral_point, self)?;
+        let ciphertext = cipher
+            .encrypt(&Nonce::<A>::default(), plaintext)
+            .map_err(|_| Error)?;
+
+        let mut out = Vec::with_capacity(ephemeral_point.len() + ciphertext.len());
+        out.extend_from_slice(ephemeral_point.as_bytes());
+        out.extend_from_slice(&ciphertext);
+        Ok(out)
+    }
+}
+
+impl<C, A, D> EciesDecrypt<A, D> for SecretKey<C>
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldSize<C>: ModulusSize,
+    A: Aead + KeyInit,
+    D: BlockSizeUser + Clone + Digest,
+{
+    fn ecies_decrypt(&self, ciphertext: &[u8]) -> Result<Vec<u8>> {
+        let point_len = UncompressedPointSize::<C>::USIZE;
+
+        if ciphertext.len() < point_len {
+            return Err(Error);
+        }
+
+        let (point_bytes, ciphertext) = ciphertext.split_at(point_len);
+        let ephemeral_point = EncodedPoint::<C>::from_bytes(point_bytes).map_err(|_| Error)?;
+
+        if ephemeral_point.is_compressed() || ephemeral_point.is_identity() {
+            return Err(Error);
+        }
+
+        let ephemeral_public_key = PublicKey::<C>::from_encoded_point(&ephemeral_point);
+        let ephemeral_public_key =
+            Option::<PublicKey<C>>::from(ephemeral_public_key).ok_or(Error)?;
+        let shared_secret =
+            ecdh::diffie_hellman(self.to_nonzero_scalar(), ephemeral_public_key.as_affine());
+
+        let cipher =
+            derive_cipher::<C, A, D>(&shared_secret, &ephemeral_point, &self.public_key())?;
+        cipher
+            .decrypt(&Nonce::<A>::default(), ciphertext)
+            .map_err(|_| Error)
+    }
+}
+
+/// Derive the AEAD cipher instance for a given ECDH shared secret.
+fn derive_cipher<C, A, D>(
+    shared_secret: &ecdh::SharedSecret<C>,
+    ephemeral_point: &EncodedPoint<C>,
+    recipient: &PublicKey<C>,
+) -> Result<A>
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldSize<C>: ModulusSize,
+    A: KeyInit,
+    D: BlockSizeUser + Clone + Digest,
+{
+    let recipient_point = recipient.to_encoded_point(false);
+    let mut key = Key::<A>::default();
+
+    shared_secret
+        .extract::<D>(Some(ephemeral_point.as_bytes()))
+        .expand_multi_info(&[DOMAIN, recipient_point.as_bytes()], &mut key)
+        .map_err(|_| Error)?;
+
+    let cipher = A::new(&key);
+    key.zeroize();
+    Ok(cipher)
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::{EciesDecrypt, EciesEncrypt};
+    use crate::{
+        dev::{EncodedPoint, PublicKey, SecretKey, PSEUDO_COORDINATE_FIXED_BASE_MUL},
+        sec1::FromEncodedPoint,
+    };
+    use aes_gcm::Aes128Gcm;
+    use alloc::vec::Vec;
+    use rand_core::OsRng;
+    use sha2::Sha256;
+
+    const MSG: &[u8] = b"attack at dawn";
+
+    fn encrypt(public_key: &PublicKey, plaintext: &[u8]) -> Vec<u8> {
+        EciesEncrypt::<Aes128Gcm, Sha256>::ecies_encrypt(public_key, &mut OsRng, plaintext).unwrap()
+    }
+
+    fn decrypt(secret_key: &SecretKey, ciphertext: &[u8]) -> crate::Result<Vec<u8>> {
+        EciesDecrypt::<Aes128Gcm, Sha256>::ecies_decrypt(secret_key, ciphertext)
+    }
+
+    #[test]
+    fn round_trip() {
+        let secret_key = SecretKey::random(&mut OsRng);
+        let public_key = secret_key.public_key();
+
+        for plaintext in [MSG, &[]] {
+            let ciphertext = encrypt(&public_key, plaintext);
+            assert_eq!(decrypt(&secret_key, &ciphertext).unwrap(), plaintext);
+        }
+
+        // Encryption is randomized by the ephemeral key
+        assert_ne!(encrypt(&public_key, MSG), encrypt(&public_key, MSG));
+    }
+
+    #[test]
+    fn reject_tampered_ciphertext() {
+        let secret_key = SecretKey::random(&mut OsRng);
+        let ciphertext = encrypt(&secret_key.public_key(), MSG);
+
+        // Tamper with the ephemeral key, the encrypted message and the tag
+        for i in [1, 65, ciphertext.len() - 1] {
+            let mut tampered = ciphertext.clone();
+            tampered[i] ^= 1;
+            assert!(decrypt(&secret_key, &tampered).is_err());
+        }
+
+        assert!(decrypt(&secret_key, &ciphertext[..ciphertext.len() - 1]).is_err());
+        assert!(decrypt(&secret_key, &ciphertext[..64]).is_err());
+        assert!(decrypt(&SecretKey::random(&mut OsRng), &ciphertext).is_err());
+    }
+
+    #[test]
+    fn reject_invalid_ephemeral_key() {
+        let secret_key = SecretKey::random(&mut OsRng);
+        let ciphertext = encrypt(&secret_key.public_key(), MSG);
+
+        // The identity, as the generator multiplied by zero
+        let identity = EncodedPoint::from_affine_coordinates(
+            &Default::default(),
+            &PSEUDO_COORDINATE_FIXED_BASE_MUL.into(),
+            false,
+        );
+        assert!(bool::from(
+            PublicKey::from_encoded_point(&identity).is_none()
+        ));
+
+        for prefix in [identity.as_bytes(), &[0x00; 65], &[0x05; 65]] {
+            let mut invalid = prefix.to_vec();
+            invalid.extend_from_slice(&ciphertext[65..]);
+            assert!(decrypt(&secret_key, &invalid).is_err());
+        }
+
+        // Compressed ephemeral keys aren't accepted
+        let mut compressed = ciphertext.clone();
+        compressed[0] = 0x02;
+        compressed.drain(33..65);
+        assert!(decrypt(&secret_key, &compressed).is_err());
+    }
+}
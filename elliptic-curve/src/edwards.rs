@@ -0,0 +1,73 @@
+//! Edwards curves and birational maps to Montgomery form.
+//!
+//! Twisted Edwards curves (e.g. edwards25519 as used by Ed25519, or
+//! edwards448 as used by Ed448) are birationally equivalent to Montgomery
+//! curves (Curve25519 and Curve448 respectively), which allows keys to be
+//! converted between the two representations, e.g. to use an Ed25519 public
+//! key for X25519 key exchange.
+//!
+//! Points on Edwards curves are serialized as specified in [RFC8032]: the
+//! little endian y-coordinate, with the sign of the x-coordinate stored in
+//! the most significant bit of the final byte. This encoding is available
+//! via [`PointEncoding`][`crate::PointEncoding`] using the
+//! [`Rfc8032`][`crate::point::Rfc8032`] format for points which impl
+//! [`CompressEdwardsPoint`] and [`DecompressEdwardsPoint`].
+//!
+//! [RFC8032]: https://www.rfc-editor.org/rfc/rfc8032
+
+use crate::{montgomery::MontgomeryCurve, Curve};
+use generic_array::{ArrayLength, GenericArray};
+use subtle::{Choice, CtOption};
+
+/// Serialized Edwards point as specified in [RFC8032].
+///
+/// [RFC8032]: https://www.rfc-editor.org/rfc/rfc8032
+pub type EdwardsEncodedPoint<C> = GenericArray<u8, <C as EdwardsCurve>::EncodedPointSize>;
+
+/// Elliptic curve in (twisted) Edwards form, e.g. edwards25519 or edwards448.
+pub trait EdwardsCurve: Curve {
+    /// Birationally equivalent Montgomery curve.
+    type Montgomery: MontgomeryCurve;
+
+    /// Size of a serialized point: 32 bytes for edwards25519, or 57 bytes for
+    /// edwards448.
+    type EncodedPointSize: ArrayLength<u8>;
+}
+
+/// Serialize an Edwards point using the [RFC8032] encoding.
+///
+/// [RFC8032]: https://www.rfc-editor.org/rfc/rfc8032
+pub trait CompressEdwardsPoint<C: EdwardsCurve> {
+    /// Serialize this point.
+    fn compress(&self) -> EdwardsEncodedPoint<C>;
+}
+
+/// Deserialize an Edwards point from its [RFC8032] encoding.
+///
+/// [RFC8032]: https://www.rfc-editor.org/rfc/rfc8032
+pub trait DecompressEdwardsPoint<C: EdwardsCurve>: Sized {
+    /// Attempt to decompress an Edwards point, returning none if the encoding
+    /// is non-canonical or doesn't describe a point on the curve.
+    fn decompress(bytes: &EdwardsEncodedPoint<C>) -> CtOption<Self>;
+}
+
+/// Map a point in Edwards form to its birationally equivalent Montgomery
+/// form `M`, i.e. 𝑢 = (1 + 𝑦) / (1 − 𝑦) in the case of Curve25519.
+pub trait ToMontgomery<M> {
+    /// Convert this point to Montgomery form.
+    fn to_montgomery(&self) -> M;
+}
+
+/// Map a point in Montgomery form to its birationally equivalent Edwards
+/// form `E`, i.e. 𝑦 = (𝑢 − 1) / (𝑢 + 1) in the case of Curve25519.
+///
+/// Montgomery points are represented solely by their u-coordinate, so the
+/// sign of the Edwards x-coordinate must be supplied separately.
+pub trait ToEdwards<E> {
+    /// Convert this point to Edwards form, selecting the point whose
+    /// x-coordinate has the given `sign`.
+    ///
+    /// Returns none if there is no corresponding Edwards point, e.g. because
+    /// the u-coordinate is that of a point on the curve's quadratic twist.
+    fn to_edwards(&self, sign: Choice) -> CtOption<E>;
+}
@@ -1,26 +1,19 @@
 //! Traits for hashing to field elements.
 //!
-//! <https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve>
+//! <https://www.rfc-editor.org/rfc/rfc9380.html#section-5>
 
 mod expand_msg;
 
 pub use expand_msg::{xmd::*, xof::*, *};
 
-use crate::Result;
-use generic_array::{typenum::Unsigned, ArrayLength, GenericArray};
-
-/// The trait for helping to convert to a field element.
-pub trait FromOkm {
-    /// The number of bytes needed to convert to a field element.
-    type Length: ArrayLength<u8>;
+pub use crate::ops::FromOkm;
 
-    /// Convert a byte sequence into a field element.
-    fn from_okm(data: &GenericArray<u8, Self::Length>) -> Self;
-}
+use crate::Result;
+use generic_array::{typenum::Unsigned, GenericArray};
 
 /// Convert an arbitrary byte sequence into a field element.
 ///
-/// <https://tools.ietf.org/html/draft-irtf-cfrg-hash-to-curve-11#section-5.3>
+/// <https://www.rfc-editor.org/rfc/rfc9380.html#section-5.2>
 ///
 /// # Errors
 /// See implementors of [`ExpandMsg`] for errors:
@@ -1,6 +1,6 @@
 //! Traits for mapping an isogeny to another curve
 //!
-//! <https://datatracker.ietf.org/doc/draft-irtf-cfrg-hash-to-curve>
+//! <https://www.rfc-editor.org/rfc/rfc9380.html#section-6.6.3>
 
 use core::ops::{AddAssign, Mul};
 use ff::Field;
@@ -14,7 +14,7 @@ pub struct IsogenyCoefficients<F: Field + AddAssign + Mul<Output = F>> {
     pub xden: &'static [F],
     /// The coefficients for the y numerator
     pub ynum: &'static [F],
-    /// The coefficients for the x denominator
+    /// The coefficients for the y denominator
     pub yden: &'static [F],
 }
 
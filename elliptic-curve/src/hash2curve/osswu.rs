@@ -1,7 +1,9 @@
 //! Optimized simplified Shallue-van de Woestijne-Ulas methods.
 //!
 //! <https://eprint.iacr.org/2009/340.pdf>
+//! <https://www.rfc-editor.org/rfc/rfc9380.html#section-6.6.2>
 
+use super::Isogeny;
 use ff::Field;
 use subtle::Choice;
 
@@ -10,13 +12,13 @@ pub struct OsswuMapParams<F>
 where
     F: Field,
 {
-    /// The first constant term
+    /// The first constant term, `c1 = (q - 3) / 4` for `q = 3 mod 4`
     pub c1: &'static [u64],
-    /// The second constant term
+    /// The second constant term, `c2 = sqrt(-Z^3)`
     pub c2: F,
-    /// The ISO A variable or Curve A variable
+    /// The ISO A variable or Curve A variable, i.e. `A'` or `A`
     pub map_a: F,
-    /// The ISO A variable or Curve A variable
+    /// The ISO B variable or Curve B variable, i.e. `B'` or `B`
     pub map_b: F,
     /// The Z parameter
     pub z: F,
@@ -85,3 +87,19 @@ pub trait OsswuMap: Field + Sgn0 {
         (x, y)
     }
 }
+
+/// Map a field element to the `(x, y)` coordinates of a point on a curve
+/// using the simplified SWU method followed by the curve's isogeny.
+///
+/// This is intended for curves such as secp256k1 where either A or B is
+/// zero, so [`OsswuMap::PARAMS`] describes an isogenous curve `E'` and
+/// [`Isogeny::COEFFICIENTS`] the 3-isogeny (or higher) mapping it back onto
+/// the original curve. Curves where A≠0 and B≠0 can use [`OsswuMap::osswu`]
+/// directly.
+pub fn osswu_isogeny_map<F>(u: &F) -> (F, F)
+where
+    F: OsswuMap + Isogeny,
+{
+    let (x, y) = u.osswu();
+    F::isogeny(x, y)
+}
@@ -0,0 +1,271 @@
+//! Hierarchical deterministic (HD) key derivation.
+//!
+//! Generic support for deriving child keys in the style of [BIP32], where a
+//! child key is computed by adding a scalar "tweak" to its parent key.
+//!
+//! The tweak, along with the child's chain code, is produced by a
+//! pseudorandom function (PRF) keyed by the parent's chain code, e.g.
+//! HMAC-SHA512 in the case of BIP32. The PRF is supplied by implementing the
+//! [`ChildKeyPrf`] trait, which means the same [`DeriveChild`] logic can be
+//! used with any curve with an arithmetic implementation (secp256k1, P-256,
+//! etc).
+//!
+//! [BIP32]: https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki
+
+use crate::{
+    CurveArithmetic, Error, KeyPair, NonZeroScalar, ProjectivePoint, PublicKey, Result, Scalar,
+    SecretKey,
+};
+use group::{Curve as _, Group};
+
+/// Index of a child key.
+///
+/// Indexes with the high bit set denote hardened derivation, where the
+/// child can only be derived from the parent's secret key.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub struct ChildNumber(pub u32);
+
+impl ChildNumber {
+    /// Bit which is set for hardened child numbers.
+    pub const HARDENED_FLAG: u32 = 1 << 31;
+
+    /// Create a new [`ChildNumber`] from an index and hardened flag.
+    ///
+    /// Returns an error if `index` has the [`ChildNumber::HARDENED_FLAG`] bit
+    /// set.
+    pub fn new(index: u32, hardened: bool) -> Result<Self> {
+        if index & Self::HARDENED_FLAG != 0 {
+            return Err(Error);
+        }
+
+        if hardened {
+            Ok(Self(index | Self::HARDENED_FLAG))
+        } else {
+            Ok(Self(index))
+        }
+    }
+
+    /// Get the index of this child, without the hardened flag.
+    pub fn index(self) -> u32 {
+        self.0 & !Self::HARDENED_FLAG
+    }
+
+    /// Is this a hardened child number?
+    pub fn is_hardened(self) -> bool {
+        self.0 & Self::HARDENED_FLAG != 0
+    }
+
+    /// Serialize this child number as a big endian integer.
+    pub fn to_be_bytes(self) -> [u8; 4] {
+        self.0.to_be_bytes()
+    }
+}
+
+impl From<u32> for ChildNumber {
+    fn from(n: u32) -> ChildNumber {
+        ChildNumber(n)
+    }
+}
+
+impl From<ChildNumber> for u32 {
+    fn from(n: ChildNumber) -> u32 {
+        n.0
+    }
+}
+
+/// Parent key material passed to a [`ChildKeyPrf`].
+#[derive(Debug)]
+pub enum ParentKey<'a, C: CurveArithmetic> {
+    /// Parent secret key, used for hardened derivation.
+    Secret(&'a SecretKey<C>),
+
+    /// Parent public key, used for non-hardened derivation.
+    Public(&'a PublicKey<C>),
+}
+
+/// Pseudorandom function used to compute the tweak and chain code of child
+/// keys.
+pub trait ChildKeyPrf<C: CurveArithmetic> {
+    /// Chain code: additional entropy associated with each key.
+    type ChainCode;
+
+    /// Compute the scalar tweak which is added to the parent key to obtain
+    /// the child key, along with the child's chain code.
+    ///
+    /// The parent key is always [`ParentKey::Secret`] for hardened child
+    /// numbers and [`ParentKey::Public`] otherwise, which ensures that public
+    /// and secret derivation of non-hardened children agree.
+    ///
+    /// Implementations should return an error for outputs which aren't valid
+    /// tweaks (e.g. values which overflow the curve order in BIP32).
+    fn child_tweak(
+        chain_code: &Self::ChainCode,
+        parent_key: ParentKey<'_, C>,
+        child_number: ChildNumber,
+    ) -> Result<(Scalar<C>, Self::ChainCode)>;
+}
+
+/// Derive child keys from a parent key and chain code.
+pub trait DeriveChild<C: CurveArithmetic>: Sized {
+    /// Derive the child key with the given [`ChildNumber`], returning it along
+    /// with its chain code.
+    ///
+    /// Returns an error if the derived key is invalid (i.e. zero or the
+    /// identity), which in BIP32 means the next index should be used instead.
+    fn derive_child<P: ChildKeyPrf<C>>(
+        &self,
+        chain_code: &P::ChainCode,
+        child_number: ChildNumber,
+    ) -> Result<(Self, P::ChainCode)>;
+}
+
+impl<C> DeriveChild<C> for SecretKey<C>
+where
+    C: CurveArithmetic,
+{
+    fn derive_child<P: ChildKeyPrf<C>>(
+        &self,
+        chain_code: &P::ChainCode,
+        child_number: ChildNumber,
+    ) -> Result<(Self, P::ChainCode)> {
+        let (tweak, chain_code) = if child_number.is_hardened() {
+            P::child_tweak(chain_code, ParentKey::Secret(self), child_number)?
+        } else {
+            P::child_tweak(
+                chain_code,
+                ParentKey::Public(&self.public_key()),
+                child_number,
+            )?
+        };
+
+        let scalar = *self.to_nonzero_scalar().as_ref() + tweak;
+        let scalar = Option::<NonZeroScalar<C>>::from(NonZeroScalar::new(scalar)).ok_or(Error)?;
+        Ok((scalar.into(), chain_code))
+    }
+}
+
+impl<C> DeriveChild<C> for PublicKey<C>
+where
+    C: CurveArithmetic,
+{
+    /// Derive a child public key.
+    ///
+    /// Returns an error for hardened child numbers, which can only be derived
+    /// from secret keys.
+    fn derive_child<P: ChildKeyPrf<C>>(
+        &self,
+        chain_code: &P::ChainCode,
+        child_number: ChildNumber,
+    ) -> Result<(Self, P::ChainCode)> {
+        if child_number.is_hardened() {
+            return Err(Error);
+        }
+
+        let (tweak, chain_code) =
+            P::child_tweak(chain_code, ParentKey::Public(self), child_number)?;
+
+        let point = self.to_projective() + ProjectivePoint::<C>::generator() * tweak;
+        Ok((PublicKey::from_affine(point.to_affine())?, chain_code))
+    }
+}
+
+impl<C> DeriveChild<C> for KeyPair<C>
+where
+    C: CurveArithmetic,
+{
+    fn derive_child<P: ChildKeyPrf<C>>(
+        &self,
+        chain_code: &P::ChainCode,
+        child_number: ChildNumber,
+    ) -> Result<(Self, P::ChainCode)> {
+        self.secret_key()
+            .derive_child::<P>(chain_code, child_number)
+            .map(|(secret_key, chain_code)| (secret_key.into(), chain_code))
+    }
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::{ChildKeyPrf, ChildNumber, DeriveChild, ParentKey};
+    use crate::{
+        dev::{MockCurve, PublicKey, Scalar, SecretKey},
+        Result,
+    };
+    use ff::PrimeField;
+    use hex_literal::hex;
+    use sha2::{Digest, Sha256};
+
+    /// Toy PRF which hashes the chain code and child number.
+    struct TestPrf;
+
+    impl ChildKeyPrf<MockCurve> for TestPrf {
+        type ChainCode = [u8; 32];
+
+        fn child_tweak(
+            chain_code: &[u8; 32],
+            _parent_key: ParentKey<'_, MockCurve>,
+            child_number: ChildNumber,
+        ) -> Result<(Scalar, [u8; 32])> {
+            let tweak = Sha256::new()
+                .chain_update(chain_code)
+                .chain_update(child_number.to_be_bytes())
+                .finalize();
+
+            let tweak = Option::from(Scalar::from_repr(tweak)).ok_or(crate::Error)?;
+            Ok((tweak, Sha256::digest(chain_code).into()))
+        }
+    }
+
+    const EXAMPLE_SCALAR: [u8; 32] =
+        hex!("AABBCCDDEEFF0000000000000000000000000000000000000000000000000001");
+
+    #[test]
+    fn child_number() {
+        let child = ChildNumber::new(7, true).unwrap();
+        assert!(child.is_hardened());
+        assert_eq!(child.index(), 7);
+        assert_eq!(child.to_be_bytes(), [0x80, 0, 0, 7]);
+
+        assert!(!ChildNumber::from(7).is_hardened());
+        assert!(ChildNumber::new(ChildNumber::HARDENED_FLAG, false).is_err());
+    }
+
+    #[test]
+    fn derive_secret_key() {
+        let chain_code = [0x42; 32];
+        let secret_key = SecretKey::from_be_bytes(&EXAMPLE_SCALAR).unwrap();
+
+        for child_number in [ChildNumber(1), ChildNumber::new(1, true).unwrap()] {
+            let (child, child_chain_code) = secret_key
+                .derive_child::<TestPrf>(&chain_code, child_number)
+                .unwrap();
+
+            let (tweak, expected_chain_code) =
+                TestPrf::child_tweak(&chain_code, ParentKey::Secret(&secret_key), child_number)
+                    .unwrap();
+
+            assert_eq!(
+                *child.to_nonzero_scalar().as_ref(),
+                *secret_key.to_nonzero_scalar().as_ref() + tweak
+            );
+            assert_eq!(child_chain_code, expected_chain_code);
+        }
+    }
+
+    #[test]
+    fn hardened_public_key_derivation_fails() {
+        let public_key = SecretKey::from_be_bytes(&EXAMPLE_SCALAR)
+            .unwrap()
+            .public_key();
+
+        let child_number = ChildNumber::new(0, true).unwrap();
+        assert!(
+            <PublicKey as DeriveChild<MockCurve>>::derive_child::<TestPrf>(
+                &public_key,
+                &[0; 32],
+                child_number
+            )
+            .is_err()
+        );
+    }
+}
@@ -0,0 +1,314 @@
+//! Constant-time hexadecimal encoding and decoding.
+//!
+//! These traits are intended for loading and storing keys and scalars as
+//! hex strings, e.g. in configuration files. Encoding and decoding is
+//! performed using the constant-time [`base16ct`] crate, so timing doesn't
+//! depend on the value of the data being processed.
+//!
+//! Fixed-size values must be encoded with exactly twice as many hex digits
+//! as their serialized size: inputs with any other length are rejected
+//! rather than being zero-padded or truncated.
+
+use crate::{Curve, Error, FieldBytes, FieldSize, Result, ScalarPrimitive, SecretKey};
+use generic_array::{typenum::Unsigned, ArrayLength, GenericArray};
+use zeroize::Zeroize;
+
+#[cfg(feature = "alloc")]
+use {
+    alloc::{string::String, vec},
+    zeroize::Zeroizing,
+};
+
+#[cfg(feature = "arithmetic")]
+use crate::{CurveArithmetic, NonZeroScalar};
+
+#[cfg(feature = "sec1")]
+use crate::sec1::ModulusSize;
+
+#[cfg(all(feature = "arithmetic", feature = "sec1"))]
+use crate::{
+    sec1::{EncodedPoint, FromEncodedPoint, ToEncodedPoint},
+    AffinePoint, PublicKey,
+};
+
+/// Decode a value from its hexadecimal encoding in constant time.
+///
+/// Both upper and lower case hex digits are accepted.
+pub trait FromHex: Sized {
+    /// Decode this value from the given hex string.
+    ///
+    /// Returns an error if the input has the wrong length, contains
+    /// characters other than hex digits, or doesn't decode to a valid value.
+    fn from_hex(hex: &str) -> Result<Self>;
+}
+
+/// Encode a value as lower case hexadecimal in constant time.
+pub trait ToHex {
+    /// Get the length of the hex encoding of this value, i.e. the size of
+    /// the buffer required by [`ToHex::encode_hex`].
+    fn hex_len(&self) -> usize;
+
+    /// Encode this value as hex into the provided buffer, returning the
+    /// encoded string.
+    ///
+    /// Returns an error if the buffer is too small.
+    fn encode_hex<'a>(&self, out: &'a mut [u8]) -> Result<&'a str>;
+
+    /// Encode this value as a hex string.
+    ///
+    /// The output is wrapped in [`Zeroizing`] as it may contain secret key
+    /// material.
+    #[cfg(feature = "alloc")]
+    fn to_hex(&self) -> Zeroizing<String> {
+        let mut buf = Zeroizing::new(vec![0u8; self.hex_len()]);
+        let hex = self
+            .encode_hex(&mut buf)
+            .expect("buffer is sized by hex_len");
+        Zeroizing::new(hex.into())
+    }
+}
+
+impl<N> FromHex for GenericArray<u8, N>
+where
+    N: ArrayLength<u8>,
+{
+    fn from_hex(hex: &str) -> Result<Self> {
+        let mut bytes = GenericArray::default();
+        decode_exact(hex, &mut bytes)?;
+        Ok(bytes)
+    }
+}
+
+impl<N> ToHex for GenericArray<u8, N>
+where
+    N: ArrayLength<u8>,
+{
+    fn hex_len(&self) -> usize {
+        self.len() * 2
+    }
+
+    fn encode_hex<'a>(&self, out: &'a mut [u8]) -> Result<&'a str> {
+        encode(self, out)
+    }
+}
+
+impl<C> FromHex for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    /// Decode a big endian scalar, rejecting values which overflow the curve
+    /// order.
+    fn from_hex(hex: &str) -> Result<Self> {
+        decode_secret::<C, _>(hex, |bytes| {
+            Option::from(Self::from_be_bytes(bytes.clone())).ok_or(Error)
+        })
+    }
+}
+
+impl<C> ToHex for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn hex_len(&self) -> usize {
+        FieldSize::<C>::USIZE * 2
+    }
+
+    fn encode_hex<'a>(&self, out: &'a mut [u8]) -> Result<&'a str> {
+        let mut bytes = self.to_be_bytes();
+        let result = encode(&bytes, out);
+        bytes.zeroize();
+        result
+    }
+}
+
+impl<C> FromHex for SecretKey<C>
+where
+    C: Curve,
+{
+    fn from_hex(hex: &str) -> Result<Self> {
+        decode_secret::<C, _>(hex, |bytes| Self::from_be_bytes(bytes))
+    }
+}
+
+impl<C> ToHex for SecretKey<C>
+where
+    C: Curve,
+{
+    fn hex_len(&self) -> usize {
+        self.as_scalar_core().hex_len()
+    }
+
+    fn encode_hex<'a>(&self, out: &'a mut [u8]) -> Result<&'a str> {
+        self.as_scalar_core().encode_hex(out)
+    }
+}
+
+#[cfg(feature = "arithmetic")]
+impl<C> FromHex for NonZeroScalar<C>
+where
+    C: CurveArithmetic,
+{
+    fn from_hex(hex: &str) -> Result<Self> {
+        decode_secret::<C, _>(hex, |bytes| {
+            Option::from(Self::from_repr(bytes.clone())).ok_or(Error)
+        })
+    }
+}
+
+#[cfg(feature = "arithmetic")]
+impl<C> ToHex for NonZeroScalar<C>
+where
+    C: CurveArithmetic,
+{
+    fn hex_len(&self) -> usize {
+        FieldSize::<C>::USIZE * 2
+    }
+
+    fn encode_hex<'a>(&self, out: &'a mut [u8]) -> Result<&'a str> {
+        ScalarPrimitive::from(self).encode_hex(out)
+    }
+}
+
+#[cfg(feature = "sec1")]
+impl<Size> FromHex for sec1::point::EncodedPoint<Size>
+where
+    Size: ModulusSize,
+{
+    /// Decode a SEC1 encoded point, which may be in either compressed or
+    /// uncompressed form.
+    fn from_hex(hex: &str) -> Result<Self> {
+        let mut bytes = GenericArray::<u8, Size::UncompressedPointSize>::default();
+
+        // Encoded points are public, so their length need not be kept secret
+        if hex.len() > bytes.len() * 2 {
+            return Err(Error);
+        }
+
+        Self::from_bytes(base16ct::mixed::decode(hex, &mut bytes)?).map_err(|_| Error)
+    }
+}
+
+#[cfg(feature = "sec1")]
+impl<Size> ToHex for sec1::point::EncodedPoint<Size>
+where
+    Size: ModulusSize,
+{
+    fn hex_len(&self) -> usize {
+        self.len() * 2
+    }
+
+    fn encode_hex<'a>(&self, out: &'a mut [u8]) -> Result<&'a str> {
+        encode(self.as_bytes(), out)
+    }
+}
+
+#[cfg(all(feature = "arithmetic", feature = "sec1"))]
+impl<C> FromHex for PublicKey<C>
+where
+    C: CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldSize<C>: ModulusSize,
+{
+    /// Decode a SEC1 encoded public key, which may be in either compressed or
+    /// uncompressed form.
+    fn from_hex(hex: &str) -> Result<Self> {
+        Option::from(Self::from_encoded_point(&EncodedPoint::<C>::from_hex(hex)?)).ok_or(Error)
+    }
+}
+
+/// Decode hex-encoded secret [`FieldBytes`], passing them to `f` and
+/// zeroizing them afterwards.
+fn decode_secret<C, T>(hex: &str, f: impl FnOnce(&FieldBytes<C>) -> Result<T>) -> Result<T>
+where
+    C: Curve,
+{
+    let mut bytes = FieldBytes::<C>::default();
+    let result = decode_exact(hex, &mut bytes).and_then(|_| f(&bytes));
+    bytes.zeroize();
+    result
+}
+
+/// Decode hex into `out`, requiring the input to fill it exactly.
+fn decode_exact(hex: &str, out: &mut [u8]) -> Result<()> {
+    if hex.len() != out.len() * 2 {
+        return Err(Error);
+    }
+
+    base16ct::mixed::decode(hex, out)?;
+    Ok(())
+}
+
+/// Encode `bytes` as lower case hex into `out`.
+fn encode<'a>(bytes: &[u8], out: &'a mut [u8]) -> Result<&'a str> {
+    Ok(base16ct::lower::encode_str(bytes, out)?)
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::{FromHex, ToHex};
+    use crate::dev::{EncodedPoint, FieldBytes, NonZeroScalar, ScalarPrimitive, SecretKey};
+
+    const EXAMPLE_HEX: &str = "aabbccddeeff0000000000000000000000000000000000000000000000000001";
+
+    /// Curve order of the mock curve.
+    const ORDER_HEX: &str = "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551";
+
+    #[test]
+    fn field_bytes_round_trip() {
+        let bytes = FieldBytes::from_hex(EXAMPLE_HEX).unwrap();
+        assert_eq!(bytes[0], 0xaa);
+
+        let mut buf = [0u8; 64];
+        assert_eq!(bytes.encode_hex(&mut buf).unwrap(), EXAMPLE_HEX);
+        assert!(bytes.encode_hex(&mut [0u8; 63]).is_err());
+    }
+
+    #[test]
+    fn mixed_case() {
+        let bytes = FieldBytes::from_hex(&EXAMPLE_HEX.to_uppercase()).unwrap();
+        assert_eq!(bytes, FieldBytes::from_hex(EXAMPLE_HEX).unwrap());
+    }
+
+    #[test]
+    fn reject_non_canonical_length() {
+        assert!(FieldBytes::from_hex(&EXAMPLE_HEX[2..]).is_err());
+        assert!(FieldBytes::from_hex(&EXAMPLE_HEX[1..]).is_err());
+        assert!(FieldBytes::from_hex(&[EXAMPLE_HEX, "00"].concat()).is_err());
+    }
+
+    #[test]
+    fn reject_invalid_digits() {
+        let hex = EXAMPLE_HEX.replace('f', "g");
+        assert!(FieldBytes::from_hex(&hex).is_err());
+    }
+
+    #[test]
+    fn scalar_range() {
+        assert!(ScalarPrimitive::from_hex(ORDER_HEX).is_err());
+        assert!(NonZeroScalar::from_hex(&"0".repeat(64)).is_err());
+        assert!(SecretKey::from_hex(&"0".repeat(64)).is_err());
+    }
+
+    #[test]
+    fn encoded_point_round_trip() {
+        let hex = "021111111111111111111111111111111111111111111111111111111111111111";
+        let point = EncodedPoint::from_hex(hex).unwrap();
+        assert!(point.is_compressed());
+        assert_eq!(point.hex_len(), hex.len());
+        assert_eq!(point.to_hex().as_str(), hex);
+
+        assert!(EncodedPoint::from_hex(&hex[..64]).is_err());
+        assert!(EncodedPoint::from_hex(&[hex, hex].concat()).is_err());
+    }
+
+    #[test]
+    fn secret_key_round_trip() {
+        let secret_key = SecretKey::from_hex(EXAMPLE_HEX).unwrap();
+        assert_eq!(secret_key.hex_len(), EXAMPLE_HEX.len());
+        assert_eq!(secret_key.to_hex().as_str(), EXAMPLE_HEX);
+        assert_eq!(
+            NonZeroScalar::from_hex(EXAMPLE_HEX).unwrap().to_hex(),
+            secret_key.to_hex()
+        );
+    }
+}
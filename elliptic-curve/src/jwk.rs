@@ -106,6 +106,64 @@ impl JwkEcKey {
         self.d.is_none()
     }
 
+    /// Get the JWK with only its public key components, i.e. with the `d`
+    /// parameter removed.
+    pub fn to_public_jwk(&self) -> JwkEcKey {
+        JwkEcKey {
+            crv: self.crv.clone(),
+            x: self.x.clone(),
+            y: self.y.clone(),
+            d: None,
+        }
+    }
+
+    /// Get the canonical JSON serialization of the required public members
+    /// of this JWK, which is the hash input for computing its thumbprint as
+    /// described in RFC 7638 Section 3:
+    /// <https://www.rfc-editor.org/rfc/rfc7638#section-3>
+    ///
+    /// Returns an error if the `crv` parameter doesn't match `C`, or if the
+    /// `x` or `y` coordinates aren't valid Base64url-encoded field elements,
+    /// as these members are copied verbatim into the output.
+    pub fn thumbprint_input<C>(&self) -> Result<String>
+    where
+        C: Curve + JwkParameters,
+        FieldSize<C>: ModulusSize,
+    {
+        self.to_encoded_point::<C>()?;
+
+        // Members are ordered lexicographically, with no whitespace
+        Ok(format!(
+            r#"{{"crv":"{}","kty":"{}","x":"{}","y":"{}"}}"#,
+            self.crv, EC_KTY, self.x, self.y
+        ))
+    }
+
+    /// Compute the RFC 7638 thumbprint of this JWK using the given digest
+    /// algorithm (RFC 7638 uses SHA-256 in its examples).
+    #[cfg(feature = "digest")]
+    pub fn thumbprint<C, D>(&self) -> Result<digest::Output<D>>
+    where
+        C: Curve + JwkParameters,
+        D: digest::Digest,
+        FieldSize<C>: ModulusSize,
+    {
+        Ok(D::digest(self.thumbprint_input::<C>()?.as_bytes()))
+    }
+
+    /// Compute the RFC 7638 thumbprint of this JWK using the given digest
+    /// algorithm, encoded as unpadded Base64url as is customary when using it
+    /// as a key ID (`kid`).
+    #[cfg(feature = "digest")]
+    pub fn thumbprint_base64url<C, D>(&self) -> Result<String>
+    where
+        C: Curve + JwkParameters,
+        D: digest::Digest,
+        FieldSize<C>: ModulusSize,
+    {
+        Ok(Base64Url::encode_string(&self.thumbprint::<C, D>()?))
+    }
+
     /// Decode a JWK into a [`PublicKey`].
     #[cfg(feature = "arithmetic")]
     pub fn to_public_key<C>(&self) -> Result<PublicKey<C>>
@@ -649,6 +707,53 @@ mod tests {
         assert_eq!(actual, expected);
     }
 
+    #[test]
+    fn to_public_jwk() {
+        let private_key = JwkEcKey::from_str(JWK_PRIVATE_KEY).unwrap();
+        let public_key = JwkEcKey::from_str(JWK_PUBLIC_KEY).unwrap();
+        assert_eq!(private_key.to_public_jwk(), public_key);
+    }
+
+    #[cfg(feature = "dev")]
+    #[test]
+    fn thumbprint_input() {
+        let jwk = JwkEcKey::from_str(JWK_PRIVATE_KEY).unwrap();
+        assert_eq!(
+            jwk.thumbprint_input::<MockCurve>().unwrap(),
+            r#"{"crv":"P-256","kty":"EC","x":"gI0GAILBdu7T53akrFmMyGcsF3n5dO7MmwNBHKW5SV0","y":"SLW_xSffzlPWrHEVI30DHM_4egVwt3NQqeUD7nMFpps"}"#
+        );
+    }
+
+    #[cfg(feature = "dev")]
+    #[test]
+    fn thumbprint_input_rejects_invalid_members() {
+        let jwk = JwkEcKey::from_str(JWK_PUBLIC_KEY).unwrap();
+
+        let mut wrong_curve = jwk.clone();
+        wrong_curve.crv = "P-384".to_owned();
+        assert!(wrong_curve.thumbprint_input::<MockCurve>().is_err());
+
+        // Members must not be able to inject JSON into the hash input
+        let mut injected = jwk.clone();
+        injected.x = format!(r#"{}","kid":""#, jwk.x);
+        assert!(injected.thumbprint_input::<MockCurve>().is_err());
+
+        let mut truncated = jwk.clone();
+        truncated.y.pop();
+        assert!(truncated.thumbprint_input::<MockCurve>().is_err());
+    }
+
+    #[cfg(all(feature = "dev", feature = "digest"))]
+    #[test]
+    fn thumbprint() {
+        let jwk = JwkEcKey::from_str(JWK_PUBLIC_KEY).unwrap();
+        assert_eq!(
+            jwk.thumbprint_base64url::<MockCurve, sha2::Sha256>()
+                .unwrap(),
+            "_GK0r6GCoJt9zcssg9lay4obIxgCq05ntiRymRHADSU"
+        );
+    }
+
     #[cfg(feature = "dev")]
     #[test]
     fn jwk_into_encoded_point() {
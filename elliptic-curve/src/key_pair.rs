@@ -0,0 +1,237 @@
+//! Key pairs bundling a secret key with its public key.
+
+use crate::{
+    rand_core::CryptoRngCore, CurveArithmetic, NonZeroScalar, PublicKey, Result, SecretKey,
+};
+use core::fmt::{self, Debug};
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::ZeroizeOnDrop;
+
+#[cfg(feature = "pkcs8")]
+use crate::{
+    pkcs8::{self, AssociatedOid, DecodePrivateKey},
+    sec1::{ModulusSize, ValidatePublicKey},
+    FieldSize,
+};
+
+#[cfg(all(feature = "alloc", feature = "pkcs8"))]
+use crate::{
+    pkcs8::{der, EncodePrivateKey},
+    sec1::{FromEncodedPoint, ToEncodedPoint},
+    AffinePoint,
+};
+
+/// Elliptic curve key pair: a [`SecretKey`] along with its corresponding
+/// [`PublicKey`].
+///
+/// The public key is computed once when the key pair is constructed and
+/// cached, and is always guaranteed to correspond to the secret key.
+///
+/// The secret key is zeroized on drop.
+#[derive(Clone)]
+pub struct KeyPair<C>
+where
+    C: CurveArithmetic,
+{
+    /// Secret key.
+    secret_key: SecretKey<C>,
+
+    /// Public key corresponding to `secret_key`.
+    public_key: PublicKey<C>,
+}
+
+impl<C> KeyPair<C>
+where
+    C: CurveArithmetic,
+{
+    /// Generate a random [`KeyPair`].
+    pub fn random(rng: &mut impl CryptoRngCore) -> Self {
+        Self::from_secret_key(SecretKey::random(rng))
+    }
+
+    /// Create a [`KeyPair`] from the given [`SecretKey`], computing its
+    /// [`PublicKey`].
+    pub fn from_secret_key(secret_key: SecretKey<C>) -> Self {
+        let public_key = secret_key.public_key();
+
+        Self {
+            secret_key,
+            public_key,
+        }
+    }
+
+    /// Deserialize a [`KeyPair`] from a raw secret scalar encoded as a big
+    /// endian integer.
+    pub fn from_be_bytes(bytes: &[u8]) -> Result<Self> {
+        SecretKey::from_be_bytes(bytes).map(Self::from_secret_key)
+    }
+
+    /// Borrow the [`SecretKey`].
+    pub fn secret_key(&self) -> &SecretKey<C> {
+        &self.secret_key
+    }
+
+    /// Borrow the [`PublicKey`].
+    pub fn public_key(&self) -> &PublicKey<C> {
+        &self.public_key
+    }
+
+    /// Get the secret [`NonZeroScalar`] value for this key pair.
+    ///
+    /// # ⚠️ Warning
+    ///
+    /// This value is key material.
+    ///
+    /// Please treat it with the care it deserves!
+    pub fn to_nonzero_scalar(&self) -> NonZeroScalar<C> {
+        self.secret_key.to_nonzero_scalar()
+    }
+}
+
+impl<C> AsRef<PublicKey<C>> for KeyPair<C>
+where
+    C: CurveArithmetic,
+{
+    fn as_ref(&self) -> &PublicKey<C> {
+        &self.public_key
+    }
+}
+
+impl<C> ConstantTimeEq for KeyPair<C>
+where
+    C: CurveArithmetic,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        // The public key is determined by the secret key
+        self.secret_key.ct_eq(&other.secret_key)
+    }
+}
+
+impl<C> Debug for KeyPair<C>
+where
+    C: CurveArithmetic,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(core::any::type_name::<Self>())
+            .field("public_key", &self.public_key)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<C: CurveArithmetic> Eq for KeyPair<C> {}
+
+impl<C> PartialEq for KeyPair<C>
+where
+    C: CurveArithmetic,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl<C> From<SecretKey<C>> for KeyPair<C>
+where
+    C: CurveArithmetic,
+{
+    fn from(secret_key: SecretKey<C>) -> KeyPair<C> {
+        KeyPair::from_secret_key(secret_key)
+    }
+}
+
+impl<C> From<&SecretKey<C>> for KeyPair<C>
+where
+    C: CurveArithmetic,
+{
+    fn from(secret_key: &SecretKey<C>) -> KeyPair<C> {
+        KeyPair::from_secret_key(secret_key.clone())
+    }
+}
+
+impl<C> From<KeyPair<C>> for SecretKey<C>
+where
+    C: CurveArithmetic,
+{
+    fn from(key_pair: KeyPair<C>) -> SecretKey<C> {
+        key_pair.secret_key
+    }
+}
+
+impl<C> From<KeyPair<C>> for PublicKey<C>
+where
+    C: CurveArithmetic,
+{
+    fn from(key_pair: KeyPair<C>) -> PublicKey<C> {
+        key_pair.public_key
+    }
+}
+
+impl<C> From<&KeyPair<C>> for PublicKey<C>
+where
+    C: CurveArithmetic,
+{
+    fn from(key_pair: &KeyPair<C>) -> PublicKey<C> {
+        key_pair.public_key
+    }
+}
+
+impl<C> ZeroizeOnDrop for KeyPair<C> where C: CurveArithmetic {}
+
+#[cfg(feature = "pkcs8")]
+impl<C> TryFrom<pkcs8::PrivateKeyInfo<'_>> for KeyPair<C>
+where
+    C: AssociatedOid + CurveArithmetic + ValidatePublicKey,
+    FieldSize<C>: ModulusSize,
+{
+    type Error = pkcs8::Error;
+
+    fn try_from(private_key_info: pkcs8::PrivateKeyInfo<'_>) -> pkcs8::Result<Self> {
+        SecretKey::try_from(private_key_info).map(Self::from_secret_key)
+    }
+}
+
+#[cfg(feature = "pkcs8")]
+impl<C> DecodePrivateKey for KeyPair<C>
+where
+    C: AssociatedOid + CurveArithmetic + ValidatePublicKey,
+    FieldSize<C>: ModulusSize,
+{
+}
+
+#[cfg(all(feature = "alloc", feature = "pkcs8"))]
+impl<C> EncodePrivateKey for KeyPair<C>
+where
+    C: AssociatedOid + CurveArithmetic,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldSize<C>: ModulusSize,
+{
+    fn to_pkcs8_der(&self) -> pkcs8::Result<der::SecretDocument> {
+        self.secret_key.to_pkcs8_der()
+    }
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use crate::dev::{KeyPair, SecretKey};
+    use hex_literal::hex;
+
+    const EXAMPLE_SCALAR: [u8; 32] =
+        hex!("AABBCCDDEEFF0000000000000000000000000000000000000000000000000001");
+
+    #[test]
+    fn from_be_bytes() {
+        let key_pair = KeyPair::from_be_bytes(&EXAMPLE_SCALAR).unwrap();
+        let secret_key = SecretKey::from_be_bytes(&EXAMPLE_SCALAR).unwrap();
+        assert_eq!(key_pair.secret_key(), &secret_key);
+        assert_eq!(key_pair.public_key(), &secret_key.public_key());
+    }
+
+    #[test]
+    #[cfg(feature = "pkcs8")]
+    fn pkcs8_round_trip() {
+        use crate::pkcs8::{DecodePrivateKey, EncodePrivateKey};
+
+        let key_pair = KeyPair::from_be_bytes(&EXAMPLE_SCALAR).unwrap();
+        let der = key_pair.to_pkcs8_der().unwrap();
+        assert_eq!(KeyPair::from_pkcs8_der(der.as_bytes()).unwrap(), key_pair);
+    }
+}
@@ -68,39 +68,72 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+pub mod edwards;
+pub mod montgomery;
 pub mod ops;
+pub mod point;
 pub mod scalar;
 
 #[cfg(feature = "dev")]
 pub mod dev;
+#[cfg(feature = "hash2curve")]
+pub mod dleq;
 #[cfg(feature = "ecdh")]
 pub mod ecdh;
+#[cfg(feature = "ecies")]
+pub mod ecies;
 #[cfg(feature = "hash2curve")]
 pub mod hash2curve;
+#[cfg(feature = "arithmetic")]
+pub mod hd;
+#[cfg(feature = "arithmetic")]
+pub mod pairing;
+#[cfg(feature = "arithmetic")]
+pub mod pedersen;
+#[cfg(feature = "rfc6979")]
+pub mod rfc6979;
+#[cfg(feature = "hash2curve")]
+pub mod schnorr;
 #[cfg(feature = "sec1")]
 pub mod sec1;
+#[cfg(all(feature = "alloc", feature = "arithmetic"))]
+pub mod shamir;
+#[cfg(all(feature = "arithmetic", feature = "digest"))]
+pub mod vrf;
 #[cfg(feature = "arithmetic")]
 pub mod weierstrass;
 
 mod error;
-mod point;
+mod hex;
+mod params;
+mod recovery;
 mod secret_key;
 
 #[cfg(feature = "arithmetic")]
 mod arithmetic;
 #[cfg(feature = "arithmetic")]
+mod key_pair;
+#[cfg(feature = "arithmetic")]
 mod public_key;
+#[cfg(feature = "alloc")]
+mod secret_box;
 
 #[cfg(feature = "jwk")]
 mod jwk;
+#[cfg(feature = "openssh")]
+mod openssh;
 
 pub use crate::{
     error::{Error, Result},
+    hex::{FromHex, ToHex},
+    params::CurveParams,
     point::{
-        AffineXCoordinate, AffineYIsOdd, DecompactPoint, DecompressPoint, PointCompaction,
-        PointCompression,
+        AffineCoordinates, AffineXCoordinate, AffineYCoordinate, AffineYIsOdd, DecompactPoint,
+        DecompressPoint, DecompressPointSign, PointCompaction, PointCompression, PointEncoding,
+        RecoverPoint, SignConvention,
     },
-    scalar::{IsHigh, ScalarPrimitive},
+    recovery::{RecoverVerifyingKey, RecoveryId},
+    scalar::{CtOrd, FromCanonical, IsHigh, ScalarPrimitive, ToCanonical},
     secret_key::SecretKey,
 };
 pub use crypto_bigint as bigint;
@@ -113,19 +146,41 @@ pub use zeroize;
 pub use {
     crate::{
         arithmetic::{CurveArithmetic, PrimeCurveArithmetic},
-        public_key::PublicKey,
+        key_pair::KeyPair,
+        public_key::{PublicKey, XOnlyPublicKey},
         scalar::{NonZeroScalar, Scalar},
     },
     ff::{self, Field, PrimeField},
     group::{self, Group},
 };
 
+#[cfg(feature = "arithmetic")]
+pub use crate::point::{
+    BatchNormalize, BlindedPoint, ClearCofactor, IsTorsionFree, NonIdentity, ValidatePoint,
+    ValidationPolicy,
+};
+
+#[cfg(all(feature = "alloc", feature = "arithmetic"))]
+pub use crate::point::FixedBaseTable;
+
+#[cfg(feature = "alloc")]
+pub use crate::secret_box::SecretBox;
+
 #[cfg(feature = "bits")]
 pub use crate::scalar::ScalarBits;
 
 #[cfg(feature = "jwk")]
 pub use crate::jwk::{JwkEcKey, JwkParameters};
 
+#[cfg(feature = "openssh")]
+pub use crate::openssh::OpenSshParameters;
+
+#[cfg(feature = "sec1")]
+pub use crate::params::{FromExplicitParameters, SpecifiedCurve};
+
+#[cfg(all(feature = "alloc", feature = "sec1"))]
+pub use crate::params::ToExplicitParameters;
+
 #[cfg(feature = "pkcs8")]
 pub use pkcs8;
 
@@ -190,17 +245,21 @@ pub type AffinePoint<C> = <C as CurveArithmetic>::AffinePoint;
 pub type ProjectivePoint<C> = <C as CurveArithmetic>::ProjectivePoint;
 
 /// Elliptic curve parameters used by VOPRF.
+///
+/// Curves implementing this trait will typically also implement
+/// `hash2curve::GroupDigest`, which provides the `HashToGroup` and
+/// `HashToScalar` functions for the ciphersuite.
 #[cfg(feature = "voprf")]
 pub trait VoprfParameters: Curve {
-    /// The `ID` parameter which identifies a particular elliptic curve
-    /// as defined in [section 4 of `draft-irtf-cfrg-voprf-08`][voprf].
+    /// The `contextString` identifier of the ciphersuite for this elliptic
+    /// curve, e.g. `"P256-SHA256"`, as defined in [section 4 of RFC 9497][voprf].
     ///
-    /// [voprf]: https://www.ietf.org/archive/id/draft-irtf-cfrg-voprf-08.html#section-4
-    const ID: u16;
+    /// [voprf]: https://www.rfc-editor.org/rfc/rfc9497.html#section-4
+    const ID: &'static str;
 
     /// The `Hash` parameter which assigns a particular hash function to this
-    /// ciphersuite as defined in [section 4 of `draft-irtf-cfrg-voprf-08`][voprf].
+    /// ciphersuite as defined in [section 4 of RFC 9497][voprf].
     ///
-    /// [voprf]: https://www.ietf.org/archive/id/draft-irtf-cfrg-voprf-08.html#section-4
+    /// [voprf]: https://www.rfc-editor.org/rfc/rfc9497.html#section-4
     type Hash: digest::Digest;
 }
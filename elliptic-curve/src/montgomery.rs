@@ -0,0 +1,309 @@
+//! Montgomery curves and x-only Diffie-Hellman.
+//!
+//! Diffie-Hellman on Montgomery curves, as specified for X25519 and X448 in
+//! [RFC7748], operates solely on the u-coordinate of points using the
+//! Montgomery ladder. Scalars are arbitrary byte strings which are "clamped"
+//! before use, rather than integers reduced modulo the curve order.
+//!
+//! This module provides the [`MontgomeryCurve`] trait which describes such
+//! curves, along with [`MontgomerySecretKey`] and [`MontgomeryPublicKey`]
+//! types which are generic over it. When the `ecdh` feature is enabled,
+//! key exchanges produce an [`ecdh::SharedSecret`][`crate::ecdh::SharedSecret`]
+//! so the same key derivation APIs can be used as with other curves.
+//!
+//! All byte strings are little endian, as is conventional for these curves.
+//!
+//! [RFC7748]: https://www.rfc-editor.org/rfc/rfc7748
+
+use crate::{Curve, FieldBytes, FieldSize};
+use core::fmt::{self, Debug};
+use generic_array::typenum::Unsigned;
+use rand_core::CryptoRngCore;
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+#[cfg(feature = "ecdh")]
+use crate::{ecdh::SharedSecret, Error, Result};
+
+/// Elliptic curve in Montgomery form supporting x-only scalar multiplication,
+/// e.g. Curve25519 or Curve448.
+pub trait MontgomeryCurve: Curve {
+    /// Number of low-order bits cleared when clamping a scalar, i.e. the base 2
+    /// logarithm of the cofactor: `3` for X25519 and `2` for X448.
+    const COFACTOR_BITS: u32;
+
+    /// Bit length of clamped scalars: when clamping, the bit at index
+    /// `SCALAR_BITS - 1` is set and all bits above it are cleared.
+    ///
+    /// This is `255` for X25519 and `448` for X448.
+    const SCALAR_BITS: u32;
+
+    /// Get the little endian u-coordinate of the base point.
+    fn base_point() -> FieldBytes<Self>;
+
+    /// Compute the u-coordinate of the product of the point with the given
+    /// u-coordinate and a scalar using the Montgomery ladder.
+    ///
+    /// The scalar has already been clamped with
+    /// [`MontgomeryCurve::clamp_scalar`], and implementations should not
+    /// clamp it again.
+    ///
+    /// This must be implemented in constant time.
+    fn ladder(scalar: &FieldBytes<Self>, u: &FieldBytes<Self>) -> FieldBytes<Self>;
+
+    /// Clamp the given little endian scalar in accordance with
+    /// [`MontgomeryCurve::COFACTOR_BITS`] and [`MontgomeryCurve::SCALAR_BITS`].
+    fn clamp_scalar(scalar: &FieldBytes<Self>) -> FieldBytes<Self> {
+        debug_assert!(Self::COFACTOR_BITS < 8);
+        debug_assert!(Self::SCALAR_BITS as usize <= FieldSize::<Self>::USIZE * 8);
+
+        let mut k = scalar.clone();
+        k[0] &= u8::MAX << Self::COFACTOR_BITS;
+
+        let top_bit = (Self::SCALAR_BITS - 1) as usize;
+        k[top_bit / 8] &= u8::MAX >> (7 - top_bit % 8);
+        k[top_bit / 8] |= 1 << (top_bit % 8);
+
+        for byte in &mut k[(top_bit / 8 + 1)..] {
+            *byte = 0;
+        }
+
+        k
+    }
+
+    /// Clamp the given scalar and multiply the point with the given
+    /// u-coordinate by it, i.e. the `X25519`/`X448` functions from RFC7748.
+    fn x_mul(scalar: &FieldBytes<Self>, u: &FieldBytes<Self>) -> FieldBytes<Self> {
+        let mut k = Self::clamp_scalar(scalar);
+        let result = Self::ladder(&k, u);
+        k.zeroize();
+        result
+    }
+}
+
+/// Public key for x-only Diffie-Hellman: the little endian u-coordinate of a
+/// point on a [`MontgomeryCurve`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MontgomeryPublicKey<C: MontgomeryCurve> {
+    u: FieldBytes<C>,
+}
+
+impl<C> MontgomeryPublicKey<C>
+where
+    C: MontgomeryCurve,
+{
+    /// Create a public key from the little endian u-coordinate of a point.
+    pub fn from_bytes(u: FieldBytes<C>) -> Self {
+        Self { u }
+    }
+
+    /// Borrow the little endian u-coordinate of this public key.
+    pub fn as_bytes(&self) -> &FieldBytes<C> {
+        &self.u
+    }
+
+    /// Serialize this public key as its little endian u-coordinate.
+    pub fn to_bytes(&self) -> FieldBytes<C> {
+        self.u.clone()
+    }
+}
+
+impl<C> AsRef<[u8]> for MontgomeryPublicKey<C>
+where
+    C: MontgomeryCurve,
+{
+    fn as_ref(&self) -> &[u8] {
+        &self.u
+    }
+}
+
+impl<C> From<FieldBytes<C>> for MontgomeryPublicKey<C>
+where
+    C: MontgomeryCurve,
+{
+    fn from(u: FieldBytes<C>) -> Self {
+        Self::from_bytes(u)
+    }
+}
+
+impl<C> From<&MontgomerySecretKey<C>> for MontgomeryPublicKey<C>
+where
+    C: MontgomeryCurve,
+{
+    fn from(secret_key: &MontgomerySecretKey<C>) -> Self {
+        secret_key.public_key()
+    }
+}
+
+/// Secret key for x-only Diffie-Hellman on a [`MontgomeryCurve`].
+///
+/// The scalar is clamped when the key is constructed, and is zeroized on drop.
+#[derive(Clone)]
+pub struct MontgomerySecretKey<C: MontgomeryCurve> {
+    scalar: FieldBytes<C>,
+}
+
+impl<C> MontgomerySecretKey<C>
+where
+    C: MontgomeryCurve,
+{
+    /// Generate a random [`MontgomerySecretKey`].
+    pub fn random(rng: &mut impl CryptoRngCore) -> Self {
+        let mut bytes = FieldBytes::<C>::default();
+        rng.fill_bytes(&mut bytes);
+        let secret_key = Self::from_bytes(&bytes);
+        bytes.zeroize();
+        secret_key
+    }
+
+    /// Create a secret key from a little endian scalar, clamping it.
+    pub fn from_bytes(bytes: &FieldBytes<C>) -> Self {
+        Self {
+            scalar: C::clamp_scalar(bytes),
+        }
+    }
+
+    /// Serialize the clamped scalar of this secret key as little endian bytes.
+    ///
+    /// # ⚠️ Warning
+    ///
+    /// This value is key material.
+    ///
+    /// Please treat it with the care it deserves!
+    pub fn to_bytes(&self) -> FieldBytes<C> {
+        self.scalar.clone()
+    }
+
+    /// Get the [`MontgomeryPublicKey`] which corresponds to this secret key.
+    pub fn public_key(&self) -> MontgomeryPublicKey<C> {
+        MontgomeryPublicKey::from_bytes(C::ladder(&self.scalar, &C::base_point()))
+    }
+
+    /// Compute a Diffie-Hellman shared secret with the given public key.
+    ///
+    /// Returns an error if the result is all zeroes, which happens when the
+    /// public key is a point of small order, as recommended by RFC7748 for
+    /// protocols which require contributory behavior.
+    #[cfg(feature = "ecdh")]
+    pub fn diffie_hellman(&self, public_key: &MontgomeryPublicKey<C>) -> Result<SharedSecret<C>> {
+        let shared_secret = C::ladder(&self.scalar, public_key.as_bytes());
+
+        if bool::from(shared_secret.ct_eq(&FieldBytes::<C>::default())) {
+            return Err(Error);
+        }
+
+        Ok(shared_secret.into())
+    }
+}
+
+impl<C> ConstantTimeEq for MontgomerySecretKey<C>
+where
+    C: MontgomeryCurve,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.scalar.ct_eq(&other.scalar)
+    }
+}
+
+impl<C> Debug for MontgomerySecretKey<C>
+where
+    C: MontgomeryCurve,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(core::any::type_name::<Self>())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<C: MontgomeryCurve> Eq for MontgomerySecretKey<C> {}
+
+impl<C> PartialEq for MontgomerySecretKey<C>
+where
+    C: MontgomeryCurve,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl<C> Zeroize for MontgomerySecretKey<C>
+where
+    C: MontgomeryCurve,
+{
+    fn zeroize(&mut self) {
+        self.scalar.zeroize();
+    }
+}
+
+impl<C> Drop for MontgomerySecretKey<C>
+where
+    C: MontgomeryCurve,
+{
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<C> ZeroizeOnDrop for MontgomerySecretKey<C> where C: MontgomeryCurve {}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::MontgomeryCurve;
+    use crate::dev::{FieldBytes, MockCurve};
+    use hex_literal::hex;
+
+    /// Toy "ladder" which XORs the scalar with the u-coordinate, which is
+    /// sufficient to check that Diffie-Hellman is commutative.
+    impl MontgomeryCurve for MockCurve {
+        const COFACTOR_BITS: u32 = 3;
+        const SCALAR_BITS: u32 = 255;
+
+        fn base_point() -> FieldBytes {
+            let mut u = FieldBytes::default();
+            u[0] = 9;
+            u
+        }
+
+        fn ladder(scalar: &FieldBytes, u: &FieldBytes) -> FieldBytes {
+            scalar.iter().zip(u.iter()).map(|(k, u)| k ^ u).collect()
+        }
+    }
+
+    #[test]
+    fn clamp_scalar() {
+        let scalar = FieldBytes::from([0xff; 32]);
+        assert_eq!(
+            MockCurve::clamp_scalar(&scalar),
+            FieldBytes::from(hex!(
+                "f8ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff7f"
+            ))
+        );
+
+        let scalar = FieldBytes::default();
+        assert_eq!(
+            MockCurve::clamp_scalar(&scalar),
+            FieldBytes::from(hex!(
+                "0000000000000000000000000000000000000000000000000000000000000040"
+            ))
+        );
+    }
+
+    #[cfg(feature = "ecdh")]
+    #[test]
+    fn diffie_hellman() {
+        use super::MontgomerySecretKey;
+
+        let alice = MontgomerySecretKey::<MockCurve>::from_bytes(&FieldBytes::from([0x11; 32]));
+        let bob = MontgomerySecretKey::<MockCurve>::from_bytes(&FieldBytes::from([0x22; 32]));
+
+        let alice_shared = alice.diffie_hellman(&bob.public_key()).unwrap();
+        let bob_shared = bob.diffie_hellman(&alice.public_key()).unwrap();
+        assert_eq!(
+            alice_shared.raw_secret_bytes(),
+            bob_shared.raw_secret_bytes()
+        );
+
+        let low_order = alice.to_bytes().into();
+        assert!(alice.diffie_hellman(&low_order).is_err());
+    }
+}
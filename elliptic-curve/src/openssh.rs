@@ -0,0 +1,557 @@
+//! OpenSSH key format support.
+//!
+//! Public keys are encoded as `ecdsa-sha2-[identifier]` keys as described in
+//! [RFC 5656 Section 3.1], and private keys use the `openssh-key-v1` format
+//! described in [PROTOCOL.key].
+//!
+//! [RFC 5656 Section 3.1]: https://www.rfc-editor.org/rfc/rfc5656#section-3.1
+//! [PROTOCOL.key]: https://cvsweb.openbsd.org/src/usr.bin/ssh/PROTOCOL.key?annotate=HEAD
+
+use crate::{
+    sec1::{EncodedPoint, FromEncodedPoint, ModulusSize, ToEncodedPoint},
+    AffinePoint, Curve, CurveArithmetic, Error, FieldBytes, FieldSize, PublicKey, Result,
+    SecretKey,
+};
+use alloc::{format, string::String, vec::Vec};
+use base64ct::{Base64, Encoding};
+use generic_array::typenum::Unsigned;
+use pem_rfc7468 as pem;
+use zeroize::{Zeroize, Zeroizing};
+
+#[cfg(feature = "openssh-encryption")]
+use {
+    aes::Aes256,
+    ctr::cipher::{KeyIvInit, StreamCipher},
+    generic_array::GenericArray,
+    rand_core::CryptoRngCore,
+};
+
+/// Elliptic curve parameters used by OpenSSH.
+pub trait OpenSshParameters: Curve {
+    /// The curve identifier used in the `ecdsa-sha2-[identifier]` key type
+    /// name as defined in RFC 5656 Section 6.1, e.g. `nistp256`:
+    /// <https://www.rfc-editor.org/rfc/rfc5656#section-6.1>
+    const SSH_IDENTIFIER: &'static str;
+}
+
+/// Magic string at the beginning of `openssh-key-v1` private keys.
+const AUTH_MAGIC: &[u8] = b"openssh-key-v1\0";
+
+/// Type label for PEM-encoded OpenSSH private keys.
+const PEM_TYPE_LABEL: &str = "OPENSSH PRIVATE KEY";
+
+/// Line width used by OpenSSH when wrapping Base64 in private keys.
+const PEM_LINE_WIDTH: usize = 70;
+
+/// Cipher and KDF name used for unencrypted private keys.
+const NONE: &[u8] = b"none";
+
+/// Block size used to pad the private section of unencrypted keys.
+const UNENCRYPTED_BLOCK_SIZE: usize = 8;
+
+/// Cipher used for passphrase-protected private keys.
+#[cfg(feature = "openssh-encryption")]
+const AES256_CTR: &[u8] = b"aes256-ctr";
+
+/// Block size used to pad the private section of AES-encrypted keys.
+#[cfg(feature = "openssh-encryption")]
+const AES_BLOCK_SIZE: usize = 16;
+
+/// KDF used to derive the cipher key and IV from a passphrase.
+#[cfg(feature = "openssh-encryption")]
+const BCRYPT: &[u8] = b"bcrypt";
+
+/// Number of bcrypt-pbkdf rounds used when encrypting, matching `ssh-keygen`.
+#[cfg(feature = "openssh-encryption")]
+const BCRYPT_ROUNDS: u32 = 16;
+
+/// Maximum number of bcrypt-pbkdf rounds accepted when decrypting, which
+/// bounds the work an untrusted key can cause (`ssh-keygen -a` defaults to 16).
+#[cfg(feature = "openssh-encryption")]
+const BCRYPT_MAX_ROUNDS: u32 = 1024;
+
+/// Size of the bcrypt-pbkdf salt used when encrypting, matching `ssh-keygen`.
+#[cfg(feature = "openssh-encryption")]
+const BCRYPT_SALT_SIZE: usize = 16;
+
+/// Encode a [`PublicKey`] as a line in the `authorized_keys` format (without
+/// a comment), e.g. `ecdsa-sha2-nistp256 AAAA...`.
+pub(crate) fn encode_public_key<C>(public_key: &PublicKey<C>) -> Result<String>
+where
+    C: CurveArithmetic + OpenSshParameters,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldSize<C>: ModulusSize,
+{
+    let blob = encode_public_key_blob::<C>(&public_key.to_encoded_point(false))?;
+    Ok(format!(
+        "{} {}",
+        key_type::<C>(),
+        Base64::encode_string(&blob)
+    ))
+}
+
+/// Decode a [`PublicKey`] from a line in the `authorized_keys` format,
+/// ignoring the comment (if any).
+pub(crate) fn decode_public_key<C>(line: &str) -> Result<PublicKey<C>>
+where
+    C: CurveArithmetic + OpenSshParameters,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldSize<C>: ModulusSize,
+{
+    let mut fields = line.split_whitespace();
+
+    if fields.next() != Some(key_type::<C>().as_str()) {
+        return Err(Error);
+    }
+
+    let blob = Base64::decode_vec(fields.next().ok_or(Error)?).map_err(|_| Error)?;
+    let point = decode_public_key_blob::<C>(&blob)?;
+    PublicKey::from_sec1_bytes(point.as_bytes())
+}
+
+/// Encode a [`SecretKey`] as an unencrypted `openssh-key-v1` private key.
+pub(crate) fn encode_private_key<C>(secret_key: &SecretKey<C>) -> Result<Zeroizing<String>>
+where
+    C: CurveArithmetic + OpenSshParameters,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldSize<C>: ModulusSize,
+{
+    let public_key = secret_key.public_key().to_encoded_point(false);
+
+    // The check integers only serve to detect decryption failures, so for
+    // unencrypted keys they're deterministically derived from the public key
+    let mut checkint = [0u8; 4];
+    checkint.copy_from_slice(&public_key.as_bytes()[1..5]);
+
+    let private_section = encode_private_section(
+        secret_key,
+        &public_key,
+        u32::from_be_bytes(checkint),
+        UNENCRYPTED_BLOCK_SIZE,
+    )?;
+
+    encode_private_key_container::<C>(NONE, NONE, &[], &public_key, &private_section)
+}
+
+/// Encode a [`SecretKey`] as an `openssh-key-v1` private key encrypted under
+/// the given passphrase using `aes256-ctr` with a key derived via `bcrypt`.
+#[cfg(feature = "openssh-encryption")]
+pub(crate) fn encode_encrypted_private_key<C>(
+    secret_key: &SecretKey<C>,
+    rng: &mut impl CryptoRngCore,
+    passphrase: &[u8],
+) -> Result<Zeroizing<String>>
+where
+    C: CurveArithmetic + OpenSshParameters,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldSize<C>: ModulusSize,
+{
+    let public_key = secret_key.public_key().to_encoded_point(false);
+
+    let mut salt = [0u8; BCRYPT_SALT_SIZE];
+    rng.fill_bytes(&mut salt);
+
+    let mut kdf_options = Writer::default();
+    kdf_options.string(&salt)?;
+    kdf_options.u32(BCRYPT_ROUNDS);
+
+    let mut private_section =
+        encode_private_section(secret_key, &public_key, rng.next_u32(), AES_BLOCK_SIZE)?;
+    apply_cipher(passphrase, &salt, BCRYPT_ROUNDS, &mut private_section)?;
+
+    encode_private_key_container::<C>(
+        AES256_CTR,
+        BCRYPT,
+        &kdf_options.0,
+        &public_key,
+        &private_section,
+    )
+}
+
+/// Decode a [`SecretKey`] from an `openssh-key-v1` private key, decrypting it
+/// with the given passphrase if one is provided.
+///
+/// Encrypted keys are rejected unless a passphrase is provided, and vice versa,
+/// as are keys whose embedded public key doesn't match the secret key.
+pub(crate) fn decode_private_key<C>(pem: &str, passphrase: Option<&[u8]>) -> Result<SecretKey<C>>
+where
+    C: CurveArithmetic + OpenSshParameters,
+    AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    FieldSize<C>: ModulusSize,
+{
+    let mut decoder =
+        pem::Decoder::new_wrapped(pem.as_bytes(), PEM_LINE_WIDTH).map_err(|_| Error)?;
+
+    if decoder.type_label() != PEM_TYPE_LABEL {
+        return Err(Error);
+    }
+
+    let mut bytes = Zeroizing::new(Vec::new());
+    decoder.decode_to_end(&mut bytes).map_err(|_| Error)?;
+
+    let mut reader = Reader(bytes.strip_prefix(AUTH_MAGIC).ok_or(Error)?);
+    let cipher = reader.string()?;
+    let kdf = reader.string()?;
+    let kdf_options = reader.string()?;
+
+    // Only files containing a single key are supported
+    if reader.u32()? != 1 {
+        return Err(Error);
+    }
+
+    let public_key = decode_public_key_blob::<C>(reader.string()?)?;
+    #[cfg_attr(not(feature = "openssh-encryption"), allow(unused_mut))]
+    let mut private_section = Zeroizing::new(reader.string()?.to_vec());
+    reader.finish()?;
+
+    let block_size = match (cipher, kdf, passphrase) {
+        (NONE, NONE, None) if kdf_options.is_empty() => UNENCRYPTED_BLOCK_SIZE,
+        #[cfg(feature = "openssh-encryption")]
+        (AES256_CTR, BCRYPT, Some(passphrase)) => {
+            let mut kdf_options = Reader(kdf_options);
+            let salt = kdf_options.string()?;
+            let rounds = kdf_options.u32()?;
+            kdf_options.finish()?;
+
+            if rounds > BCRYPT_MAX_ROUNDS {
+                return Err(Error);
+            }
+
+            apply_cipher(passphrase, salt, rounds, &mut private_section)?;
+            AES_BLOCK_SIZE
+        }
+        _ => return Err(Error),
+    };
+
+    if private_section.len() % block_size != 0 {
+        return Err(Error);
+    }
+
+    let mut reader = Reader(&private_section);
+
+    // Mismatched check integers indicate an incorrect passphrase
+    if reader.u32()? != reader.u32()? {
+        return Err(Error);
+    }
+
+    if reader.string()? != key_type::<C>().as_bytes()
+        || reader.string()? != C::SSH_IDENTIFIER.as_bytes()
+        || reader.string()? != public_key.as_bytes()
+    {
+        return Err(Error);
+    }
+
+    let secret_scalar = reader.mpint()?;
+    let _comment = reader.string()?;
+
+    // Padding consists of the bytes 1, 2, 3, ... up to the block size
+    let padding = reader.0;
+    if padding.len() >= block_size
+        || !padding
+            .iter()
+            .enumerate()
+            .all(|(i, &byte)| usize::from(byte) == i + 1)
+    {
+        return Err(Error);
+    }
+
+    if secret_scalar.len() > FieldSize::<C>::USIZE {
+        return Err(Error);
+    }
+
+    let mut secret_key_bytes = FieldBytes::<C>::default();
+    secret_key_bytes[(FieldSize::<C>::USIZE - secret_scalar.len())..]
+        .copy_from_slice(secret_scalar);
+
+    let secret_key = SecretKey::from_be_bytes(&secret_key_bytes);
+    secret_key_bytes.zeroize();
+    let secret_key = secret_key?;
+
+    // Nothing else ties the public key to the secret key, so a mismatched
+    // one would otherwise be silently accepted
+    if PublicKey::from_sec1_bytes(public_key.as_bytes())? != secret_key.public_key() {
+        return Err(Error);
+    }
+
+    Ok(secret_key)
+}
+
+/// Get the SSH key type name for the given curve.
+fn key_type<C: OpenSshParameters>() -> String {
+    format!("ecdsa-sha2-{}", C::SSH_IDENTIFIER)
+}
+
+/// Encode the SSH wire format of a public key.
+fn encode_public_key_blob<C>(point: &EncodedPoint<C>) -> Result<Vec<u8>>
+where
+    C: OpenSshParameters,
+    FieldSize<C>: ModulusSize,
+{
+    let mut writer = Writer::default();
+    writer.string(key_type::<C>().as_bytes())?;
+    writer.string(C::SSH_IDENTIFIER.as_bytes())?;
+    writer.string(point.as_bytes())?;
+    Ok(writer.0)
+}
+
+/// Decode the SSH wire format of a public key.
+fn decode_public_key_blob<C>(blob: &[u8]) -> Result<EncodedPoint<C>>
+where
+    C: OpenSshParameters,
+    FieldSize<C>: ModulusSize,
+{
+    let mut reader = Reader(blob);
+
+    if reader.string()? != key_type::<C>().as_bytes()
+        || reader.string()? != C::SSH_IDENTIFIER.as_bytes()
+    {
+        return Err(Error);
+    }
+
+    let point = EncodedPoint::<C>::from_bytes(reader.string()?).map_err(|_| Error)?;
+    reader.finish()?;
+    Ok(point)
+}
+
+/// Encode the (unencrypted) private section of an `openssh-key-v1` key,
+/// padded to a multiple of the given block size.
+fn encode_private_section<C>(
+    secret_key: &SecretKey<C>,
+    public_key: &EncodedPoint<C>,
+    checkint: u32,
+    block_size: usize,
+) -> Result<Zeroizing<Vec<u8>>>
+where
+    C: OpenSshParameters,
+    FieldSize<C>: ModulusSize,
+{
+    let mut writer = Writer::default();
+    writer.u32(checkint);
+    writer.u32(checkint);
+    writer.string(key_type::<C>().as_bytes())?;
+    writer.string(C::SSH_IDENTIFIER.as_bytes())?;
+    writer.string(public_key.as_bytes())?;
+
+    let mut secret_key_bytes = secret_key.to_be_bytes();
+    let result = writer.mpint(&secret_key_bytes);
+    secret_key_bytes.zeroize();
+    result?;
+
+    // Comment
+    writer.string(&[])?;
+
+    let mut padding = 1;
+    while writer.0.len() % block_size != 0 {
+        writer.0.push(padding);
+        padding += 1;
+    }
+
+    Ok(Zeroizing::new(writer.0))
+}
+
+/// Encode the outer `openssh-key-v1` container as PEM.
+fn encode_private_key_container<C>(
+    cipher: &[u8],
+    kdf: &[u8],
+    kdf_options: &[u8],
+    public_key: &EncodedPoint<C>,
+    private_section: &[u8],
+) -> Result<Zeroizing<String>>
+where
+    C: OpenSshParameters,
+    FieldSize<C>: ModulusSize,
+{
+    let mut writer = Writer::default();
+    writer.0.extend_from_slice(AUTH_MAGIC);
+    writer.string(cipher)?;
+    writer.string(kdf)?;
+    writer.string(kdf_options)?;
+    writer.u32(1);
+    writer.string(&encode_public_key_blob::<C>(public_key)?)?;
+    writer.string(private_section)?;
+    let bytes = Zeroizing::new(writer.0);
+
+    let line_ending = pem::LineEnding::LF;
+    let pem_len =
+        pem::encapsulated_len_wrapped(PEM_TYPE_LABEL, PEM_LINE_WIDTH, line_ending, bytes.len())
+            .map_err(|_| Error)?;
+
+    let mut buf = Zeroizing::new(vec![0u8; pem_len]);
+    let mut encoder =
+        pem::Encoder::new_wrapped(PEM_TYPE_LABEL, PEM_LINE_WIDTH, line_ending, &mut buf)
+            .map_err(|_| Error)?;
+    encoder.encode(&bytes).map_err(|_| Error)?;
+    let len = encoder.finish().map_err(|_| Error)?;
+    buf.truncate(len);
+
+    String::from_utf8(core::mem::take(&mut *buf))
+        .map(Zeroizing::new)
+        .map_err(|_| Error)
+}
+
+/// Encrypt or decrypt the private section of a key in-place.
+#[cfg(feature = "openssh-encryption")]
+fn apply_cipher(passphrase: &[u8], salt: &[u8], rounds: u32, data: &mut [u8]) -> Result<()> {
+    // 256-bit AES key followed by a 128-bit IV
+    let mut key_iv = Zeroizing::new([0u8; 48]);
+    bcrypt_pbkdf::bcrypt_pbkdf(passphrase, salt, rounds, key_iv.as_mut()).map_err(|_| Error)?;
+
+    let (key, iv) = key_iv.split_at(32);
+    let mut cipher =
+        ctr::Ctr128BE::<Aes256>::new(GenericArray::from_slice(key), GenericArray::from_slice(iv));
+    cipher.apply_keystream(data);
+    Ok(())
+}
+
+/// Writer for the SSH wire format.
+#[derive(Default)]
+struct Writer(Vec<u8>);
+
+impl Writer {
+    /// Write a big endian `uint32`.
+    fn u32(&mut self, n: u32) {
+        self.0.extend_from_slice(&n.to_be_bytes());
+    }
+
+    /// Write a length-prefixed `string`.
+    fn string(&mut self, bytes: &[u8]) -> Result<()> {
+        self.u32(u32::try_from(bytes.len()).map_err(|_| Error)?);
+        self.0.extend_from_slice(bytes);
+        Ok(())
+    }
+
+    /// Write a non-negative big endian integer as an `mpint`.
+    fn mpint(&mut self, bytes: &[u8]) -> Result<()> {
+        let leading_zeros = bytes.iter().take_while(|&&byte| byte == 0).count();
+        let bytes = &bytes[leading_zeros..];
+
+        // A leading zero byte is needed if the high bit is set
+        match bytes.first() {
+            Some(&byte) if byte >= 0x80 => {
+                self.u32(u32::try_from(bytes.len() + 1).map_err(|_| Error)?);
+                self.0.push(0);
+                self.0.extend_from_slice(bytes);
+                Ok(())
+            }
+            _ => self.string(bytes),
+        }
+    }
+}
+
+/// Reader for the SSH wire format.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    /// Read a big endian `uint32`.
+    fn u32(&mut self) -> Result<u32> {
+        let mut bytes = [0u8; 4];
+        bytes.copy_from_slice(self.take(4)?);
+        Ok(u32::from_be_bytes(bytes))
+    }
+
+    /// Read a length-prefixed `string`.
+    fn string(&mut self) -> Result<&'a [u8]> {
+        let len = usize::try_from(self.u32()?).map_err(|_| Error)?;
+        self.take(len)
+    }
+
+    /// Read a non-negative `mpint`, returning its big endian magnitude
+    /// without leading zeros.
+    fn mpint(&mut self) -> Result<&'a [u8]> {
+        let bytes = self.string()?;
+
+        match bytes {
+            [0, rest @ ..] if rest.first().map_or(false, |&byte| byte >= 0x80) => Ok(rest),
+            [first, ..] if *first == 0 || *first >= 0x80 => Err(Error),
+            _ => Ok(bytes),
+        }
+    }
+
+    /// Ensure all input has been consumed.
+    fn finish(self) -> Result<()> {
+        if self.0.is_empty() {
+            Ok(())
+        } else {
+            Err(Error)
+        }
+    }
+
+    /// Read the given number of bytes.
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        if self.0.len() < len {
+            return Err(Error);
+        }
+
+        let (bytes, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(bytes)
+    }
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::{
+        decode_private_key, encode_private_key_container, encode_private_section, NONE,
+        UNENCRYPTED_BLOCK_SIZE,
+    };
+    use crate::{
+        dev::{MockCurve, SecretKey},
+        sec1::ToEncodedPoint,
+    };
+    use hex_literal::hex;
+
+    #[cfg(feature = "openssh-encryption")]
+    use super::{Writer, AES256_CTR, BCRYPT, BCRYPT_MAX_ROUNDS};
+
+    const EXAMPLE_SCALAR: [u8; 32] =
+        hex!("b546de480c085b38a76ecc03286afa9c8126dfa85a176643b99c2e5758818a94");
+
+    #[test]
+    fn reject_mismatched_public_key() {
+        let secret_key = SecretKey::from_be_bytes(&EXAMPLE_SCALAR).unwrap();
+        let mut other_scalar = EXAMPLE_SCALAR;
+        other_scalar[31] ^= 1;
+        let other_public_key = SecretKey::from_be_bytes(&other_scalar)
+            .unwrap()
+            .public_key()
+            .to_encoded_point(false);
+
+        let private_section =
+            encode_private_section(&secret_key, &other_public_key, 0, UNENCRYPTED_BLOCK_SIZE)
+                .unwrap();
+        let pem = encode_private_key_container::<MockCurve>(
+            NONE,
+            NONE,
+            &[],
+            &other_public_key,
+            &private_section,
+        )
+        .unwrap();
+
+        assert!(decode_private_key::<MockCurve>(&pem, None).is_err());
+    }
+
+    #[cfg(feature = "openssh-encryption")]
+    #[test]
+    fn reject_excessive_kdf_rounds() {
+        let secret_key = SecretKey::from_be_bytes(&EXAMPLE_SCALAR).unwrap();
+        let public_key = secret_key.public_key().to_encoded_point(false);
+
+        let mut kdf_options = Writer::default();
+        kdf_options.string(&[0; 16]).unwrap();
+        kdf_options.u32(BCRYPT_MAX_ROUNDS + 1);
+
+        // Rejected before running the KDF, so the private section is unused
+        let private_section = encode_private_section(&secret_key, &public_key, 0, 16).unwrap();
+        let pem = encode_private_key_container::<MockCurve>(
+            AES256_CTR,
+            BCRYPT,
+            &kdf_options.0,
+            &public_key,
+            &private_section,
+        )
+        .unwrap();
+
+        assert!(decode_private_key::<MockCurve>(&pem, Some(b"passphrase")).is_err());
+    }
+}
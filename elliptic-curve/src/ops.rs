@@ -3,13 +3,26 @@
 pub use core::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
 
 use crypto_bigint::{ArrayEncoding, ByteArray, Integer};
+use generic_array::{ArrayLength, GenericArray};
 
 #[cfg(feature = "arithmetic")]
-use {group::Group, subtle::CtOption};
+use {
+    crate::{CurveArithmetic, FieldBytes, IsHigh, ProjectivePoint, Scalar},
+    crypto_bigint::Limb,
+    ff::{Field, PrimeField},
+    group::Group,
+    subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption},
+};
+
+#[cfg(all(feature = "alloc", feature = "arithmetic"))]
+use alloc::vec::Vec;
 
 #[cfg(feature = "digest")]
 use digest::FixedOutput;
 
+#[cfg(feature = "hash2curve")]
+use crate::hash2curve::{hash_to_field, ExpandMsg};
+
 /// Perform an inversion on a field element (i.e. base field element or scalar)
 pub trait Invert {
     /// Field element type
@@ -17,14 +30,168 @@ pub trait Invert {
 
     /// Invert a field element.
     fn invert(&self) -> Self::Output;
+
+    /// Invert a field element in variable time.
+    ///
+    /// This is useful for operating on public values (e.g. when verifying
+    /// signatures), where implementations may provide a faster inversion
+    /// strategy than their constant-time one.
+    ///
+    /// The default implementation falls back on [`Invert::invert`].
+    ///
+    /// ⚠️ WARNING!
+    ///
+    /// This method should not be used with secret values, as its variable-time
+    /// operation can potentially leak secrets through sidechannels.
+    fn invert_vartime(&self) -> Self::Output {
+        // Fall back on constant-time implementation by default.
+        self.invert()
+    }
 }
 
 #[cfg(feature = "arithmetic")]
-impl<F: ff::Field> Invert for F {
+impl<F: Field> Invert for F {
     type Output = CtOption<F>;
 
     fn invert(&self) -> CtOption<F> {
-        ff::Field::invert(self)
+        Field::invert(self)
+    }
+}
+
+/// Perform a batched inversion on a sequence of field elements (i.e. base field
+/// elements or scalars) at an amortized cost that should be practically as
+/// efficient as a single inversion.
+///
+/// Inversion of the entire batch fails if any of the field elements is zero.
+#[cfg(feature = "arithmetic")]
+pub trait BatchInvert<FieldElements: ?Sized>: Field + Sized {
+    /// The output of batch inversion. A container of field elements.
+    type Output: AsRef<[Self]>;
+
+    /// Invert a batch of field elements.
+    fn batch_invert(
+        field_elements: &FieldElements,
+    ) -> CtOption<<Self as BatchInvert<FieldElements>>::Output>;
+}
+
+#[cfg(feature = "arithmetic")]
+impl<const N: usize, T> BatchInvert<[T; N]> for T
+where
+    T: Field,
+{
+    type Output = [Self; N];
+
+    fn batch_invert(field_elements: &[Self; N]) -> CtOption<[Self; N]> {
+        let mut field_elements_multiples = [Self::ONE; N];
+        let mut field_elements_inverses = [Self::ONE; N];
+
+        let inversion_succeeded = invert_batch_internal(
+            field_elements,
+            &mut field_elements_multiples,
+            &mut field_elements_inverses,
+        );
+
+        CtOption::new(field_elements_inverses, inversion_succeeded)
+    }
+}
+
+#[cfg(all(feature = "alloc", feature = "arithmetic"))]
+impl<T> BatchInvert<[T]> for T
+where
+    T: Field,
+{
+    type Output = Vec<Self>;
+
+    fn batch_invert(field_elements: &[Self]) -> CtOption<Vec<Self>> {
+        let mut field_elements_multiples = vec![Self::ONE; field_elements.len()];
+        let mut field_elements_inverses = vec![Self::ONE; field_elements.len()];
+
+        let inversion_succeeded = invert_batch_internal(
+            field_elements,
+            &mut field_elements_multiples,
+            &mut field_elements_inverses,
+        );
+
+        CtOption::new(field_elements_inverses, inversion_succeeded)
+    }
+}
+
+/// Implements "Montgomery's trick", a trick for computing many modular inverses
+/// at once.
+///
+/// "Montgomery's trick" works by reducing the problem of computing `n` inverses
+/// to computing a single inversion, plus some storage and `O(n)` extra
+/// multiplications.
+///
+/// It does so by computing the product of all `n` elements, inverting it, and
+/// then walking the running products backwards to recover the inverse of each
+/// individual element.
+#[cfg(feature = "arithmetic")]
+fn invert_batch_internal<T: Field>(
+    field_elements: &[T],
+    field_elements_multiples: &mut [T],
+    field_elements_inverses: &mut [T],
+) -> Choice {
+    let batch_size = field_elements.len();
+    debug_assert_eq!(batch_size, field_elements_multiples.len());
+    debug_assert_eq!(batch_size, field_elements_inverses.len());
+
+    if batch_size == 0 {
+        return Choice::from(1);
+    }
+
+    // $ a_n = a_{n-1} * x_n $
+    let mut acc = T::ONE;
+    for (multiple, field_element) in field_elements_multiples.iter_mut().zip(field_elements) {
+        *multiple = acc;
+        acc *= field_element;
+    }
+
+    let inverse = acc.invert();
+    let mut acc = inverse.unwrap_or(T::ONE);
+
+    // $ {x_n}^{-1} = a_{n-1} * {a_n}^{-1} $
+    for ((inverse, multiple), field_element) in field_elements_inverses
+        .iter_mut()
+        .zip(field_elements_multiples.iter())
+        .zip(field_elements)
+        .rev()
+    {
+        *inverse = *multiple * acc;
+        acc *= field_element;
+    }
+
+    inverse.is_some()
+}
+
+/// Point doubling.
+///
+/// This trait enables crates to provide an optimized implementation of
+/// repeated doubling (e.g. avoiding intermediate normalizations), as used by
+/// windowed scalar multiplication and related algorithms, or otherwise
+/// provides a default non-optimized implementation in terms of
+/// [`Group::double`].
+#[cfg(feature = "arithmetic")]
+pub trait Double: Group {
+    /// Double this point, i.e. compute `2 * self`.
+    ///
+    /// Named distinctly from [`Group::double`] so the two don't conflict
+    /// when both traits are in scope.
+    #[must_use]
+    fn double_point(&self) -> Self {
+        Group::double(self)
+    }
+
+    /// Double this point `n` times, i.e. compute `2^n * self`.
+    #[must_use]
+    fn double_n(&self, n: usize) -> Self {
+        let mut ret = *self;
+
+        for _ in 0..n {
+            ret = ret.double_point();
+        }
+
+        ret
     }
 }
 
@@ -42,6 +209,110 @@ pub trait LinearCombination: Group {
     }
 }
 
+/// Linear combination (extended version).
+///
+/// This trait enables crates to provide an optimized implementation of
+/// linear combinations of an arbitrary number of terms, e.g. multi-scalar
+/// multiplication using the Pippenger/bucket method, or otherwise provides a
+/// default non-optimized implementation.
+///
+/// The `PointsAndScalars` type parameter allows implementations for both
+/// fixed-size arrays (e.g. `[(Self, Self::Scalar); N]`), which can be used in
+/// heapless environments, as well as slices (`[(Self, Self::Scalar)]`).
+// TODO(tarcieri): replace the current `LinearCombination` with this in the next release
+#[cfg(feature = "arithmetic")]
+pub trait LinearCombinationExt<PointsAndScalars>: Group
+where
+    PointsAndScalars: AsRef<[(Self, Self::Scalar)]> + ?Sized,
+{
+    /// Calculates `x1 * k1 + ... + xn * kn`.
+    fn lincomb_ext(points_and_scalars: &PointsAndScalars) -> Self {
+        points_and_scalars
+            .as_ref()
+            .iter()
+            .map(|(point, scalar)| *point * scalar)
+            .sum()
+    }
+}
+
+/// Variable-time scalar multiplication.
+///
+/// This is kept separate from the constant-time [`Mul`] impls of [`Group`]
+/// so that code operating on public values (e.g. signature verification) can
+/// explicitly opt into faster variable-time implementations by bounding on
+/// this trait, while generic code which only requires [`Group`] (e.g.
+/// signing) can never accidentally use them.
+///
+/// Implementations may use e.g. [`mul_wnaf`][`crate::scalar::mul_wnaf`],
+/// or otherwise fall back on the default constant-time implementation.
+#[cfg(feature = "arithmetic")]
+pub trait MulVartime: Group {
+    /// Multiply this point by the given scalar in variable time.
+    ///
+    /// ⚠️ WARNING!
+    ///
+    /// This method should not be used with secret scalars, as its
+    /// variable-time operation can potentially leak secrets through
+    /// sidechannels.
+    #[must_use]
+    fn mul_vartime(&self, scalar: &Self::Scalar) -> Self {
+        *self * scalar
+    }
+}
+
+/// Variable-time linear combination.
+///
+/// Like [`MulVartime`], this allows code operating on public values to
+/// explicitly opt into faster variable-time implementations of
+/// [`LinearCombination`] (e.g. interleaved wNAF), as typically used when
+/// verifying signatures.
+#[cfg(feature = "arithmetic")]
+pub trait LincombVartime: LinearCombination {
+    /// Calculates `x * k + y * l` in variable time.
+    ///
+    /// ⚠️ WARNING!
+    ///
+    /// This method should not be used with secret scalars, as its
+    /// variable-time operation can potentially leak secrets through
+    /// sidechannels.
+    fn lincomb_vartime(x: &Self, k: &Self::Scalar, y: &Self, l: &Self::Scalar) -> Self {
+        Self::lincomb(x, k, y, l)
+    }
+}
+
+/// Mixed addition of a point in projective coordinates and a point in affine
+/// coordinates.
+///
+/// Since the `z`-coordinate of an affine point is implicitly one, mixed
+/// addition is cheaper than adding two projective points, which generic code
+/// such as multi-scalar multiplication or table-based multiplication can
+/// exploit by normalizing its precomputed points.
+///
+/// The default implementation uses the `Add<AffineRepr>` impl required by
+/// [`group::Curve`], which curve backends without a dedicated mixed
+/// addition formula may implement as a full addition.
+#[cfg(feature = "arithmetic")]
+pub trait AddMixed: group::Curve {
+    /// Add the given affine point to this point.
+    #[must_use]
+    fn add_mixed(&self, other: &Self::AffineRepr) -> Self {
+        *self + other
+    }
+}
+
+/// Mixed subtraction of a point in affine coordinates from a point in
+/// projective coordinates.
+///
+/// See [`AddMixed`] for more information.
+#[cfg(feature = "arithmetic")]
+pub trait SubMixed: group::Curve {
+    /// Subtract the given affine point from this point.
+    #[must_use]
+    fn sub_mixed(&self, other: &Self::AffineRepr) -> Self {
+        *self - other
+    }
+}
+
 /// Multiplication by the generator.
 ///
 /// May use optimizations (e.g. precomputed tables) when available.
@@ -55,6 +326,312 @@ pub trait MulByGenerator: Group {
     }
 }
 
+/// Efficiently computable endomorphism, as used by the
+/// Gallant-Lambert-Vanstone (GLV) method for scalar multiplication.
+///
+/// Curves such as secp256k1 have an endomorphism `φ` which acts on points as
+/// multiplication by a constant scalar `λ`, i.e. `φ(P) = λP`, yet which can be
+/// computed much more cheaply than a scalar multiplication. This allows a
+/// scalar `k` to be decomposed into two scalars `k1` and `k2` of roughly half
+/// the bit length such that `k = k1 + k2 * λ (mod n)`, which can then be used
+/// to compute `kP = k1 * P + k2 * φ(P)` with half the number of doublings.
+///
+/// See [`mul_glv`] for a generic scalar multiplication which uses this trait.
+///
+/// <https://www.iacr.org/archive/crypto2001/21390189.pdf>
+#[cfg(feature = "arithmetic")]
+pub trait Endomorphism: Group {
+    /// Maximum bit length of the absolute values of the scalars returned by
+    /// [`Endomorphism::decompose_scalar`].
+    const DECOMPOSED_BITS: usize;
+
+    /// Apply the endomorphism to this point, i.e. compute `λ * self`.
+    #[must_use]
+    fn endomorphism(&self) -> Self;
+
+    /// Decompose the given scalar `k` into `(k1, k2)` such that
+    /// `k = k1 + k2 * λ (mod n)`.
+    ///
+    /// The decomposed scalars may be negative, where a negative value `-x` is
+    /// represented as `n - x` as usual. In either case their absolute value
+    /// must fit within [`Endomorphism::DECOMPOSED_BITS`].
+    fn decompose_scalar(k: &Self::Scalar) -> (Self::Scalar, Self::Scalar);
+}
+
+/// Constant-time scalar multiplication using the GLV method.
+///
+/// Decomposes the scalar using the curve's [`Endomorphism`] and computes
+/// `k1 * P + k2 * φ(P)` using a joint double-and-add over the shortened
+/// scalars.
+#[cfg(feature = "arithmetic")]
+pub fn mul_glv<C>(point: &ProjectivePoint<C>, scalar: &Scalar<C>) -> ProjectivePoint<C>
+where
+    C: CurveArithmetic,
+    ProjectivePoint<C>: Endomorphism,
+{
+    let (k1, k2) = ProjectivePoint::<C>::decompose_scalar(scalar);
+    let p1 = *point;
+    let p2 = point.endomorphism();
+
+    // Map negative scalars to their absolute value, negating the point instead
+    let k1_neg = k1.is_high();
+    let k1 = Scalar::<C>::conditional_select(&k1, &-k1, k1_neg);
+    let p1 = ProjectivePoint::<C>::conditional_select(&p1, &-p1, k1_neg);
+
+    let k2_neg = k2.is_high();
+    let k2 = Scalar::<C>::conditional_select(&k2, &-k2, k2_neg);
+    let p2 = ProjectivePoint::<C>::conditional_select(&p2, &-p2, k2_neg);
+
+    let table = [ProjectivePoint::<C>::identity(), p1, p2, p1 + p2];
+    let k1_repr = k1.to_repr();
+    let k2_repr = k2.to_repr();
+
+    let mut ret = ProjectivePoint::<C>::identity();
+
+    for i in (0..<ProjectivePoint<C> as Endomorphism>::DECOMPOSED_BITS).rev() {
+        ret = ret.double();
+
+        let index = repr_bit::<C>(&k1_repr, i) | (repr_bit::<C>(&k2_repr, i) << 1);
+        let mut addend = ProjectivePoint::<C>::identity();
+
+        for (j, point) in table.iter().enumerate() {
+            addend.conditional_assign(point, (j as u8).ct_eq(&index));
+        }
+
+        ret += addend;
+    }
+
+    ret
+}
+
+/// Constant-time scalar multiplication using the Montgomery ladder.
+///
+/// This is a generic fallback for curve implementations which don't provide
+/// an optimized scalar multiplication, built only on the [`Group`] operations
+/// and [`ConditionallySelectable`]. Every bit of the scalar is processed with
+/// exactly one addition and one doubling, regardless of its value.
+#[cfg(feature = "arithmetic")]
+pub fn montgomery_ladder<C>(point: &ProjectivePoint<C>, scalar: &Scalar<C>) -> ProjectivePoint<C>
+where
+    C: CurveArithmetic,
+{
+    let repr = scalar.to_repr();
+    let mut r0 = ProjectivePoint::<C>::identity();
+    let mut r1 = *point;
+
+    for i in (0..Scalar::<C>::NUM_BITS as usize).rev() {
+        let bit = Choice::from(repr_bit::<C>(&repr, i));
+        ProjectivePoint::<C>::conditional_swap(&mut r0, &mut r1, bit);
+        r1 += r0;
+        r0 = r0.double();
+        ProjectivePoint::<C>::conditional_swap(&mut r0, &mut r1, bit);
+    }
+
+    r0
+}
+
+/// Get the bit at position `i` (counting from the least significant bit) of
+/// a big endian serialized scalar.
+#[cfg(feature = "arithmetic")]
+fn repr_bit<C: crate::Curve>(repr: &FieldBytes<C>, i: usize) -> u8 {
+    repr.iter()
+        .rev()
+        .nth(i / 8)
+        .map(|byte| (byte >> (i % 8)) & 1)
+        .unwrap_or(0)
+}
+
+/// Multiplication of a fixed point using a precomputed table.
+///
+/// Building a table for a given point is comparatively expensive, but once
+/// built it can be used to quickly multiply that point by many different
+/// scalars, e.g. when performing repeated ECDH against a static peer key.
+///
+/// [`MulByGenerator`] covers the special case where the fixed point is the
+/// generator.
+#[cfg(feature = "arithmetic")]
+pub trait PrecomputedTable: Sized {
+    /// Point type the table is computed for.
+    type Point: Group;
+
+    /// Precompute a table for the given point.
+    fn new(point: &Self::Point) -> Self;
+
+    /// Multiply the point this table was computed for by the given scalar.
+    fn multiply(&self, scalar: &<Self::Point as Group>::Scalar) -> Self::Point;
+}
+
+/// Exponentiation of a field element by an unsigned integer exponent.
+///
+/// Unlike [`Field::pow`], which takes the exponent as 64-bit limbs, this
+/// accepts any `crypto-bigint` integer (e.g. `C::Uint`), and performs the
+/// same sequence of operations for every exponent of a given width.
+///
+/// A blanket impl is provided for all types which impl [`Field`].
+#[cfg(feature = "arithmetic")]
+pub trait Pow<Uint: Integer>: Sized {
+    /// Compute `self^exp` in constant time.
+    #[must_use]
+    fn pow_uint(&self, exp: &Uint) -> Self;
+}
+
+#[cfg(feature = "arithmetic")]
+impl<F: Field, Uint: Integer> Pow<Uint> for F {
+    fn pow_uint(&self, exp: &Uint) -> Self {
+        let mut ret = F::ONE;
+
+        for limb in exp.as_ref().iter().rev() {
+            for i in (0..Limb::BITS).rev() {
+                ret = ret.square();
+                let bit = Choice::from(((limb.0 >> i) & 1) as u8);
+                ret.conditional_assign(&(ret * self), bit);
+            }
+        }
+
+        ret
+    }
+}
+
+/// Variable-time exponentiation of a field element by an unsigned integer
+/// exponent.
+///
+/// A blanket impl is provided for all types which impl [`Field`].
+#[cfg(feature = "arithmetic")]
+pub trait PowVartime<Uint: Integer>: Sized {
+    /// Compute `self^exp` in variable time.
+    ///
+    /// ⚠️ WARNING!
+    ///
+    /// This method should not be used with secret exponents, as its
+    /// variable-time operation can potentially leak secrets through
+    /// sidechannels.
+    #[must_use]
+    fn pow_uint_vartime(&self, exp: &Uint) -> Self;
+}
+
+#[cfg(feature = "arithmetic")]
+impl<F: Field, Uint: Integer> PowVartime<Uint> for F {
+    fn pow_uint_vartime(&self, exp: &Uint) -> Self {
+        let mut ret = F::ONE;
+
+        for limb in exp.as_ref().iter().rev() {
+            for i in (0..Limb::BITS).rev() {
+                ret = ret.square();
+
+                if (limb.0 >> i) & 1 == 1 {
+                    ret *= self;
+                }
+            }
+        }
+
+        ret
+    }
+}
+
+/// Square roots in prime fields.
+///
+/// This provides generic implementations of the square root which curve
+/// implementations can use for [`Field::sqrt`], given the required constants
+/// for their field:
+///
+/// - When `p ≡ 3 mod 4`, the square root of `x` is `x^((p + 1) / 4)`, which
+///   is used if [`Sqrt::P_PLUS_ONE_DIV_FOUR`] is provided.
+/// - Otherwise, the constant-time Tonelli-Shanks algorithm is used, which
+///   requires [`PrimeField::ROOT_OF_UNITY`] and [`PrimeField::S`] to be
+///   correct.
+#[cfg(feature = "arithmetic")]
+pub trait Sqrt: PrimeField {
+    /// `(t - 1) / 2` as little endian 64-bit limbs, where `p - 1 = 2^S * t`
+    /// with `t` odd.
+    const T_MINUS_ONE_DIV_TWO: &'static [u64];
+
+    /// `(p + 1) / 4` as little endian 64-bit limbs, if `p ≡ 3 mod 4`.
+    const P_PLUS_ONE_DIV_FOUR: Option<&'static [u64]> = None;
+
+    /// Compute the square root of this field element, if it is a square.
+    fn sqrt_generic(&self) -> CtOption<Self> {
+        match Self::P_PLUS_ONE_DIV_FOUR {
+            Some(exp) => {
+                let sqrt = self.pow(exp);
+                CtOption::new(sqrt, sqrt.square().ct_eq(self))
+            }
+            None => ff::helpers::sqrt_tonelli_shanks(self, Self::T_MINUS_ONE_DIV_TWO),
+        }
+    }
+}
+
+/// Construct a field element from uniformly random output keying material
+/// (OKM), e.g. the output of `expand_message` when hashing to a field, or
+/// of a KDF such as HKDF.
+///
+/// Implementations reduce the OKM modulo the field's modulus. To keep the
+/// bias of the result negligible, [`FromOkm::Length`] should be at least
+/// `ceil((ceil(log2(p)) + k) / 8)` bytes where `k` is the target security
+/// level in bits, as described in [section 5 of RFC 9380][rfc9380].
+///
+/// [rfc9380]: https://www.rfc-editor.org/rfc/rfc9380.html#section-5
+pub trait FromOkm {
+    /// The number of bytes needed to convert to a field element.
+    type Length: ArrayLength<u8>;
+
+    /// Convert a byte sequence into a field element.
+    fn from_okm(data: &GenericArray<u8, Self::Length>) -> Self;
+}
+
+/// Derive a challenge scalar from a transcript, e.g. the Fiat-Shamir
+/// challenge of a Schnorr signature or proof of discrete log equality.
+///
+/// The transcript is expanded with domain separation to
+/// [`FromOkm::Length`] bytes using [`ExpandMsg`] and then reduced, in the
+/// same way as `hash_to_scalar` from [RFC 9380][rfc9380]. This makes the
+/// bias of the challenge negligible, and makes derivation consistent across
+/// curves and protocols: different domain separation tags yield unrelated
+/// challenges for the same transcript.
+///
+/// This trait is impl'd for all types which impl [`FromOkm`] and [`Default`].
+///
+/// [rfc9380]: https://www.rfc-editor.org/rfc/rfc9380.html#section-5
+#[cfg(feature = "hash2curve")]
+pub trait ChallengeScalar: Sized {
+    /// Derive a challenge from the concatenation of the given messages, using
+    /// the domain separation tag `dst`.
+    ///
+    /// As the messages are concatenated, protocols must encode them
+    /// unambiguously, e.g. by using fixed-length encodings for points.
+    ///
+    /// Returns an error if `X` rejects the messages or tag, e.g. because the
+    /// tag is empty.
+    fn challenge<'a, X>(msgs: &[&[u8]], dst: &'a [u8]) -> crate::Result<Self>
+    where
+        X: ExpandMsg<'a>;
+
+    /// Derive a challenge from a running hash of the transcript, using its
+    /// output as the message.
+    fn from_transcript<'a, X, D>(transcript: D, dst: &'a [u8]) -> crate::Result<Self>
+    where
+        X: ExpandMsg<'a>,
+        D: FixedOutput,
+    {
+        Self::challenge::<X>(&[&transcript.finalize_fixed()], dst)
+    }
+}
+
+#[cfg(feature = "hash2curve")]
+impl<S> ChallengeScalar for S
+where
+    S: FromOkm + Default,
+{
+    fn challenge<'a, X>(msgs: &[&[u8]], dst: &'a [u8]) -> crate::Result<Self>
+    where
+        X: ExpandMsg<'a>,
+    {
+        let mut out = [S::default()];
+        hash_to_field::<X, S>(msgs, dst, &mut out)?;
+        let [scalar] = out;
+        Ok(scalar)
+    }
+}
+
 /// Modular reduction.
 pub trait Reduce<Uint: Integer + ArrayEncoding>: Sized {
     /// Perform a modular reduction, returning a field element.
@@ -93,6 +670,60 @@ pub trait Reduce<Uint: Integer + ArrayEncoding>: Sized {
     }
 }
 
+/// Modular reduction of a double-width integer.
+///
+/// Reducing an integer of the same width as the modulus introduces a bias
+/// towards small values, unless the modulus is very close to a power of two.
+/// Reducing a uniformly random integer of twice the width yields a
+/// statistically uniform field element, as required e.g. for hash-to-field as
+/// described in [section 5 of RFC 9380][rfc9380].
+///
+/// [rfc9380]: https://www.rfc-editor.org/rfc/rfc9380.html#section-5
+pub trait ReduceWide<WideUint: Integer + ArrayEncoding>: Sized {
+    /// Perform a modular reduction of a double-width integer, returning a
+    /// field element.
+    fn from_wide_uint_reduced(n: WideUint) -> Self;
+
+    /// Interpret the given byte array as a big endian double-width integer
+    /// and perform a modular reduction.
+    fn from_be_bytes_reduced_wide(bytes: ByteArray<WideUint>) -> Self {
+        Self::from_wide_uint_reduced(WideUint::from_be_byte_array(bytes))
+    }
+
+    /// Interpret the given byte array as a little endian double-width integer
+    /// and perform a modular reduction.
+    fn from_le_bytes_reduced_wide(bytes: ByteArray<WideUint>) -> Self {
+        Self::from_wide_uint_reduced(WideUint::from_le_byte_array(bytes))
+    }
+
+    /// Interpret a digest as a big endian double-width integer and perform a
+    /// modular reduction.
+    #[cfg(feature = "digest")]
+    fn from_be_digest_reduced_wide<D>(digest: D) -> Self
+    where
+        D: FixedOutput<OutputSize = WideUint::ByteSize>,
+    {
+        Self::from_be_bytes_reduced_wide(digest.finalize_fixed())
+    }
+}
+
+/// Modular reduction of a byte string of arbitrary length.
+///
+/// This is useful for protocols which derive scalars from inputs that don't
+/// have a fixed width, such as deterministic nonce derivation or deriving
+/// challenges from a transcript. Note that the result is only statistically
+/// uniform if the input is uniformly random and sufficiently longer than the
+/// modulus (see [`ReduceWide`]).
+pub trait ReduceBytes: Sized {
+    /// Interpret the given bytes as a big endian integer and perform a
+    /// modular reduction.
+    fn from_be_slice_reduced(bytes: &[u8]) -> Self;
+
+    /// Interpret the given bytes as a little endian integer and perform a
+    /// modular reduction.
+    fn from_le_slice_reduced(bytes: &[u8]) -> Self;
+}
+
 /// Modular reduction to a non-zero output.
 ///
 /// This trait is primarily intended for use by curve implementations such
@@ -110,3 +741,169 @@ pub trait Shr1 {
     /// Right shift this value by one bit in-place.
     fn shr1(&mut self);
 }
+
+/// Shift the canonical integer representation of this value by an arbitrary
+/// number of bits, storing the result in-place.
+///
+/// These operations run in constant time with respect to the value being
+/// shifted, but may be variable-time with respect to the shift amount.
+pub trait BitShift {
+    /// Right shift this value by `shift` bits in-place.
+    ///
+    /// This is equivalent to dividing the canonical integer representation by
+    /// `2^shift` and rounding down.
+    fn shr_assign_bits(&mut self, shift: usize);
+
+    /// Left shift this value by `shift` bits in-place.
+    ///
+    /// This is equivalent to multiplying by `2^shift` modulo the field modulus.
+    fn shl_assign_bits(&mut self, shift: usize);
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::{montgomery_ladder, mul_glv, Double, Endomorphism, Pow, PowVartime, Sqrt};
+    use crate::{
+        bigint::{U256, U64},
+        dev::{test_points, test_scalars, FieldBytes, MockCurve, ProjectivePoint, Scalar},
+        Curve,
+    };
+    use ff::{Field, PrimeField};
+    use group::Group;
+    use rand_core::OsRng;
+
+    #[cfg(feature = "hash2curve")]
+    use {
+        super::ChallengeScalar,
+        crate::hash2curve::ExpandMsgXmd,
+        sha2::{Digest, Sha256},
+    };
+
+    #[cfg(feature = "hash2curve")]
+    const DST: &[u8] = b"CHALLENGE-TEST-V1";
+
+    /// The constant `λ = 2^128` of the mock endomorphism.
+    fn lambda() -> Scalar {
+        let mut repr = FieldBytes::default();
+        repr[15] = 1;
+        Scalar::from_repr(repr).unwrap()
+    }
+
+    /// Mock endomorphism which splits scalars into their high and low halves,
+    /// returning a negative `k1` to exercise the handling of negative scalars.
+    impl Endomorphism for ProjectivePoint {
+        const DECOMPOSED_BITS: usize = 129;
+
+        fn endomorphism(&self) -> Self {
+            *self * lambda()
+        }
+
+        fn decompose_scalar(k: &Scalar) -> (Scalar, Scalar) {
+            let repr = k.to_repr();
+            let mut lo = FieldBytes::default();
+            let mut hi = FieldBytes::default();
+            lo[16..].copy_from_slice(&repr[16..]);
+            hi[16..].copy_from_slice(&repr[..16]);
+
+            let k1 = Scalar::from_repr(lo).unwrap();
+            let k2 = Scalar::from_repr(hi).unwrap();
+            (k1 - lambda(), k2 + Scalar::ONE)
+        }
+    }
+
+    #[test]
+    fn pow_uint() {
+        for x in test_scalars() {
+            let mut expected = Scalar::ONE;
+
+            for exp in 0u64..8 {
+                assert_eq!(x.pow_uint(&U64::from(exp)), expected);
+                assert_eq!(x.pow_uint_vartime(&U64::from(exp)), expected);
+                expected *= x;
+            }
+        }
+
+        // Fermat's little theorem: x^(n - 1) = 1
+        let x = Scalar::random(&mut OsRng);
+        let order_minus_one = <MockCurve as Curve>::ORDER.wrapping_sub(&U256::ONE);
+        assert_eq!(x.pow_uint(&order_minus_one), Scalar::ONE);
+    }
+
+    #[test]
+    fn sqrt_generic() {
+        for x in test_scalars() {
+            let sqrt = x.square().sqrt_generic().unwrap();
+            assert!(sqrt == x || sqrt == -x);
+        }
+
+        // The multiplicative generator isn't a square
+        assert!(bool::from(Scalar::from(7u64).sqrt_generic().is_none()));
+    }
+
+    #[test]
+    fn double_n() {
+        for point in test_points() {
+            assert_eq!(point.double_n(0), point);
+            assert_eq!(point.double_point(), point.double());
+            assert_eq!(point.double_n(3), point * Scalar::from(8u64));
+        }
+    }
+
+    #[test]
+    fn glv() {
+        for point in test_points() {
+            for scalar in test_scalars() {
+                assert_eq!(mul_glv::<MockCurve>(&point, &scalar), point * scalar);
+            }
+        }
+    }
+
+    #[test]
+    fn ladder() {
+        for point in test_points() {
+            for scalar in test_scalars() {
+                assert_eq!(
+                    montgomery_ladder::<MockCurve>(&point, &scalar),
+                    point * scalar
+                );
+            }
+        }
+    }
+
+    #[cfg(feature = "hash2curve")]
+    #[test]
+    fn challenge_domain_separation() {
+        let msgs: &[&[u8]] = &[b"commitment", b"public key", b"message"];
+        let challenge = Scalar::challenge::<ExpandMsgXmd<Sha256>>(msgs, DST).unwrap();
+
+        assert_eq!(
+            Scalar::challenge::<ExpandMsgXmd<Sha256>>(msgs, DST)
+                .unwrap()
+                .to_repr(),
+            challenge.to_repr()
+        );
+        assert_ne!(
+            Scalar::challenge::<ExpandMsgXmd<Sha256>>(msgs, b"OTHER-TEST-V1")
+                .unwrap()
+                .to_repr(),
+            challenge.to_repr()
+        );
+        assert!(Scalar::challenge::<ExpandMsgXmd<Sha256>>(msgs, &[]).is_err());
+    }
+
+    #[cfg(feature = "hash2curve")]
+    #[test]
+    fn from_transcript() {
+        let transcript = Sha256::new().chain_update(b"commitment");
+        let digest = transcript.clone().finalize();
+
+        assert_eq!(
+            Scalar::from_transcript::<ExpandMsgXmd<Sha256>, _>(transcript, DST)
+                .unwrap()
+                .to_repr(),
+            Scalar::challenge::<ExpandMsgXmd<Sha256>>(&[&digest], DST)
+                .unwrap()
+                .to_repr()
+        );
+    }
+}
@@ -0,0 +1,69 @@
+//! Traits for pairing-friendly elliptic curves.
+//!
+//! A pairing is a bilinear map 𝑒: 𝔾₁ × 𝔾₂ → 𝔾ₜ between the groups of a
+//! pairing-friendly curve (e.g. BLS12-381), as used by BLS signatures and
+//! many zero-knowledge proof systems.
+//!
+//! These traits allow such protocols to be implemented generically rather
+//! than against the API of a particular curve implementation.
+
+use ff::PrimeField;
+use group::{
+    prime::{PrimeCurve, PrimeCurveAffine},
+    Group,
+};
+
+/// Pairing-friendly elliptic curve.
+pub trait PairingCurve: Sized + 'static {
+    /// Scalar field shared by 𝔾₁, 𝔾₂, and 𝔾ₜ.
+    type Scalar: PrimeField;
+
+    /// Elements of 𝔾₁ in projective form.
+    type G1: PrimeCurve<Scalar = Self::Scalar, Affine = Self::G1Affine>;
+
+    /// Elements of 𝔾₁ in affine form.
+    type G1Affine: PrimeCurveAffine<Scalar = Self::Scalar, Curve = Self::G1>;
+
+    /// Elements of 𝔾₂ in projective form.
+    type G2: PrimeCurve<Scalar = Self::Scalar, Affine = Self::G2Affine>;
+
+    /// Elements of 𝔾₂ in affine form.
+    type G2Affine: PrimeCurveAffine<Scalar = Self::Scalar, Curve = Self::G2>;
+
+    /// Target group 𝔾ₜ, into which the pairing maps.
+    type Gt: Group<Scalar = Self::Scalar>;
+
+    /// Compute the pairing 𝑒(𝑷, 𝑸) of the given elements of 𝔾₁ and 𝔾₂.
+    fn pairing(p: &Self::G1Affine, q: &Self::G2Affine) -> Self::Gt;
+}
+
+/// Pairing-friendly elliptic curve which supports computing the product of
+/// several pairings with a single shared final exponentiation.
+///
+/// This is considerably faster than computing each pairing individually, and
+/// is how e.g. BLS signature aggregation is verified.
+pub trait MultiMillerLoop: PairingCurve {
+    /// Elements of 𝔾₂ with precomputed values for the Miller loop.
+    type G2Prepared: Clone + From<Self::G2Affine>;
+
+    /// Output of the Miller loop, prior to the final exponentiation.
+    type Result: MillerLoopResult<Gt = Self::Gt>;
+
+    /// Compute the Miller loop over the product of the given pairs:
+    ///
+    /// ∏ 𝑒′(𝑷ᵢ, 𝑸ᵢ)
+    ///
+    /// The result must be passed to
+    /// [`MillerLoopResult::final_exponentiation`] to obtain an element of 𝔾ₜ.
+    fn multi_miller_loop(terms: &[(&Self::G1Affine, &Self::G2Prepared)]) -> Self::Result;
+}
+
+/// Output of a Miller loop, which can be mapped into the target group 𝔾ₜ by
+/// performing the final exponentiation.
+pub trait MillerLoopResult {
+    /// Target group of the pairing.
+    type Gt: Group;
+
+    /// Perform the final exponentiation, completing the pairing computation.
+    fn final_exponentiation(&self) -> Self::Gt;
+}
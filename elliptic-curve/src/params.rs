@@ -0,0 +1,120 @@
+//! Introspection of elliptic curve domain parameters.
+
+#[cfg(feature = "sec1")]
+mod explicit;
+
+#[cfg(all(feature = "alloc", feature = "sec1"))]
+pub use self::explicit::ToExplicitParameters;
+#[cfg(feature = "sec1")]
+pub use self::explicit::{FromExplicitParameters, SpecifiedCurve};
+
+use crate::{bigint::ArrayEncoding, Curve, FieldBytes};
+
+/// Domain parameters of an elliptic curve in short Weierstrass form:
+///
+/// ```text
+/// y² = x³ + ax + b
+/// ```
+///
+/// This allows tooling which needs the explicit parameters of a curve, such
+/// as key format converters, certificate libraries emitting explicit
+/// `ECParameters`, or debuggers, to be generic over the curve in use.
+///
+/// All field elements are in canonical form, i.e. reduced modulo
+/// [`CurveParams::FIELD_MODULUS`]. The order of the prime order subgroup is
+/// given by [`Curve::ORDER`].
+pub trait CurveParams: Curve {
+    /// Modulus `p` of the base field.
+    const FIELD_MODULUS: Self::Uint;
+
+    /// Coefficient `a` of the curve equation.
+    const EQUATION_A: Self::Uint;
+
+    /// Coefficient `b` of the curve equation.
+    const EQUATION_B: Self::Uint;
+
+    /// Affine `x` coordinate of the generator of the prime order subgroup.
+    const GENERATOR_X: Self::Uint;
+
+    /// Affine `y` coordinate of the generator of the prime order subgroup.
+    const GENERATOR_Y: Self::Uint;
+
+    /// Cofactor `h`, i.e. the number of points on the curve divided by the
+    /// order of the prime order subgroup.
+    const COFACTOR: u64;
+
+    /// Is the group of points on the curve of prime order, i.e. is the
+    /// cofactor 1?
+    fn is_prime_order() -> bool {
+        Self::COFACTOR == 1
+    }
+
+    /// Serialize the base field modulus as big endian bytes.
+    fn field_modulus_bytes() -> FieldBytes<Self> {
+        Self::FIELD_MODULUS.to_be_byte_array()
+    }
+
+    /// Serialize the order of the prime order subgroup as big endian bytes.
+    fn order_bytes() -> FieldBytes<Self> {
+        Self::ORDER.to_be_byte_array()
+    }
+
+    /// Serialize the coefficient `a` as big endian bytes.
+    fn equation_a_bytes() -> FieldBytes<Self> {
+        Self::EQUATION_A.to_be_byte_array()
+    }
+
+    /// Serialize the coefficient `b` as big endian bytes.
+    fn equation_b_bytes() -> FieldBytes<Self> {
+        Self::EQUATION_B.to_be_byte_array()
+    }
+
+    /// Serialize the affine coordinates of the generator as big endian bytes,
+    /// returning `(x, y)`.
+    fn generator_bytes() -> (FieldBytes<Self>, FieldBytes<Self>) {
+        (
+            Self::GENERATOR_X.to_be_byte_array(),
+            Self::GENERATOR_Y.to_be_byte_array(),
+        )
+    }
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::CurveParams;
+    use crate::{
+        bigint::modular::runtime_mod::{DynResidue, DynResidueParams},
+        dev::MockCurve,
+    };
+    use hex_literal::hex;
+
+    #[test]
+    fn generator_is_on_curve() {
+        let params = DynResidueParams::new(&MockCurve::FIELD_MODULUS);
+        let a = DynResidue::new(&MockCurve::EQUATION_A, params);
+        let b = DynResidue::new(&MockCurve::EQUATION_B, params);
+        let x = DynResidue::new(&MockCurve::GENERATOR_X, params);
+        let y = DynResidue::new(&MockCurve::GENERATOR_Y, params);
+
+        assert_eq!((y * y).retrieve(), (x * x * x + a * x + b).retrieve());
+    }
+
+    #[test]
+    fn serialize_params() {
+        assert!(MockCurve::is_prime_order());
+        assert_eq!(
+            MockCurve::order_bytes().as_slice(),
+            hex!("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551")
+        );
+
+        let (x, y) = MockCurve::generator_bytes();
+        assert_eq!(
+            x.as_slice(),
+            hex!("6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296")
+        );
+        assert_eq!(
+            y.as_slice(),
+            hex!("4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5")
+        );
+    }
+}
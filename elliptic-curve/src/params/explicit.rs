@@ -0,0 +1,381 @@
+//! Explicit elliptic curve parameters, i.e. the `specifiedCurve` choice of
+//! `ECParameters` as described in [RFC 3279 § 2.3.5].
+//!
+//! [RFC 3279 § 2.3.5]: https://www.rfc-editor.org/rfc/rfc3279#section-2.3.5
+
+use super::CurveParams;
+use sec1::der::{
+    self,
+    asn1::{BitStringRef, ObjectIdentifier, OctetStringRef, UIntRef},
+    Decode, DecodeValue, Encode, Header, Reader, Sequence, Tag,
+};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// Object identifier for the `prime-field` field type.
+const PRIME_FIELD: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.1.1");
+
+/// `ecpVer1`, the only version this crate encodes.
+const VERSION: u8 = 1;
+
+/// Highest version accepted when decoding, as defined in SEC 1 v2 for curves
+/// whose parameters were generated verifiably at random.
+const MAX_VERSION: u8 = 3;
+
+/// SEC1 tag of an uncompressed point.
+const UNCOMPRESSED_TAG: u8 = 0x04;
+
+/// Explicit elliptic curve parameters over a prime field.
+///
+/// ```text
+/// ECParameters ::= SEQUENCE {
+///    version   ECPVer,          -- version is always 1
+///    fieldID   FieldID,         -- identifies the finite field over
+///                               -- which the curve is defined
+///    curve     Curve,           -- coefficients a and b of the
+///                               -- elliptic curve
+///    base      ECPoint,         -- specifies the base point P
+///                               -- on the elliptic curve
+///    order     INTEGER,         -- the order n of the base point
+///    cofactor  INTEGER OPTIONAL -- The integer h = #E(Fq)/n
+///    }
+///
+/// FieldID ::= SEQUENCE {
+///    fieldType   OBJECT IDENTIFIER,
+///    parameters  ANY DEFINED BY fieldType }
+///
+/// Curve ::= SEQUENCE {
+///    a         FieldElement,
+///    b         FieldElement,
+///    seed      BIT STRING OPTIONAL }
+/// ```
+///
+/// Only prime fields are supported. Integers are represented as big endian
+/// bytes with leading zeroes stripped.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct SpecifiedCurve<'a> {
+    /// Modulus `p` of the base field.
+    pub field_modulus: &'a [u8],
+
+    /// Coefficient `a` of the curve equation.
+    pub a: &'a [u8],
+
+    /// Coefficient `b` of the curve equation.
+    pub b: &'a [u8],
+
+    /// Seed from which the coefficients were generated, if any.
+    pub seed: Option<BitStringRef<'a>>,
+
+    /// SEC1 encoding of the base point.
+    pub base: &'a [u8],
+
+    /// Order `n` of the base point.
+    pub order: &'a [u8],
+
+    /// Cofactor `h`, if present.
+    pub cofactor: Option<&'a [u8]>,
+}
+
+impl<'a> DecodeValue<'a> for SpecifiedCurve<'a> {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> der::Result<Self> {
+        reader.read_nested(header.length, |reader| {
+            let version = u8::decode(reader)?;
+            if !(VERSION..=MAX_VERSION).contains(&version) {
+                return Err(Tag::Integer.value_error());
+            }
+
+            let field_id = FieldId::decode(reader)?;
+            let coefficients = Coefficients::decode(reader)?;
+
+            Ok(Self {
+                field_modulus: field_id.field_modulus.as_bytes(),
+                a: coefficients.a.as_bytes(),
+                b: coefficients.b.as_bytes(),
+                seed: coefficients.seed,
+                base: OctetStringRef::decode(reader)?.as_bytes(),
+                order: UIntRef::decode(reader)?.as_bytes(),
+                cofactor: Option::<UIntRef<'_>>::decode(reader)?.map(|h| h.as_bytes()),
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for SpecifiedCurve<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        f(&[
+            &VERSION,
+            &FieldId {
+                field_modulus: UIntRef::new(self.field_modulus)?,
+            },
+            &Coefficients {
+                a: OctetStringRef::new(self.a)?,
+                b: OctetStringRef::new(self.b)?,
+                seed: self.seed,
+            },
+            &OctetStringRef::new(self.base)?,
+            &UIntRef::new(self.order)?,
+            &self.cofactor.map(UIntRef::new).transpose()?,
+        ])
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for SpecifiedCurve<'a> {
+    type Error = der::Error;
+
+    fn try_from(bytes: &'a [u8]) -> der::Result<Self> {
+        Self::from_der(bytes)
+    }
+}
+
+/// `FieldID` of a prime field.
+struct FieldId<'a> {
+    field_modulus: UIntRef<'a>,
+}
+
+impl<'a> DecodeValue<'a> for FieldId<'a> {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> der::Result<Self> {
+        reader.read_nested(header.length, |reader| {
+            if ObjectIdentifier::decode(reader)? != PRIME_FIELD {
+                return Err(Tag::ObjectIdentifier.value_error());
+            }
+
+            Ok(Self {
+                field_modulus: UIntRef::decode(reader)?,
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for FieldId<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        f(&[&PRIME_FIELD, &self.field_modulus])
+    }
+}
+
+/// `Curve`, i.e. the coefficients of the curve equation.
+struct Coefficients<'a> {
+    a: OctetStringRef<'a>,
+    b: OctetStringRef<'a>,
+    seed: Option<BitStringRef<'a>>,
+}
+
+impl<'a> DecodeValue<'a> for Coefficients<'a> {
+    fn decode_value<R: Reader<'a>>(reader: &mut R, header: Header) -> der::Result<Self> {
+        reader.read_nested(header.length, |reader| {
+            Ok(Self {
+                a: OctetStringRef::decode(reader)?,
+                b: OctetStringRef::decode(reader)?,
+                seed: reader.decode()?,
+            })
+        })
+    }
+}
+
+impl<'a> Sequence<'a> for Coefficients<'a> {
+    fn fields<F, T>(&self, f: F) -> der::Result<T>
+    where
+        F: FnOnce(&[&dyn Encode]) -> der::Result<T>,
+    {
+        f(&[&self.a, &self.b, &self.seed])
+    }
+}
+
+/// Encode the parameters of a curve as a DER `specifiedCurve`.
+///
+/// This is blanket impl'd for all curves which impl [`CurveParams`]. The
+/// base point is encoded in uncompressed form, and no seed is included.
+#[cfg(feature = "alloc")]
+pub trait ToExplicitParameters: CurveParams {
+    /// Serialize the parameters of this curve as ASN.1 DER.
+    fn to_explicit_parameters_der() -> der::Result<Vec<u8>> {
+        let p = Self::field_modulus_bytes();
+        let len = strip_leading_zeroes(&p).len();
+        let a = Self::equation_a_bytes();
+        let b = Self::equation_b_bytes();
+        let (x, y) = Self::generator_bytes();
+        let order = Self::order_bytes();
+        let cofactor = Self::COFACTOR.to_be_bytes();
+
+        let mut base = Vec::with_capacity(1 + 2 * len);
+        base.push(UNCOMPRESSED_TAG);
+        base.extend_from_slice(field_element(&x, len)?);
+        base.extend_from_slice(field_element(&y, len)?);
+
+        SpecifiedCurve {
+            field_modulus: &p,
+            a: field_element(&a, len)?,
+            b: field_element(&b, len)?,
+            seed: None,
+            base: &base,
+            order: &order,
+            cofactor: Some(&cofactor),
+        }
+        .to_vec()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<C: CurveParams> ToExplicitParameters for C {}
+
+/// Decode a DER `specifiedCurve`, checking that it matches the parameters of
+/// a particular curve.
+///
+/// This allows accepting keys from legacy PKI and HSMs which identify the
+/// curve by its explicit parameters rather than a named curve OID. It's
+/// blanket impl'd for all curves which impl [`CurveParams`].
+pub trait FromExplicitParameters: CurveParams {
+    /// Check that the given explicit parameters are those of this curve.
+    ///
+    /// The base point may be either compressed or uncompressed, and the
+    /// cofactor and seed are optional.
+    fn from_explicit_parameters(params: &SpecifiedCurve<'_>) -> der::Result<Self> {
+        let p = Self::field_modulus_bytes();
+        let a = Self::equation_a_bytes();
+        let b = Self::equation_b_bytes();
+        let (x, y) = Self::generator_bytes();
+        let order = Self::order_bytes();
+        let len = strip_leading_zeroes(&p).len();
+
+        if params.field_modulus != strip_leading_zeroes(&p) {
+            return Err(Tag::Integer.value_error());
+        }
+
+        if strip_leading_zeroes(params.a) != strip_leading_zeroes(&a)
+            || strip_leading_zeroes(params.b) != strip_leading_zeroes(&b)
+        {
+            return Err(Tag::OctetString.value_error());
+        }
+
+        let x = field_element(&x, len)?;
+        let y = field_element(&y, len)?;
+        let base_matches = match params.base.split_first() {
+            Some((&UNCOMPRESSED_TAG, coordinates)) => {
+                coordinates.len() == 2 * len && coordinates.split_at(len) == (x, y)
+            }
+            Some((&tag @ (0x02 | 0x03), coordinate)) => {
+                coordinate.len() == len && coordinate == x && (tag & 1) == (y[len - 1] & 1)
+            }
+            _ => false,
+        };
+
+        if !base_matches {
+            return Err(Tag::OctetString.value_error());
+        }
+
+        if params.order != strip_leading_zeroes(&order) {
+            return Err(Tag::Integer.value_error());
+        }
+
+        if let Some(cofactor) = params.cofactor {
+            if cofactor != strip_leading_zeroes(&Self::COFACTOR.to_be_bytes()) {
+                return Err(Tag::Integer.value_error());
+            }
+        }
+
+        Ok(Self::default())
+    }
+
+    /// Deserialize explicit parameters from ASN.1 DER, checking that they're
+    /// the parameters of this curve.
+    fn from_explicit_parameters_der(bytes: &[u8]) -> der::Result<Self> {
+        Self::from_explicit_parameters(&SpecifiedCurve::from_der(bytes)?)
+    }
+}
+
+impl<C: CurveParams> FromExplicitParameters for C {}
+
+/// Strip the leading zeroes of a big endian integer.
+fn strip_leading_zeroes(bytes: &[u8]) -> &[u8] {
+    let zeroes = bytes.iter().take_while(|&&b| b == 0).count();
+    &bytes[zeroes..]
+}
+
+/// Get the `len` least significant bytes of a big endian field element.
+fn field_element(bytes: &[u8], len: usize) -> der::Result<&[u8]> {
+    bytes
+        .len()
+        .checked_sub(len)
+        .map(|offset| &bytes[offset..])
+        .ok_or_else(|| Tag::OctetString.value_error())
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::{FromExplicitParameters, SpecifiedCurve};
+    use crate::dev::MockCurve;
+    use hex_literal::hex;
+    use sec1::der::Decode;
+
+    /// Explicit parameters of P-256 as emitted by OpenSSL, including the seed.
+    const P256_EXPLICIT: &[u8] = &hex!(
+        "3081f7020101302c06072a8648ce3d0101022100ffffffff00000001000000000000"
+        "000000000000ffffffffffffffffffffffff305b0420ffffffff0000000100000000"
+        "0000000000000000fffffffffffffffffffffffc04205ac635d8aa3a93e7b3ebbd55"
+        "769886bc651d06b0cc53b0f63bce3c3e27d2604b031500c49d360886e704936a6678"
+        "e1139d26b7819f7e900441046b17d1f2e12c4247f8bce6e563a440f277037d812deb"
+        "33a0f4a13945d898c2964fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ece"
+        "cbb6406837bf51f5022100ffffffff00000000ffffffffffffffffbce6faada7179e"
+        "84f3b9cac2fc632551020101"
+    );
+
+    #[test]
+    fn decode_openssl() {
+        let params = SpecifiedCurve::from_der(P256_EXPLICIT).unwrap();
+        assert!(params.seed.is_some());
+        assert_eq!(params.cofactor, Some(&[1u8][..]));
+        MockCurve::from_explicit_parameters(&params).unwrap();
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn round_trip() {
+        use super::ToExplicitParameters;
+
+        let der = MockCurve::to_explicit_parameters_der().unwrap();
+        let params = SpecifiedCurve::from_der(&der).unwrap();
+        assert_eq!(params.seed, None);
+        MockCurve::from_explicit_parameters(&params).unwrap();
+    }
+
+    #[test]
+    fn reject_mismatch() {
+        let mut params = SpecifiedCurve::from_der(P256_EXPLICIT).unwrap();
+        let b = hex!("5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604c");
+        params.b = &b;
+        assert!(MockCurve::from_explicit_parameters(&params).is_err());
+    }
+
+    #[test]
+    fn reject_malformed_base() {
+        let params = SpecifiedCurve::from_der(P256_EXPLICIT).unwrap();
+        let base = params.base;
+
+        for malformed in [
+            &[][..],
+            &[0x04],
+            &base[..33],
+            &base[..base.len() - 1],
+            &[0x02],
+            &[&[0x02], &base[1..33]].concat(),
+            &[&[0x02], &base[1..32]].concat(),
+            &[base, &[0x00]].concat(),
+        ] {
+            let mut params = params;
+            params.base = malformed;
+            assert!(MockCurve::from_explicit_parameters(&params).is_err());
+        }
+
+        // P-256's generator has an odd y-coordinate
+        let mut params = params;
+        let compressed = [&[0x03], &base[1..33]].concat();
+        params.base = &compressed;
+        MockCurve::from_explicit_parameters(&params).unwrap();
+    }
+}
@@ -0,0 +1,167 @@
+//! Pedersen commitments.
+//!
+//! A Pedersen commitment to a value 𝑣 with blinding factor 𝑟 is the point
+//! 𝑪 = 𝑮 × 𝑣 + 𝑯 × 𝑟, where 𝑮 and 𝑯 are generators whose discrete logarithm
+//! relative to each other is unknown. Commitments are perfectly hiding,
+//! computationally binding, and additively homomorphic: the sum of two
+//! commitments is a commitment to the sum of their values and blindings.
+//!
+//! The second generator 𝑯 is typically derived by hashing to the curve, which
+//! is supported by [`PedersenGenerators::hash_to_curve`] when the
+//! `hash2curve` feature is enabled.
+
+use crate::{CurveArithmetic, ProjectivePoint, Scalar};
+use subtle::{Choice, ConstantTimeEq};
+
+#[cfg(feature = "hash2curve")]
+use {
+    crate::{
+        hash2curve::{ExpandMsg, GroupDigest},
+        Result,
+    },
+    group::{cofactor::CofactorGroup, Group},
+};
+
+/// Commitment scheme which binds to a value using a blinding factor.
+pub trait Commitment {
+    /// Type of the values being committed to.
+    type Value;
+
+    /// Type of the blinding factor used to hide the value.
+    type Blinding;
+
+    /// Type of the commitment.
+    type Output: ConstantTimeEq;
+
+    /// Commit to the given value using the given blinding factor.
+    ///
+    /// The blinding factor must be uniformly random and kept secret until
+    /// the commitment is opened, or the commitment won't be hiding.
+    fn commit(&self, value: &Self::Value, blinding: &Self::Blinding) -> Self::Output;
+
+    /// Verify that `commitment` opens to the given value and blinding factor
+    /// in constant time.
+    fn verify(
+        &self,
+        commitment: &Self::Output,
+        value: &Self::Value,
+        blinding: &Self::Blinding,
+    ) -> Choice {
+        self.commit(value, blinding).ct_eq(commitment)
+    }
+}
+
+/// Generators 𝑮 and 𝑯 for Pedersen commitments.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PedersenGenerators<C>
+where
+    C: CurveArithmetic,
+{
+    /// Generator which the value is multiplied by.
+    g: ProjectivePoint<C>,
+
+    /// Generator which the blinding factor is multiplied by.
+    h: ProjectivePoint<C>,
+}
+
+impl<C> PedersenGenerators<C>
+where
+    C: CurveArithmetic,
+{
+    /// Create Pedersen generators from the given points.
+    ///
+    /// The discrete logarithm of `h` with respect to `g` must be unknown,
+    /// otherwise commitments aren't binding.
+    pub fn new(g: ProjectivePoint<C>, h: ProjectivePoint<C>) -> Self {
+        Self { g, h }
+    }
+
+    /// Create Pedersen generators using the curve's standard generator as 𝑮
+    /// and deriving 𝑯 by hashing the given messages to the curve with the
+    /// given domain separation tag.
+    ///
+    /// As 𝑯 is the output of a hash function, no one knows its discrete
+    /// logarithm.
+    #[cfg(feature = "hash2curve")]
+    pub fn hash_to_curve<'a, X>(msgs: &[&[u8]], dst: &'a [u8]) -> Result<Self>
+    where
+        C: GroupDigest,
+        ProjectivePoint<C>: CofactorGroup,
+        X: ExpandMsg<'a>,
+    {
+        let h = C::hash_from_bytes::<X>(msgs, dst)?;
+        Ok(Self::new(ProjectivePoint::<C>::generator(), h))
+    }
+
+    /// Generator which the value is multiplied by.
+    pub fn g(&self) -> &ProjectivePoint<C> {
+        &self.g
+    }
+
+    /// Generator which the blinding factor is multiplied by.
+    pub fn h(&self) -> &ProjectivePoint<C> {
+        &self.h
+    }
+}
+
+impl<C> Commitment for PedersenGenerators<C>
+where
+    C: CurveArithmetic,
+{
+    type Value = Scalar<C>;
+    type Blinding = Scalar<C>;
+    type Output = ProjectivePoint<C>;
+
+    fn commit(&self, value: &Scalar<C>, blinding: &Scalar<C>) -> ProjectivePoint<C> {
+        self.g * value + self.h * blinding
+    }
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::{Commitment, PedersenGenerators};
+    use crate::dev::{MockCurve, ProjectivePoint, Scalar};
+    use group::Group;
+
+    fn generators() -> PedersenGenerators<MockCurve> {
+        let h = ProjectivePoint::generator() * Scalar::from(12345u64);
+        PedersenGenerators::new(ProjectivePoint::generator(), h)
+    }
+
+    #[test]
+    fn commit_and_verify() {
+        let generators = generators();
+        let value = Scalar::from(42u64);
+        let blinding = Scalar::from(7u64);
+        let commitment = generators.commit(&value, &blinding);
+
+        assert!(bool::from(generators.verify(
+            &commitment,
+            &value,
+            &blinding
+        )));
+        assert!(!bool::from(generators.verify(
+            &commitment,
+            &Scalar::from(43u64),
+            &blinding
+        )));
+        assert!(!bool::from(generators.verify(
+            &commitment,
+            &value,
+            &Scalar::from(8u64)
+        )));
+    }
+
+    #[test]
+    fn homomorphic() {
+        let generators = generators();
+        let a = generators.commit(&Scalar::from(1u64), &Scalar::from(2u64));
+        let b = generators.commit(&Scalar::from(3u64), &Scalar::from(4u64));
+
+        assert!(bool::from(generators.verify(
+            &(a + b),
+            &Scalar::from(4u64),
+            &Scalar::from(6u64)
+        )));
+    }
+}
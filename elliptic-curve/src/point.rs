@@ -1,13 +1,35 @@
 //! Traits for elliptic curve points.
 
+#[cfg(feature = "arithmetic")]
+mod blinded;
+mod elligator;
+mod encoding;
 #[cfg(feature = "arithmetic")]
 mod non_identity;
+#[cfg(all(feature = "alloc", feature = "arithmetic"))]
+mod table;
+#[cfg(feature = "arithmetic")]
+mod validation;
+
+#[cfg(feature = "arithmetic")]
+pub use self::blinded::BlindedPoint;
+pub use self::elligator::{ElligatorDecode, ElligatorEncode};
+pub use self::encoding::{
+    Compact, PointEncoding, Rfc8032, Ristretto, Sec1Compressed, Sec1Uncompressed, XOnly,
+};
 
 #[cfg(feature = "arithmetic")]
 pub use self::non_identity::NonIdentity;
+#[cfg(all(feature = "alloc", feature = "arithmetic"))]
+pub use self::table::FixedBaseTable;
+#[cfg(feature = "arithmetic")]
+pub use self::validation::{ValidatePoint, ValidationPolicy};
 
-use crate::{Curve, FieldBytes};
-use subtle::{Choice, CtOption};
+use crate::{
+    bigint::{ArrayEncoding, CheckedAdd},
+    Curve, FieldBytes, RecoveryId,
+};
+use subtle::{Choice, ConditionallySelectable, CtOption};
 
 /// Obtain the affine x-coordinate of an elliptic curve point.
 pub trait AffineXCoordinate {
@@ -18,12 +40,96 @@ pub trait AffineXCoordinate {
     fn x(&self) -> Self::FieldRepr;
 }
 
+/// Obtain the affine y-coordinate of an elliptic curve point.
+pub trait AffineYCoordinate {
+    /// Field element representation.
+    type FieldRepr: AsRef<[u8]>;
+
+    /// Get the affine y-coordinate as a serialized field element.
+    fn y(&self) -> Self::FieldRepr;
+}
+
+/// Obtain both affine coordinates of an elliptic curve point.
+///
+/// This trait is blanket impl'd for all types which impl both
+/// [`AffineXCoordinate`] and [`AffineYCoordinate`] with the same field
+/// element representation.
+pub trait AffineCoordinates:
+    AffineXCoordinate + AffineYCoordinate<FieldRepr = <Self as AffineXCoordinate>::FieldRepr>
+{
+    /// Get the affine `(x, y)` coordinates as serialized field elements.
+    fn coordinates(
+        &self,
+    ) -> (
+        <Self as AffineXCoordinate>::FieldRepr,
+        <Self as AffineXCoordinate>::FieldRepr,
+    ) {
+        (self.x(), self.y())
+    }
+}
+
+impl<P> AffineCoordinates for P where
+    P: AffineXCoordinate + AffineYCoordinate<FieldRepr = <P as AffineXCoordinate>::FieldRepr>
+{
+}
+
 /// Is the affine y-coordinate of this elliptic curve point odd?
 pub trait AffineYIsOdd {
     /// Is the affine y-coordinate odd?
     fn y_is_odd(&self) -> Choice;
 }
 
+/// Normalize point(s) in projective representation by converting them to their
+/// affine ones.
+///
+/// Implementations are expected to use a single batched inversion (see
+/// [`BatchInvert`][`crate::ops::BatchInvert`]) to amortize the cost of the
+/// conversion across all of the points.
+///
+/// No default implementation is provided, as the output container depends on
+/// `Points` (e.g. an array for `[P; N]`, or a `Vec` for `[P]`) and associated
+/// type defaults aren't available on stable Rust. Implementations can instead
+/// allocate the container and fill it with [`group::Curve::batch_normalize`],
+/// which curve crates should override with a batched inversion (Montgomery's
+/// trick) as its default converts each point individually:
+///
+/// ```ignore
+/// impl<const N: usize> BatchNormalize<[ProjectivePoint; N]> for ProjectivePoint {
+///     type Output = [AffinePoint; N];
+///
+///     fn batch_normalize(points: &[ProjectivePoint; N]) -> [AffinePoint; N] {
+///         let mut ret = [AffinePoint::IDENTITY; N];
+///         group::Curve::batch_normalize(points, &mut ret);
+///         ret
+///     }
+/// }
+/// ```
+#[cfg(feature = "arithmetic")]
+pub trait BatchNormalize<Points: ?Sized>: group::Curve {
+    /// The output of the batch normalization; a container of affine points.
+    type Output: AsRef<[Self::AffineRepr]>;
+
+    /// Perform a batched conversion to affine representation on a sequence of
+    /// projective points at an amortized cost that should be practically as
+    /// efficient as a single conversion.
+    fn batch_normalize(points: &Points) -> <Self as BatchNormalize<Points>>::Output;
+}
+
+/// Clear the cofactor of a group element, mapping it into the prime-order
+/// subgroup.
+///
+/// The default implementation returns the element unchanged, which is only
+/// correct for prime-order curves (i.e. cofactor 1), where this trait can be
+/// impl'd with an empty body. Curves with a cofactor greater than 1 must
+/// override it, e.g. by multiplying by the cofactor.
+#[cfg(feature = "arithmetic")]
+pub trait ClearCofactor: group::Group {
+    /// Multiply this element by the curve's cofactor.
+    fn clear_cofactor(&self) -> Self {
+        *self
+    }
+}
+
 /// Decompress an elliptic curve point.
 ///
 /// Point decompression recovers an original curve point from its x-coordinate
@@ -33,6 +139,65 @@ pub trait DecompressPoint<C: Curve>: Sized {
     fn decompress(x: &FieldBytes<C>, y_is_odd: Choice) -> CtOption<Self>;
 }
 
+/// Convention used to distinguish between the two points which share an
+/// x-coordinate.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum SignConvention {
+    /// The sign flag indicates the y-coordinate is odd, as in SEC1 point
+    /// compression.
+    Parity,
+
+    /// The sign flag indicates the y-coordinate is the lexicographically
+    /// larger of `y` and `-y`, i.e. `y > (p - 1) / 2`.
+    Lexicographic,
+}
+
+/// Decompress an elliptic curve point using an explicit [`SignConvention`].
+///
+/// This generalizes [`DecompressPoint`], which only supports the "y is odd"
+/// flag used by SEC1.
+pub trait DecompressPointSign<C: Curve>: Sized {
+    /// Attempt to decompress an elliptic curve point, selecting the
+    /// y-coordinate whose sign under the given `convention` matches `sign`.
+    fn decompress_with_sign(
+        x: &FieldBytes<C>,
+        sign: Choice,
+        convention: SignConvention,
+    ) -> CtOption<Self>;
+}
+
+/// Recover an elliptic curve point from the `r` component of a signature and
+/// a recovery ID, as used by ECDSA public key recovery.
+///
+/// When [`RecoveryId::is_x_reduced`] is set, the x-coordinate of the point is
+/// `r + n` where `n` is the curve order.
+///
+/// This trait is blanket impl'd for all types which impl [`DecompressPoint`],
+/// [`ConditionallySelectable`] and [`Default`], such as affine points.
+pub trait RecoverPoint<C: Curve>: Sized {
+    /// Attempt to recover an elliptic curve point.
+    fn recover_point(r: &FieldBytes<C>, recovery_id: RecoveryId) -> CtOption<Self>;
+}
+
+impl<C, P> RecoverPoint<C> for P
+where
+    C: Curve,
+    P: ConditionallySelectable + Default + DecompressPoint<C>,
+{
+    fn recover_point(r: &FieldBytes<C>, recovery_id: RecoveryId) -> CtOption<Self> {
+        let y_is_odd = Choice::from(u8::from(recovery_id.is_y_odd()));
+        let is_x_reduced = Choice::from(u8::from(recovery_id.is_x_reduced()));
+
+        let r = C::Uint::from_be_byte_array(r.clone());
+        let r_plus_n = r.checked_add(&C::ORDER);
+        let x = C::Uint::conditional_select(&r, &r_plus_n.unwrap_or(r), is_x_reduced);
+        let is_valid = !is_x_reduced | r_plus_n.is_some();
+
+        Self::decompress(&x.to_be_byte_array(), y_is_odd)
+            .and_then(|point| CtOption::new(point, is_valid))
+    }
+}
+
 /// Decompact an elliptic curve point from an x-coordinate.
 ///
 /// Decompaction relies on properties of specially-generated keys but provides
@@ -42,6 +207,21 @@ pub trait DecompactPoint<C: Curve>: Sized {
     fn decompact(x: &FieldBytes<C>) -> CtOption<Self>;
 }
 
+/// Check whether a group element lies in the prime-order subgroup.
+///
+/// The default implementation always returns true, which is only correct for
+/// prime-order curves (i.e. cofactor 1), where this trait can be impl'd with
+/// an empty body. Curves with a cofactor greater than 1 must override it, e.g.
+/// by checking that multiplying by the subgroup order yields the identity.
+#[cfg(feature = "arithmetic")]
+pub trait IsTorsionFree: group::Group {
+    /// Is this element free of any component in the small-order torsion
+    /// subgroup?
+    fn is_torsion_free(&self) -> Choice {
+        Choice::from(1)
+    }
+}
+
 /// Point compression settings.
 pub trait PointCompression {
     /// Should point compression be applied by default?
@@ -53,3 +233,55 @@ pub trait PointCompaction {
     /// Should point compaction be applied by default?
     const COMPACT_POINTS: bool;
 }
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::{ClearCofactor, IsTorsionFree, RecoverPoint};
+    use crate::{
+        dev::{AffinePoint, FieldBytes, ProjectivePoint},
+        sec1::ToEncodedPoint,
+        RecoveryId,
+    };
+    use hex_literal::hex;
+
+    #[test]
+    fn recover_point() {
+        let r = FieldBytes::from(hex!(
+            "1111111111111111111111111111111111111111111111111111111111111111"
+        ));
+
+        let point = AffinePoint::recover_point(&r, RecoveryId::new(true, false)).unwrap();
+        assert_eq!(
+            point.to_encoded_point(true).as_bytes(),
+            hex!("031111111111111111111111111111111111111111111111111111111111111111")
+        );
+
+        // x = r + n
+        let r = FieldBytes::from(hex!(
+            "0000000000000000000000000000000000000000000000000000000000000001"
+        ));
+        let point = AffinePoint::recover_point(&r, RecoveryId::new(false, true)).unwrap();
+        assert_eq!(
+            point.to_encoded_point(true).as_bytes(),
+            hex!("02ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632552")
+        );
+    }
+
+    #[test]
+    fn recover_point_overflow() {
+        let r = FieldBytes::from([0xFF; 32]);
+        assert!(bool::from(
+            AffinePoint::recover_point(&r, RecoveryId::new(false, false)).is_some()
+        ));
+        assert!(bool::from(
+            AffinePoint::recover_point(&r, RecoveryId::new(false, true)).is_none()
+        ));
+    }
+
+    #[test]
+    fn prime_order_defaults() {
+        let point = ProjectivePoint::Identity;
+        assert_eq!(point.clear_cofactor(), point);
+        assert!(bool::from(point.is_torsion_free()));
+    }
+}
@@ -0,0 +1,123 @@
+//! Random blinding support for elliptic curve points.
+
+use crate::{CurveArithmetic, NonZeroScalar, ProjectivePoint, Scalar};
+use core::ops::Mul;
+use group::Group;
+use rand_core::CryptoRngCore;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Point blinded by adding a randomly generated mask to it.
+///
+/// Multiplying a [`BlindedPoint`] by a scalar `k` computes `k * (P + R)`
+/// and `k * R` for a random multiple `R` of the generator, so that the
+/// point which the scalar multiplication actually operates on is unknown
+/// to an attacker observing side channels. The product `k * P` is then
+/// recovered with [`BlindedPoint::unblind`].
+///
+/// This additive masking works with any curve backend, at the cost of
+/// roughly doubling the cost of a scalar multiplication, which may be
+/// worthwhile for e.g. smartcards and other embedded targets.
+#[derive(Clone)]
+pub struct BlindedPoint<C>
+where
+    C: CurveArithmetic,
+{
+    /// Sum of the point and the mask.
+    blinded: ProjectivePoint<C>,
+
+    /// Mask value.
+    mask: ProjectivePoint<C>,
+}
+
+impl<C> BlindedPoint<C>
+where
+    C: CurveArithmetic,
+{
+    /// Blind the given point with a mask generated from a [`CryptoRngCore`].
+    ///
+    /// The mask is computed as a [`NonZeroScalar`] multiple of the
+    /// generator.
+    pub fn blind(point: &ProjectivePoint<C>, rng: &mut impl CryptoRngCore) -> Self {
+        let mask = ProjectivePoint::<C>::generator() * *NonZeroScalar::<C>::random(rng);
+
+        Self {
+            blinded: *point + mask,
+            mask,
+        }
+    }
+
+    /// Remove the mask, returning the (possibly multiplied) point.
+    pub fn unblind(&self) -> ProjectivePoint<C> {
+        self.blinded - self.mask
+    }
+}
+
+impl<C> Mul<&Scalar<C>> for &BlindedPoint<C>
+where
+    C: CurveArithmetic,
+{
+    type Output = BlindedPoint<C>;
+
+    fn mul(self, scalar: &Scalar<C>) -> BlindedPoint<C> {
+        BlindedPoint {
+            blinded: self.blinded * scalar,
+            mask: self.mask * scalar,
+        }
+    }
+}
+
+impl<C> Mul<&Scalar<C>> for BlindedPoint<C>
+where
+    C: CurveArithmetic,
+{
+    type Output = BlindedPoint<C>;
+
+    fn mul(self, scalar: &Scalar<C>) -> BlindedPoint<C> {
+        &self * scalar
+    }
+}
+
+impl<C> Zeroize for BlindedPoint<C>
+where
+    C: CurveArithmetic,
+{
+    fn zeroize(&mut self) {
+        self.blinded.zeroize();
+        self.mask.zeroize();
+    }
+}
+
+impl<C> Drop for BlindedPoint<C>
+where
+    C: CurveArithmetic,
+{
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<C> ZeroizeOnDrop for BlindedPoint<C> where C: CurveArithmetic {}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::BlindedPoint;
+    use crate::dev::{MockCurve, ProjectivePoint, Scalar};
+    use group::Group;
+    use rand_core::OsRng;
+
+    #[test]
+    fn blind_unblind() {
+        let point = ProjectivePoint::generator() * Scalar::from(42u64);
+        let blinded = BlindedPoint::<MockCurve>::blind(&point, &mut OsRng);
+        assert_eq!(blinded.unblind(), point);
+    }
+
+    #[test]
+    fn blinded_mul() {
+        let point = ProjectivePoint::generator() * Scalar::from(42u64);
+        let k = Scalar::from(7u64);
+
+        let product = BlindedPoint::<MockCurve>::blind(&point, &mut OsRng) * &k;
+        assert_eq!(product.unblind(), point * k);
+    }
+}
@@ -0,0 +1,41 @@
+//! Elligator-style maps between points and uniformly random byte strings.
+
+use subtle::CtOption;
+
+/// Encode a point as a "representative": a byte string which is
+/// indistinguishable from uniformly random, as with e.g. [Elligator 2].
+///
+/// This is used by censorship-resistant protocols which must avoid revealing
+/// that curve points are being exchanged at all.
+///
+/// Only some points have a representative (roughly half of them in the case
+/// of Elligator 2), so protocols generally repeatedly generate ephemeral keys
+/// until they obtain one whose public key can be encoded.
+///
+/// [Elligator 2]: https://elligator.cr.yp.to/elligator-20130828.pdf
+pub trait ElligatorEncode {
+    /// Serialized representative.
+    type Representative: AsRef<[u8]>;
+
+    /// Attempt to compute the representative of this point, returning none
+    /// if it doesn't have one.
+    ///
+    /// The `tweak` must be uniformly random: it's used to fill the bits of
+    /// the representative which aren't determined by the point (e.g. the
+    /// unused high bits of a field element), as well as to select between
+    /// multiple representatives of the same point, without which the output
+    /// would be distinguishable from random.
+    fn to_representative(&self, tweak: u8) -> CtOption<Self::Representative>;
+}
+
+/// Decode a point from a representative produced by [`ElligatorEncode`].
+///
+/// Unlike encoding, decoding is a total function: every byte string of the
+/// appropriate length maps to a point.
+pub trait ElligatorDecode: Sized {
+    /// Serialized representative.
+    type Representative;
+
+    /// Map the given representative to a point.
+    fn from_representative(representative: &Self::Representative) -> Self;
+}
@@ -0,0 +1,242 @@
+//! Format-agnostic point serialization.
+
+use crate::{Curve, Error, FieldBytes, FieldSize, Result};
+use core::marker::PhantomData;
+use generic_array::typenum::Unsigned;
+use subtle::Choice;
+
+use super::{AffineXCoordinate, DecompressPoint};
+use crate::edwards::{
+    CompressEdwardsPoint, DecompressEdwardsPoint, EdwardsCurve, EdwardsEncodedPoint,
+};
+
+#[cfg(feature = "sec1")]
+use crate::sec1::{EncodedPoint, FromEncodedPoint, ModulusSize, ToEncodedPoint};
+
+/// Serialize and deserialize elliptic curve points using a particular
+/// encoding `Format`.
+///
+/// This generalizes [`ToEncodedPoint`][`crate::sec1::ToEncodedPoint`] and
+/// [`FromEncodedPoint`][`crate::sec1::FromEncodedPoint`] beyond SEC1, so
+/// curves which aren't in short Weierstrass form (e.g. Edwards or Montgomery
+/// curves) can participate in generic serialization code by implementing this
+/// trait for their own encoding formats.
+///
+/// The format is selected via a marker type, e.g. [`Sec1Compressed`],
+/// [`Sec1Uncompressed`], [`Compact`], [`Ristretto`], [`XOnly`] or
+/// [`Rfc8032`]. Curve implementations may also define their own marker types.
+pub trait PointEncoding<Format>: Sized {
+    /// Serialized representation of a point.
+    type Repr: AsRef<[u8]>;
+
+    /// Serialize this point using the given format.
+    fn to_encoding(&self) -> Self::Repr;
+
+    /// Deserialize a point encoded using the given format.
+    ///
+    /// Returns an error if the input is malformed or doesn't describe a
+    /// point on the curve.
+    fn from_encoding(bytes: &[u8]) -> Result<Self>;
+}
+
+/// SEC1 compressed point format: a tag byte followed by the x-coordinate.
+///
+/// Impl'd for all types which impl both [`FromEncodedPoint`] and
+/// [`ToEncodedPoint`].
+///
+/// [`FromEncodedPoint`]: crate::sec1::FromEncodedPoint
+/// [`ToEncodedPoint`]: crate::sec1::ToEncodedPoint
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Sec1Compressed<C: Curve>(PhantomData<C>);
+
+/// SEC1 uncompressed point format: a tag byte followed by the x- and
+/// y-coordinates.
+///
+/// Impl'd for all types which impl both [`FromEncodedPoint`] and
+/// [`ToEncodedPoint`].
+///
+/// [`FromEncodedPoint`]: crate::sec1::FromEncodedPoint
+/// [`ToEncodedPoint`]: crate::sec1::ToEncodedPoint
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Sec1Uncompressed<C: Curve>(PhantomData<C>);
+
+/// Compact point format, as described in [draft-jivsov-ecc-compact].
+///
+/// Not every point has a compact representation, so this format is left to
+/// curve implementations which can uphold that for the points they encode
+/// (see [`DecompactPoint`][`crate::DecompactPoint`]).
+///
+/// [draft-jivsov-ecc-compact]: https://datatracker.ietf.org/doc/html/draft-jivsov-ecc-compact-05
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Compact<C: Curve>(PhantomData<C>);
+
+/// Ristretto-style encoding of a prime-order group built atop a curve with a
+/// cofactor, e.g. [ristretto255] or decaf448.
+///
+/// Such encodings are specific to each group, so this format is left to the
+/// curve implementations.
+///
+/// [ristretto255]: https://www.rfc-editor.org/rfc/rfc9496.html
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Ristretto<C: Curve>(PhantomData<C>);
+
+/// Raw x-only format: the serialized x-coordinate alone.
+///
+/// Decoding picks the point with an even y-coordinate, as in [BIP340].
+/// Encoding discards the y-coordinate, so a point with an odd y-coordinate
+/// decodes to its negation.
+///
+/// Impl'd for all types which impl both [`AffineXCoordinate`] and
+/// [`DecompressPoint`].
+///
+/// [BIP340]: https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct XOnly<C: Curve>(PhantomData<C>);
+
+/// Edwards point format as specified in [RFC8032]: the y-coordinate along
+/// with the sign of the x-coordinate.
+///
+/// Impl'd for all types which impl both [`CompressEdwardsPoint`] and
+/// [`DecompressEdwardsPoint`].
+///
+/// [RFC8032]: https://www.rfc-editor.org/rfc/rfc8032
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Rfc8032<C: EdwardsCurve>(PhantomData<C>);
+
+#[cfg(feature = "sec1")]
+impl<C, P> PointEncoding<Sec1Compressed<C>> for P
+where
+    C: Curve,
+    FieldSize<C>: ModulusSize,
+    P: FromEncodedPoint<C> + ToEncodedPoint<C>,
+{
+    type Repr = EncodedPoint<C>;
+
+    fn to_encoding(&self) -> EncodedPoint<C> {
+        self.to_encoded_point(true)
+    }
+
+    fn from_encoding(bytes: &[u8]) -> Result<Self> {
+        let point = EncodedPoint::<C>::from_bytes(bytes).map_err(|_| Error)?;
+
+        if point.is_compressed() || point.is_identity() {
+            Option::from(Self::from_encoded_point(&point)).ok_or(Error)
+        } else {
+            Err(Error)
+        }
+    }
+}
+
+#[cfg(feature = "sec1")]
+impl<C, P> PointEncoding<Sec1Uncompressed<C>> for P
+where
+    C: Curve,
+    FieldSize<C>: ModulusSize,
+    P: FromEncodedPoint<C> + ToEncodedPoint<C>,
+{
+    type Repr = EncodedPoint<C>;
+
+    fn to_encoding(&self) -> EncodedPoint<C> {
+        self.to_encoded_point(false)
+    }
+
+    fn from_encoding(bytes: &[u8]) -> Result<Self> {
+        let point = EncodedPoint::<C>::from_bytes(bytes).map_err(|_| Error)?;
+
+        if point.is_compressed() || point.is_compact() {
+            Err(Error)
+        } else {
+            Option::from(Self::from_encoded_point(&point)).ok_or(Error)
+        }
+    }
+}
+
+impl<C, P> PointEncoding<XOnly<C>> for P
+where
+    C: Curve,
+    P: AffineXCoordinate<FieldRepr = FieldBytes<C>> + DecompressPoint<C>,
+{
+    type Repr = FieldBytes<C>;
+
+    fn to_encoding(&self) -> FieldBytes<C> {
+        self.x()
+    }
+
+    fn from_encoding(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != FieldSize::<C>::USIZE {
+            return Err(Error);
+        }
+
+        let x = FieldBytes::<C>::clone_from_slice(bytes);
+        Option::from(Self::decompress(&x, Choice::from(0))).ok_or(Error)
+    }
+}
+
+impl<C, P> PointEncoding<Rfc8032<C>> for P
+where
+    C: EdwardsCurve,
+    P: CompressEdwardsPoint<C> + DecompressEdwardsPoint<C>,
+{
+    type Repr = EdwardsEncodedPoint<C>;
+
+    fn to_encoding(&self) -> EdwardsEncodedPoint<C> {
+        self.compress()
+    }
+
+    fn from_encoding(bytes: &[u8]) -> Result<Self> {
+        if bytes.len() != C::EncodedPointSize::USIZE {
+            return Err(Error);
+        }
+
+        let bytes = EdwardsEncodedPoint::<C>::clone_from_slice(bytes);
+        Option::from(Self::decompress(&bytes)).ok_or(Error)
+    }
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::{PointEncoding, Sec1Compressed, Sec1Uncompressed};
+    use crate::dev::{AffinePoint, MockCurve};
+    use hex_literal::hex;
+
+    const UNCOMPRESSED_POINT: [u8; 65] = hex!("0411111111111111111111111111111111111111111111111111111111111111112222222222222222222222222222222222222222222222222222222222222222");
+    const COMPRESSED_POINT: [u8; 33] =
+        hex!("021111111111111111111111111111111111111111111111111111111111111111");
+
+    #[test]
+    fn sec1_round_trip() {
+        let point = <AffinePoint as PointEncoding<Sec1Uncompressed<MockCurve>>>::from_encoding(
+            &UNCOMPRESSED_POINT,
+        )
+        .unwrap();
+        assert_eq!(
+            PointEncoding::<Sec1Uncompressed<MockCurve>>::to_encoding(&point).as_bytes(),
+            UNCOMPRESSED_POINT
+        );
+
+        let point = <AffinePoint as PointEncoding<Sec1Compressed<MockCurve>>>::from_encoding(
+            &COMPRESSED_POINT,
+        )
+        .unwrap();
+        assert_eq!(
+            PointEncoding::<Sec1Compressed<MockCurve>>::to_encoding(&point).as_bytes(),
+            COMPRESSED_POINT
+        );
+    }
+
+    #[test]
+    fn sec1_rejects_other_format() {
+        assert!(
+            <AffinePoint as PointEncoding<Sec1Uncompressed<MockCurve>>>::from_encoding(
+                &COMPRESSED_POINT
+            )
+            .is_err()
+        );
+        assert!(
+            <AffinePoint as PointEncoding<Sec1Compressed<MockCurve>>>::from_encoding(
+                &UNCOMPRESSED_POINT
+            )
+            .is_err()
+        );
+    }
+}
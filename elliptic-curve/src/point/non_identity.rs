@@ -1,11 +1,19 @@
 //! Non-identity point type.
 
-use core::ops::Deref;
+use core::ops::{Deref, Mul};
 
 use group::{prime::PrimeCurveAffine, Curve, GroupEncoding};
 use rand_core::{CryptoRng, RngCore};
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
+use crate::{CurveArithmetic, NonZeroScalar, PrimeCurve, Scalar};
+
+#[cfg(feature = "sec1")]
+use crate::{
+    sec1::{EncodedPoint, FromEncodedPoint, ModulusSize, ToEncodedPoint},
+    FieldSize,
+};
+
 #[cfg(feature = "serde")]
 use serdect::serde::{de, ser, Deserialize, Serialize};
 
@@ -35,6 +43,14 @@ where
     }
 }
 
+impl<P> NonIdentity<P> {
+    /// Create a [`NonIdentity`] from a point which is already known not to
+    /// be the identity, e.g. the point of a [`PublicKey`][`crate::PublicKey`].
+    pub(crate) fn new_unchecked(point: P) -> Self {
+        Self { point }
+    }
+}
+
 impl<P: Copy> NonIdentity<P> {
     /// Return wrapped point.
     pub fn to_point(self) -> P {
@@ -129,6 +145,60 @@ where
     }
 }
 
+#[cfg(feature = "sec1")]
+impl<C, P> FromEncodedPoint<C> for NonIdentity<P>
+where
+    C: CurveArithmetic,
+    P: ConditionallySelectable + ConstantTimeEq + Default + FromEncodedPoint<C> + GroupEncoding,
+    FieldSize<C>: ModulusSize,
+{
+    /// Decode a [`NonIdentity`] from a SEC1 [`EncodedPoint`], returning
+    /// `None` if it is the identity.
+    fn from_encoded_point(point: &EncodedPoint<C>) -> CtOption<Self> {
+        P::from_encoded_point(point).and_then(Self::new)
+    }
+}
+
+#[cfg(feature = "sec1")]
+impl<C, P> ToEncodedPoint<C> for NonIdentity<P>
+where
+    C: CurveArithmetic,
+    P: ToEncodedPoint<C>,
+    FieldSize<C>: ModulusSize,
+{
+    fn to_encoded_point(&self, compress: bool) -> EncodedPoint<C> {
+        self.point.to_encoded_point(compress)
+    }
+}
+
+impl<C, P> Mul<NonZeroScalar<C>> for NonIdentity<P>
+where
+    C: PrimeCurve + CurveArithmetic,
+    P: Copy + Mul<Scalar<C>, Output = P>,
+{
+    type Output = NonIdentity<P>;
+
+    fn mul(self, scalar: NonZeroScalar<C>) -> Self {
+        &self * &scalar
+    }
+}
+
+impl<C, P> Mul<&NonZeroScalar<C>> for &NonIdentity<P>
+where
+    C: PrimeCurve + CurveArithmetic,
+    P: Copy + Mul<Scalar<C>, Output = P>,
+{
+    type Output = NonIdentity<P>;
+
+    fn mul(self, scalar: &NonZeroScalar<C>) -> NonIdentity<P> {
+        // In a prime order group, multiplying a point which is not the
+        // identity by a non-zero scalar never results in the identity.
+        NonIdentity {
+            point: self.point * *scalar.as_ref(),
+        }
+    }
+}
+
 #[cfg(feature = "serde")]
 impl<P> Serialize for NonIdentity<P>
 where
@@ -159,7 +229,10 @@ where
 #[cfg(all(test, feature = "dev"))]
 mod tests {
     use super::NonIdentity;
-    use crate::dev::{AffinePoint, ProjectivePoint};
+    use crate::{
+        dev::{AffinePoint, EncodedPoint, ProjectivePoint},
+        sec1::{FromEncodedPoint, ToEncodedPoint},
+    };
     use group::GroupEncoding;
     use hex_literal::hex;
 
@@ -197,4 +270,17 @@ mod tests {
         let point = NonIdentity::<AffinePoint>::from_repr(&bytes.into()).unwrap();
         assert_eq!(&bytes, point.to_bytes().as_slice());
     }
+
+    #[test]
+    fn sec1_round_trip() {
+        let bytes = hex!("02c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+        let encoded = EncodedPoint::from_bytes(bytes).unwrap();
+        let point = NonIdentity::<AffinePoint>::from_encoded_point(&encoded).unwrap();
+        assert_eq!(encoded, point.to_encoded_point(true));
+
+        let identity = EncodedPoint::identity();
+        assert!(bool::from(
+            NonIdentity::<AffinePoint>::from_encoded_point(&identity).is_none()
+        ));
+    }
 }
@@ -0,0 +1,131 @@
+//! Precomputed tables for fixed-base scalar multiplication.
+
+use crate::{ops::PrecomputedTable, CurveArithmetic, FieldBytes, ProjectivePoint, Scalar};
+use alloc::vec::Vec;
+use core::fmt;
+use ff::PrimeField;
+use group::Group;
+use subtle::{ConditionallySelectable, ConstantTimeEq};
+
+/// Number of bits in each window of the table.
+const WINDOW_BITS: usize = 4;
+
+/// Number of entries in each window of the table.
+const WINDOW_SIZE: usize = 1 << WINDOW_BITS;
+
+/// Generic precomputed table for multiplying a fixed point.
+///
+/// For every 4-bit window of the scalar, this table stores the multiples
+/// `[0, P, 2P, ..., 15P]` of the base point `P` shifted to that window's
+/// position. Multiplication is then performed with one constant-time table
+/// lookup and one point addition per window, and without any doublings.
+///
+/// This is a curve-agnostic fallback: curve implementations are encouraged to
+/// provide their own [`PrecomputedTable`] impls where they can do better.
+#[derive(Clone)]
+pub struct FixedBaseTable<C>
+where
+    C: CurveArithmetic,
+{
+    /// Per-window tables, starting from the least significant window.
+    windows: Vec<[ProjectivePoint<C>; WINDOW_SIZE]>,
+}
+
+impl<C> FixedBaseTable<C>
+where
+    C: CurveArithmetic,
+{
+    /// Precompute a table for the given point.
+    pub fn new(point: &ProjectivePoint<C>) -> Self {
+        let num_windows = FieldBytes::<C>::default().len() * 8 / WINDOW_BITS;
+        let mut windows = Vec::with_capacity(num_windows);
+        let mut base = *point;
+
+        for _ in 0..num_windows {
+            let mut window = [ProjectivePoint::<C>::identity(); WINDOW_SIZE];
+
+            for i in 1..WINDOW_SIZE {
+                window[i] = window[i - 1] + base;
+            }
+
+            base = window[WINDOW_SIZE - 1] + base;
+            windows.push(window);
+        }
+
+        Self { windows }
+    }
+
+    /// Multiply the point this table was computed for by the given scalar.
+    ///
+    /// This operation runs in constant time with respect to the scalar.
+    pub fn multiply(&self, scalar: &Scalar<C>) -> ProjectivePoint<C> {
+        // `FieldBytes` are big endian, so the least significant byte is last
+        let repr = scalar.to_repr();
+        let digits = repr.iter().rev().flat_map(|byte| [byte & 0xf, byte >> 4]);
+
+        let mut ret = ProjectivePoint::<C>::identity();
+
+        for (window, digit) in self.windows.iter().zip(digits) {
+            ret += select(window, digit);
+        }
+
+        ret
+    }
+}
+
+impl<C> PrecomputedTable for FixedBaseTable<C>
+where
+    C: CurveArithmetic,
+{
+    type Point = ProjectivePoint<C>;
+
+    fn new(point: &ProjectivePoint<C>) -> Self {
+        FixedBaseTable::new(point)
+    }
+
+    fn multiply(&self, scalar: &Scalar<C>) -> ProjectivePoint<C> {
+        FixedBaseTable::multiply(self, scalar)
+    }
+}
+
+impl<C> fmt::Debug for FixedBaseTable<C>
+where
+    C: CurveArithmetic,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FixedBaseTable")
+            .field("windows", &self.windows.len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Select the entry for the given digit from a window in constant time.
+fn select<P>(window: &[P; WINDOW_SIZE], digit: u8) -> P
+where
+    P: ConditionallySelectable + Default,
+{
+    let mut ret = P::default();
+
+    for (i, point) in window.iter().enumerate() {
+        ret.conditional_assign(point, (i as u8).ct_eq(&digit));
+    }
+
+    ret
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::FixedBaseTable;
+    use crate::dev::{test_points, test_scalars, MockCurve};
+
+    #[test]
+    fn multiply() {
+        for base in test_points() {
+            let table = FixedBaseTable::<MockCurve>::new(&base);
+
+            for scalar in test_scalars() {
+                assert_eq!(table.multiply(&scalar), base * scalar);
+            }
+        }
+    }
+}
@@ -0,0 +1,99 @@
+//! Tiered validation of received points.
+
+use super::{ClearCofactor, IsTorsionFree};
+use crate::{Error, Result};
+use group::Group;
+use subtle::Choice;
+
+/// Strictness of the checks applied to a point by [`ValidatePoint`].
+///
+/// Each policy includes all of the checks of the ones before it, i.e. the
+/// variants are ordered from least to most strict.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub enum ValidationPolicy {
+    /// Only check that the point is on the curve.
+    OnCurve,
+
+    /// Additionally check that the point is not the identity.
+    NotIdentity,
+
+    /// Additionally check that the point does not have small order, i.e.
+    /// that it isn't the identity once the cofactor is cleared.
+    ///
+    /// This is equivalent to [`ValidationPolicy::NotIdentity`] for prime
+    /// order curves.
+    NotSmallOrder,
+
+    /// Additionally check that the point is in the prime order subgroup.
+    ///
+    /// This is full public key validation, and the default policy.
+    PrimeOrderSubgroup,
+}
+
+impl Default for ValidationPolicy {
+    fn default() -> Self {
+        Self::PrimeOrderSubgroup
+    }
+}
+
+/// Validate that a point (e.g. a received public key or Diffie-Hellman
+/// share) meets a given [`ValidationPolicy`].
+///
+/// This allows protocols to demand the appropriate strictness for points
+/// received from other parties generically, which is particularly important
+/// for curves with a cofactor, where a decoded point may have a small order
+/// component.
+///
+/// The checks are performed in constant time, so default implementations
+/// are provided in terms of [`ClearCofactor`] and [`IsTorsionFree`].
+pub trait ValidatePoint: Group + ClearCofactor + IsTorsionFree {
+    /// Is this point on the curve?
+    ///
+    /// The default implementation always returns true, which is correct for
+    /// types which can only represent points on the curve (e.g. because this
+    /// is checked when decoding them).
+    fn is_on_curve(&self) -> Choice {
+        Choice::from(1)
+    }
+
+    /// Does this point have small order, i.e. is it the identity once the
+    /// cofactor is cleared?
+    fn is_small_order(&self) -> Choice {
+        self.clear_cofactor().is_identity()
+    }
+
+    /// Is this point in the prime order subgroup?
+    fn is_in_prime_order_subgroup(&self) -> Choice {
+        self.is_torsion_free()
+    }
+
+    /// Does this point satisfy the checks of the given `policy`?
+    fn satisfies(&self, policy: ValidationPolicy) -> Choice {
+        let mut ret = self.is_on_curve();
+
+        if policy >= ValidationPolicy::NotIdentity {
+            ret &= !self.is_identity();
+        }
+
+        if policy >= ValidationPolicy::NotSmallOrder {
+            ret &= !self.is_small_order();
+        }
+
+        if policy >= ValidationPolicy::PrimeOrderSubgroup {
+            ret &= self.is_in_prime_order_subgroup();
+        }
+
+        ret
+    }
+
+    /// Validate this point according to the given `policy`.
+    ///
+    /// Returns an error if any of its checks fail.
+    fn validate(&self, policy: ValidationPolicy) -> Result<()> {
+        if self.satisfies(policy).into() {
+            Ok(())
+        } else {
+            Err(Error)
+        }
+    }
+}
@@ -1,7 +1,12 @@
 //! Elliptic curve public keys.
 
+mod x_only;
+
+pub use self::x_only::XOnlyPublicKey;
+
 use crate::{
-    point::NonIdentity, AffinePoint, CurveArithmetic, Error, NonZeroScalar, ProjectivePoint, Result,
+    point::{NonIdentity, ValidatePoint, ValidationPolicy},
+    AffinePoint, CurveArithmetic, Error, NonZeroScalar, ProjectivePoint, Result,
 };
 use core::fmt::Debug;
 use group::{Curve as _, Group};
@@ -18,7 +23,10 @@ use core::str::FromStr;
 #[cfg(feature = "sec1")]
 use {
     crate::{
-        sec1::{EncodedPoint, FromEncodedPoint, ModulusSize, ToEncodedPoint},
+        sec1::{
+            DecodingPolicy, EncodedPoint, FromEncodedPoint, ModulusSize, PointFormat, Tag,
+            ToEncodedPoint, UncompressedPoint,
+        },
         Curve, FieldSize, PointCompression,
     },
     core::cmp::Ordering,
@@ -28,8 +36,14 @@ use {
 #[cfg(all(feature = "alloc", feature = "pkcs8"))]
 use pkcs8::EncodePublicKey;
 
+#[cfg(feature = "openssh")]
+use crate::{openssh, OpenSshParameters};
+
+#[cfg(any(feature = "jwk", feature = "openssh", feature = "pem"))]
+use alloc::string::String;
+
 #[cfg(any(feature = "jwk", feature = "pem"))]
-use alloc::string::{String, ToString};
+use alloc::string::ToString;
 
 #[cfg(all(feature = "pkcs8", feature = "serde"))]
 use serdect::serde::{de, ser, Deserialize, Serialize};
@@ -100,6 +114,17 @@ where
         }
     }
 
+    /// Validate this public key according to the given [`ValidationPolicy`].
+    ///
+    /// [`PublicKey`] is never the identity, however curves with a cofactor
+    /// may require stricter checks for keys received from other parties.
+    pub fn validate(&self, policy: ValidationPolicy) -> Result<()>
+    where
+        ProjectivePoint<C>: ValidatePoint,
+    {
+        self.to_projective().validate(policy)
+    }
+
     /// Compute a [`PublicKey`] from a secret [`NonZeroScalar`] value
     /// (i.e. a secret key represented as a raw scalar value)
     pub fn from_secret_scalar(scalar: &NonZeroScalar<C>) -> Self {
@@ -126,6 +151,49 @@ where
         Option::from(Self::from_encoded_point(&point)).ok_or(Error)
     }
 
+    /// Decode [`PublicKey`] from the `Elliptic-Curve-Point-to-Octet-String`
+    /// encoding described in SEC 1, accepting only the formats allowed by
+    /// the given [`DecodingPolicy`].
+    ///
+    /// In addition to the formats supported by [`PublicKey::from_sec1_bytes`],
+    /// this supports the hybrid format of ANSI X9.62. Returns the key along
+    /// with the format it was encoded in.
+    #[cfg(feature = "sec1")]
+    pub fn from_sec1_bytes_with_policy(
+        bytes: &[u8],
+        policy: DecodingPolicy,
+    ) -> Result<(Self, PointFormat)>
+    where
+        C: Curve,
+        FieldSize<C>: ModulusSize,
+        AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+    {
+        let tag = *bytes.first().ok_or(Error)?;
+        let format = PointFormat::from_tag(tag)?;
+
+        if !policy.allows(format) {
+            return Err(Error);
+        }
+
+        let public_key = if format == PointFormat::Hybrid {
+            // A hybrid point is an uncompressed point whose tag also encodes
+            // the sign of the y-coordinate, which must be consistent with it.
+            let mut uncompressed = UncompressedPoint::<C>::default();
+
+            if bytes.len() != uncompressed.len() || (tag ^ bytes[bytes.len() - 1]) & 1 != 0 {
+                return Err(Error);
+            }
+
+            uncompressed.copy_from_slice(bytes);
+            uncompressed[0] = Tag::Uncompressed.into();
+            Self::from_sec1_bytes(&uncompressed)?
+        } else {
+            Self::from_sec1_bytes(bytes)?
+        };
+
+        Ok((public_key, format))
+    }
+
     /// Convert this [`PublicKey`] into the
     /// `Elliptic-Curve-Point-to-Octet-String` encoding described in
     /// SEC 1: Elliptic Curve Cryptography (Version 2.0) section 2.3.3
@@ -155,6 +223,11 @@ where
         self.point.into()
     }
 
+    /// Convert this [`PublicKey`] to a [`NonIdentity`] of its [`AffinePoint`].
+    pub fn to_nonidentity(&self) -> NonIdentity<AffinePoint<C>> {
+        NonIdentity::new_unchecked(self.point)
+    }
+
     /// Parse a [`JwkEcKey`] JSON Web Key (JWK) into a [`PublicKey`].
     #[cfg(feature = "jwk")]
     pub fn from_jwk(jwk: &JwkEcKey) -> Result<Self>
@@ -198,6 +271,48 @@ where
     {
         self.to_jwk().to_string()
     }
+
+    /// Parse a [`PublicKey`] from a line in the OpenSSH `authorized_keys`
+    /// format, e.g. `ecdsa-sha2-nistp256 AAAA... comment`.
+    ///
+    /// The comment (if any) is ignored.
+    #[cfg(feature = "openssh")]
+    pub fn from_openssh(line: &str) -> Result<Self>
+    where
+        C: OpenSshParameters,
+        AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+        FieldSize<C>: ModulusSize,
+    {
+        openssh::decode_public_key(line)
+    }
+
+    /// Serialize this public key in the OpenSSH `authorized_keys` format,
+    /// without a comment.
+    #[cfg(feature = "openssh")]
+    pub fn to_openssh(&self) -> Result<String>
+    where
+        C: OpenSshParameters,
+        AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+        FieldSize<C>: ModulusSize,
+    {
+        openssh::encode_public_key(self)
+    }
+
+    /// Compute a fingerprint of this public key, i.e. the digest of its
+    /// DER-encoded X.509 `SubjectPublicKeyInfo` (SPKI).
+    ///
+    /// This matches the input hashed for TLS public key pinning.
+    #[cfg(all(feature = "alloc", feature = "digest", feature = "pkcs8"))]
+    pub fn fingerprint<D>(&self) -> Result<digest::Output<D>>
+    where
+        C: AssociatedOid,
+        D: digest::Digest,
+        AffinePoint<C>: FromEncodedPoint<C> + ToEncodedPoint<C>,
+        FieldSize<C>: ModulusSize,
+    {
+        let der = self.to_public_key_der().map_err(|_| Error)?;
+        Ok(D::digest(der.as_bytes()))
+    }
 }
 
 impl<C> AsRef<AffinePoint<C>> for PublicKey<C>
@@ -287,6 +402,24 @@ where
     }
 }
 
+impl<C> From<PublicKey<C>> for NonIdentity<AffinePoint<C>>
+where
+    C: CurveArithmetic,
+{
+    fn from(value: PublicKey<C>) -> Self {
+        Self::from(&value)
+    }
+}
+
+impl<C> From<&PublicKey<C>> for NonIdentity<AffinePoint<C>>
+where
+    C: CurveArithmetic,
+{
+    fn from(value: &PublicKey<C>) -> Self {
+        PublicKey::to_nonidentity(value)
+    }
+}
+
 #[cfg(feature = "sec1")]
 impl<C> PartialOrd for PublicKey<C>
 where
@@ -423,7 +556,11 @@ where
 
 #[cfg(all(feature = "dev", test))]
 mod tests {
-    use crate::{dev::MockCurve, sec1::FromEncodedPoint};
+    use crate::{
+        dev::MockCurve,
+        sec1::{DecodingPolicy, FromEncodedPoint, PointFormat},
+    };
+    use hex_literal::hex;
 
     type EncodedPoint = crate::sec1::EncodedPoint<MockCurve>;
     type PublicKey = super::PublicKey<MockCurve>;
@@ -435,4 +572,46 @@ mod tests {
             PublicKey::from_encoded_point(&identity).is_none()
         ));
     }
+
+    /// Generator of P-256 in uncompressed form.
+    const UNCOMPRESSED: [u8; 65] = hex!(
+        "046b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296"
+        "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5"
+    );
+
+    #[test]
+    fn from_sec1_bytes_with_policy() {
+        const COMPRESSED: [u8; 33] =
+            hex!("036b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296");
+
+        let (_, format) =
+            PublicKey::from_sec1_bytes_with_policy(&UNCOMPRESSED, DecodingPolicy::default())
+                .unwrap();
+        assert_eq!(format, PointFormat::Uncompressed);
+
+        let (_, format) =
+            PublicKey::from_sec1_bytes_with_policy(&COMPRESSED, DecodingPolicy::default()).unwrap();
+        assert_eq!(format, PointFormat::Compressed);
+
+        let policy = DecodingPolicy::only(PointFormat::Uncompressed);
+        assert!(PublicKey::from_sec1_bytes_with_policy(&COMPRESSED, policy).is_err());
+    }
+
+    #[test]
+    fn from_sec1_bytes_with_policy_hybrid() {
+        let mut hybrid = UNCOMPRESSED;
+        hybrid[0] = 0x07;
+
+        assert!(
+            PublicKey::from_sec1_bytes_with_policy(&hybrid, DecodingPolicy::default()).is_err()
+        );
+
+        let (_, format) =
+            PublicKey::from_sec1_bytes_with_policy(&hybrid, DecodingPolicy::ANY).unwrap();
+        assert_eq!(format, PointFormat::Hybrid);
+
+        // y-coordinate is odd, so the even hybrid tag is inconsistent
+        hybrid[0] = 0x06;
+        assert!(PublicKey::from_sec1_bytes_with_policy(&hybrid, DecodingPolicy::ANY).is_err());
+    }
 }
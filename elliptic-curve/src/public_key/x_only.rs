@@ -0,0 +1,192 @@
+//! X-only public keys.
+
+use crate::{
+    AffinePoint, AffineXCoordinate, AffineYIsOdd, CurveArithmetic, DecompressPoint, Error,
+    FieldBytes, FieldSize, ProjectivePoint, PublicKey, Result,
+};
+use generic_array::typenum::Unsigned;
+use group::Curve as _;
+use subtle::{Choice, ConditionallySelectable};
+
+/// Elliptic curve public key serialized as its affine x-coordinate alone.
+///
+/// The y-coordinate is implicitly normalized to be even, as in [BIP340]
+/// Schnorr signatures and Taproot. Converting a [`PublicKey`] with an odd
+/// y-coordinate into an [`XOnlyPublicKey`] negates the point; use
+/// [`XOnlyPublicKey::from_public_key`] to learn whether that happened.
+///
+/// [BIP340]: https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct XOnlyPublicKey<C>
+where
+    C: CurveArithmetic,
+{
+    public_key: PublicKey<C>,
+}
+
+impl<C> XOnlyPublicKey<C>
+where
+    C: CurveArithmetic,
+{
+    /// Convert a [`PublicKey`] into an [`XOnlyPublicKey`], negating it if its
+    /// y-coordinate is odd.
+    ///
+    /// Also returns the parity of the original key's y-coordinate, i.e.
+    /// whether or not it was negated.
+    pub fn from_public_key(public_key: &PublicKey<C>) -> (Self, Choice) {
+        let point = public_key.as_affine();
+        let y_is_odd = point.y_is_odd();
+        let negated = (-ProjectivePoint::<C>::from(*point)).to_affine();
+
+        // Negating a non-identity point never yields the identity
+        let public_key = PublicKey {
+            point: AffinePoint::<C>::conditional_select(point, &negated, y_is_odd),
+        };
+
+        (Self { public_key }, y_is_odd)
+    }
+
+    /// Decode an [`XOnlyPublicKey`] from its serialized x-coordinate, choosing
+    /// the point with an even y-coordinate.
+    pub fn from_bytes(bytes: &FieldBytes<C>) -> Result<Self>
+    where
+        AffinePoint<C>: DecompressPoint<C>,
+    {
+        let point =
+            Option::from(AffinePoint::<C>::decompress(bytes, Choice::from(0))).ok_or(Error)?;
+
+        PublicKey::from_affine(point).map(|public_key| Self { public_key })
+    }
+
+    /// Decode an [`XOnlyPublicKey`] from a byte slice containing its
+    /// serialized x-coordinate.
+    pub fn from_slice(slice: &[u8]) -> Result<Self>
+    where
+        AffinePoint<C>: DecompressPoint<C>,
+    {
+        if slice.len() == FieldSize::<C>::USIZE {
+            Self::from_bytes(FieldBytes::<C>::from_slice(slice))
+        } else {
+            Err(Error)
+        }
+    }
+
+    /// Serialize this key as its affine x-coordinate.
+    pub fn to_bytes(&self) -> FieldBytes<C> {
+        self.public_key.as_affine().x()
+    }
+
+    /// Borrow the inner [`AffinePoint`], whose y-coordinate is even.
+    pub fn as_affine(&self) -> &AffinePoint<C> {
+        self.public_key.as_affine()
+    }
+
+    /// Convert this key into a [`PublicKey`] with an even y-coordinate.
+    pub fn to_public_key(&self) -> PublicKey<C> {
+        self.public_key
+    }
+}
+
+impl<C> AsRef<AffinePoint<C>> for XOnlyPublicKey<C>
+where
+    C: CurveArithmetic,
+{
+    fn as_ref(&self) -> &AffinePoint<C> {
+        self.as_affine()
+    }
+}
+
+impl<C> From<PublicKey<C>> for XOnlyPublicKey<C>
+where
+    C: CurveArithmetic,
+{
+    fn from(public_key: PublicKey<C>) -> XOnlyPublicKey<C> {
+        XOnlyPublicKey::from(&public_key)
+    }
+}
+
+impl<C> From<&PublicKey<C>> for XOnlyPublicKey<C>
+where
+    C: CurveArithmetic,
+{
+    fn from(public_key: &PublicKey<C>) -> XOnlyPublicKey<C> {
+        XOnlyPublicKey::from_public_key(public_key).0
+    }
+}
+
+impl<C> From<XOnlyPublicKey<C>> for PublicKey<C>
+where
+    C: CurveArithmetic,
+{
+    fn from(x_only: XOnlyPublicKey<C>) -> PublicKey<C> {
+        x_only.public_key
+    }
+}
+
+impl<C> From<&XOnlyPublicKey<C>> for PublicKey<C>
+where
+    C: CurveArithmetic,
+{
+    fn from(x_only: &XOnlyPublicKey<C>) -> PublicKey<C> {
+        x_only.public_key
+    }
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::XOnlyPublicKey;
+    use crate::{
+        dev::{MockCurve, ProjectivePoint, PublicKey, Scalar},
+        AffineXCoordinate, AffineYIsOdd, IsHigh,
+    };
+    use group::{Curve, Group};
+
+    type XOnly = XOnlyPublicKey<MockCurve>;
+
+    fn public_key(scalar: Scalar) -> PublicKey {
+        PublicKey::from_affine((ProjectivePoint::generator() * scalar).to_affine()).unwrap()
+    }
+
+    #[test]
+    fn from_public_key_even() {
+        let scalar = Scalar::from(42u64);
+        assert!(!bool::from(scalar.is_high()));
+
+        let public_key = public_key(scalar);
+        assert!(!bool::from(public_key.as_affine().y_is_odd()));
+
+        let (x_only, negated) = XOnly::from_public_key(&public_key);
+        assert!(!bool::from(negated));
+        assert_eq!(x_only.to_public_key(), public_key);
+    }
+
+    #[test]
+    fn from_public_key_odd() {
+        let public_key = public_key(-Scalar::from(42u64));
+        assert!(bool::from(public_key.as_affine().y_is_odd()));
+
+        let (x_only, negated) = XOnly::from_public_key(&public_key);
+        assert!(bool::from(negated));
+        assert!(!bool::from(x_only.as_affine().y_is_odd()));
+        assert_eq!(
+            ProjectivePoint::from(*x_only.as_affine()),
+            -public_key.to_projective()
+        );
+
+        // Both keys share the same x-coordinate
+        assert_eq!(x_only, XOnly::from(self::public_key(Scalar::from(42u64))));
+        assert_eq!(x_only.to_bytes(), public_key.as_affine().x());
+    }
+
+    #[test]
+    fn bytes_round_trip() {
+        let x_only = XOnly::from(public_key(-Scalar::from(42u64)));
+        let bytes = x_only.to_bytes();
+
+        let decoded = XOnly::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded.to_bytes(), bytes);
+        assert!(!bool::from(decoded.as_affine().y_is_odd()));
+        assert_eq!(XOnly::from_slice(&bytes).unwrap().to_bytes(), bytes);
+        assert!(XOnly::from_slice(&bytes[1..]).is_err());
+    }
+}
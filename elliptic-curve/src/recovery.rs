@@ -0,0 +1,125 @@
+//! Public key recovery support.
+
+use crate::{Error, Result};
+
+#[cfg(feature = "arithmetic")]
+use crate::{bigint::ArrayEncoding, AffinePoint, AffineXCoordinate, AffineYIsOdd, CurveArithmetic};
+
+/// Recovery IDs, a.k.a. "recid".
+///
+/// This is an integer value `0`, `1`, `2`, or `3` included along with a
+/// signature which is used during the recovery process to select the correct
+/// public key from the signature.
+///
+/// It consists of two bits of information:
+///
+/// - low bit (0/1): was the y-coordinate of the affine point resulting from
+///   the fixed-base multiplication 𝑘×𝑮 odd? This part of the algorithm
+///   functions similar to point decompression.
+/// - hi bit (2/3): did the affine x-coordinate of 𝑘×𝑮 overflow the order of
+///   the curve? This is a rare case which is only possible on curves whose
+///   order is smaller than their base field modulus.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord, Hash)]
+pub struct RecoveryId(u8);
+
+impl RecoveryId {
+    /// Maximum supported value for the recovery ID (inclusive).
+    pub const MAX: u8 = 3;
+
+    /// Create a new [`RecoveryId`] from the following 1-bit arguments:
+    ///
+    /// - `is_y_odd`: is the affine y-coordinate of 𝑘×𝑮 odd?
+    /// - `is_x_reduced`: did the affine x-coordinate of 𝑘×𝑮 overflow the curve order?
+    pub const fn new(is_y_odd: bool, is_x_reduced: bool) -> Self {
+        Self((is_x_reduced as u8) << 1 | (is_y_odd as u8))
+    }
+
+    /// Compute the [`RecoveryId`] for the affine point 𝑹 = 𝑘×𝑮 computed while
+    /// producing a signature, whose x-coordinate (reduced modulo the curve
+    /// order) becomes the signature's `r` component.
+    #[cfg(feature = "arithmetic")]
+    pub fn from_point<C>(point: &AffinePoint<C>) -> Self
+    where
+        C: CurveArithmetic,
+    {
+        let x = C::Uint::from_be_byte_array(point.x());
+        Self::new(point.y_is_odd().into(), x >= C::ORDER)
+    }
+
+    /// Did the affine x-coordinate of 𝑘×𝑮 overflow the curve order?
+    pub const fn is_x_reduced(self) -> bool {
+        (self.0 & 0b10) != 0
+    }
+
+    /// Is the affine y-coordinate of 𝑘×𝑮 odd?
+    pub const fn is_y_odd(self) -> bool {
+        (self.0 & 1) != 0
+    }
+
+    /// Convert a `u8` into a [`RecoveryId`].
+    pub const fn from_byte(byte: u8) -> Option<Self> {
+        if byte <= Self::MAX {
+            Some(Self(byte))
+        } else {
+            None
+        }
+    }
+
+    /// Convert this [`RecoveryId`] into a `u8`.
+    pub const fn to_byte(self) -> u8 {
+        self.0
+    }
+}
+
+impl TryFrom<u8> for RecoveryId {
+    type Error = Error;
+
+    fn try_from(byte: u8) -> Result<Self> {
+        Self::from_byte(byte).ok_or(Error)
+    }
+}
+
+impl From<RecoveryId> for u8 {
+    fn from(id: RecoveryId) -> u8 {
+        id.0
+    }
+}
+
+/// Recover the verifying key (i.e. public key) which produced a signature.
+///
+/// This is intended to be impl'd by the verifying key types of signature
+/// algorithms which support public key recovery, such as ECDSA, so that
+/// recovery (as used by e.g. Ethereum) can be performed generically.
+pub trait RecoverVerifyingKey<S>: Sized {
+    /// Recover the verifying key which produced the given `signature` over
+    /// the given message `prehash`, using the provided [`RecoveryId`].
+    ///
+    /// Returns an error if no valid key can be recovered, e.g. because the
+    /// recovery ID selects an x-coordinate which is out of range.
+    fn recover_from_prehash(prehash: &[u8], signature: &S, recovery_id: RecoveryId)
+        -> Result<Self>;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RecoveryId;
+
+    #[test]
+    fn new() {
+        assert_eq!(RecoveryId::new(false, false).to_byte(), 0);
+        assert_eq!(RecoveryId::new(true, false).to_byte(), 1);
+        assert_eq!(RecoveryId::new(false, true).to_byte(), 2);
+        assert_eq!(RecoveryId::new(true, true).to_byte(), 3);
+    }
+
+    #[test]
+    fn try_from() {
+        for n in 0u8..=3 {
+            let id = RecoveryId::try_from(n).unwrap();
+            assert_eq!(id.is_y_odd(), n & 1 == 1);
+            assert_eq!(id.is_x_reduced(), n & 2 == 2);
+        }
+
+        assert!(RecoveryId::try_from(4).is_err());
+    }
+}
@@ -0,0 +1,224 @@
+//! Deterministic generation of the ephemeral scalar `k` as described in
+//! [RFC 6979], for use by signature schemes such as ECDSA and Schnorr.
+//!
+//! The nonce is derived from the secret scalar and the message hash using
+//! HMAC-DRBG. Additional data can be mixed in as described in
+//! [RFC 6979 § 3.6], e.g. randomness for hedged signatures, which protects
+//! against fault attacks while the output remains deterministic for a given
+//! input (and thus compatible with test vectors when it's empty).
+//!
+//! [RFC 6979]: https://www.rfc-editor.org/rfc/rfc6979
+//! [RFC 6979 § 3.6]: https://www.rfc-editor.org/rfc/rfc6979#section-3.6
+
+use crate::{
+    bigint::{ArrayEncoding, CheckedSub, Zero},
+    Curve, FieldBytes, ScalarPrimitive,
+};
+use digest::{crypto_common::BlockSizeUser, Digest, FixedOutput, KeyInit, Mac, Output};
+use hmac::SimpleHmac;
+use zeroize::Zeroize;
+
+/// Deterministically generate the ephemeral scalar `k` for the given
+/// `secret_scalar` and `message_hash`, using HMAC-DRBG instantiated with
+/// the digest `D`.
+///
+/// The `extra_entropy` is mixed into the DRBG as the additional data `k'`
+/// of RFC 6979 § 3.6, and may be empty.
+///
+/// The result is always in the range `1..n`. Curves of any order size are
+/// supported, including those whose order isn't a whole number of bytes.
+pub fn generate_k<C, D>(
+    secret_scalar: &ScalarPrimitive<C>,
+    message_hash: &[u8],
+    extra_entropy: &[u8],
+) -> ScalarPrimitive<C>
+where
+    C: Curve,
+    D: BlockSizeUser + Digest,
+{
+    let order = Order::<C>::new();
+
+    let mut x = secret_scalar.to_be_bytes();
+    let h = order
+        .reduce(order.bits2int(message_hash))
+        .to_be_byte_array();
+
+    // RFC 6979 § 3.2 steps b through g
+    let mut k = Output::<SimpleHmac<D>>::default();
+    let mut v = Output::<SimpleHmac<D>>::default();
+    v.iter_mut().for_each(|b| *b = 0x01);
+
+    for prefix in [0x00, 0x01] {
+        k = hmac::<D>(
+            &k,
+            &[
+                &v,
+                &[prefix],
+                order.int2octets(&x),
+                order.int2octets(&h),
+                extra_entropy,
+            ],
+        );
+        v = hmac::<D>(&k, &[&v]);
+    }
+
+    x.as_mut_slice().zeroize();
+
+    // RFC 6979 § 3.2 step h
+    let mut t = FieldBytes::<C>::default();
+
+    let ret = loop {
+        let mut t_len = 0;
+
+        while t_len < order.len {
+            v = hmac::<D>(&k, &[&v]);
+            let n = (order.len - t_len).min(v.len());
+            t[t_len..][..n].copy_from_slice(&v[..n]);
+            t_len += n;
+        }
+
+        let mut candidate = order.bits2int(&t[..order.len]);
+
+        if !bool::from(candidate.is_zero()) {
+            if let Some(ret) = ScalarPrimitive::new(candidate).into() {
+                break ret;
+            }
+        }
+
+        candidate.zeroize();
+        k = hmac::<D>(&k, &[&v, &[0x00]]);
+        v = hmac::<D>(&k, &[&v]);
+    };
+
+    k.as_mut_slice().zeroize();
+    v.as_mut_slice().zeroize();
+    t.as_mut_slice().zeroize();
+    ret
+}
+
+/// Compute the HMAC of the concatenation of `data` using `key`.
+fn hmac<D>(key: &[u8], data: &[&[u8]]) -> Output<SimpleHmac<D>>
+where
+    D: BlockSizeUser + Digest,
+{
+    let mut mac = <SimpleHmac<D> as KeyInit>::new_from_slice(key).expect("HMAC accepts any key");
+
+    for part in data {
+        mac.update(part);
+    }
+
+    mac.finalize_fixed()
+}
+
+/// Order of a curve along with its bit and byte lengths, i.e. `qlen` and
+/// `rlen` of RFC 6979 § 2.3.
+struct Order<C: Curve> {
+    /// Order of the curve.
+    n: C::Uint,
+
+    /// Length of the order in bits.
+    bits: usize,
+
+    /// Length of the order in bytes.
+    len: usize,
+}
+
+impl<C: Curve> Order<C> {
+    fn new() -> Self {
+        let bytes = C::ORDER.to_be_byte_array();
+        let leading_zeroes = bytes
+            .iter()
+            .position(|&b| b != 0)
+            .map(|i| i * 8 + bytes[i].leading_zeros() as usize)
+            .unwrap_or(bytes.len() * 8);
+        let bits = bytes.len() * 8 - leading_zeroes;
+
+        Self {
+            n: C::ORDER,
+            bits,
+            len: (bits + 7) / 8,
+        }
+    }
+
+    /// Convert the leftmost `qlen` bits of `bytes` into an integer.
+    fn bits2int(&self, bytes: &[u8]) -> C::Uint {
+        let len = bytes.len().min(self.len);
+        let mut buf = FieldBytes::<C>::default();
+        let offset = buf.len() - len;
+        buf[offset..].copy_from_slice(&bytes[..len]);
+
+        let mut ret = C::Uint::from_be_byte_array(buf.clone());
+        buf.as_mut_slice().zeroize();
+
+        if len * 8 > self.bits {
+            ret >>= len * 8 - self.bits;
+        }
+
+        ret
+    }
+
+    /// Reduce an integer less than `2^qlen` modulo the order, which
+    /// requires at most one subtraction since `2^qlen < 2n`.
+    fn reduce(&self, x: C::Uint) -> C::Uint {
+        x.checked_sub(&self.n).unwrap_or(x)
+    }
+
+    /// Get the big endian encoding of an integer less than the order as an
+    /// octet string of length `rlen`.
+    fn int2octets<'b>(&self, bytes: &'b FieldBytes<C>) -> &'b [u8] {
+        &bytes[bytes.len() - self.len..]
+    }
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::generate_k;
+    use crate::{dev::MockCurve, ScalarPrimitive};
+    use hex_literal::hex;
+    use sha2::{Digest, Sha256, Sha512};
+
+    /// Secret key from RFC 6979 § A.2.5 (P-256).
+    const KEY: [u8; 32] = hex!("C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721");
+
+    fn secret_scalar() -> ScalarPrimitive<MockCurve> {
+        ScalarPrimitive::from_be_slice(&KEY).unwrap()
+    }
+
+    #[test]
+    fn rfc6979_sha256() {
+        let k = generate_k::<MockCurve, Sha256>(&secret_scalar(), &Sha256::digest(b"sample"), &[]);
+        assert_eq!(
+            k.to_be_bytes().as_slice(),
+            hex!("A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60")
+        );
+
+        let k = generate_k::<MockCurve, Sha256>(&secret_scalar(), &Sha256::digest(b"test"), &[]);
+        assert_eq!(
+            k.to_be_bytes().as_slice(),
+            hex!("D16B6AE827F17175E040871A1C7EC3500192C4C92677336EC2537ACAEE0008E0")
+        );
+    }
+
+    #[test]
+    fn rfc6979_sha512() {
+        // Hashes longer than the order are truncated to its bit length.
+        let k = generate_k::<MockCurve, Sha512>(&secret_scalar(), &Sha512::digest(b"sample"), &[]);
+        assert_eq!(
+            k.to_be_bytes().as_slice(),
+            hex!("5FA81C63109BADB88C1F367B47DA606DA28CAD69AA22C4FE6AD7DF73A7173AA5")
+        );
+    }
+
+    #[test]
+    fn extra_entropy() {
+        let h = Sha256::digest(b"sample");
+        let k = generate_k::<MockCurve, Sha256>(&secret_scalar(), &h, &[]);
+        let hedged = generate_k::<MockCurve, Sha256>(&secret_scalar(), &h, &[0x42; 32]);
+
+        assert_ne!(k, hedged);
+        assert_eq!(
+            generate_k::<MockCurve, Sha256>(&secret_scalar(), &h, &[0x42; 32]),
+            hedged
+        );
+    }
+}
@@ -1,5 +1,7 @@
 //! Scalar types.
 
+#[cfg(feature = "arithmetic")]
+mod additive;
 #[cfg(feature = "arithmetic")]
 mod blinded;
 #[cfg(feature = "arithmetic")]
@@ -7,13 +9,19 @@ mod invert;
 #[cfg(feature = "arithmetic")]
 mod nonzero;
 mod primitive;
+mod recode;
+mod wnaf;
 
-pub use self::primitive::ScalarPrimitive;
+#[cfg(all(feature = "alloc", feature = "arithmetic"))]
+pub use self::wnaf::mul_wnaf;
 #[cfg(feature = "arithmetic")]
-pub use self::{blinded::BlindedScalar, invert::invert_vartime, nonzero::NonZeroScalar};
+pub use self::{
+    additive::AdditiveShare, blinded::BlindedScalar, invert::invert_vartime, nonzero::NonZeroScalar,
+};
+pub use self::{primitive::ScalarPrimitive, recode::RecodeSigned, wnaf::ToWnaf};
 
 use crypto_bigint::Integer;
-use subtle::Choice;
+use subtle::{Choice, ConstantTimeEq, CtOption};
 
 #[cfg(feature = "arithmetic")]
 use crate::CurveArithmetic;
@@ -43,6 +51,49 @@ pub trait FromUintUnchecked {
     fn from_uint_unchecked(uint: Self::Uint) -> Self;
 }
 
+/// Constant-time ordering of scalars by their canonical integer
+/// representation in the range `0..n`.
+///
+/// This is useful for checks such as whether a scalar is in canonical form,
+/// or low-S normalization, which must not leak the values being compared.
+pub trait CtOrd {
+    /// Is this scalar less than `other`?
+    fn ct_lt(&self, other: &Self) -> Choice;
+
+    /// Is this scalar greater than `other`?
+    fn ct_gt(&self, other: &Self) -> Choice;
+}
+
+/// Conversion of a scalar from its internal representation (e.g. Montgomery
+/// form) into its canonical representation, i.e. the integer in the range
+/// `0..n` which it represents.
+///
+/// Serialization code and constant-time comparisons which care about the
+/// value of a scalar should operate on its canonical representation, so that
+/// they never accidentally mix it with an internal one.
+pub trait ToCanonical {
+    /// Canonical representation, e.g. [`ScalarPrimitive`].
+    type Canonical: ConstantTimeEq;
+
+    /// Convert this scalar into its canonical representation.
+    fn to_canonical(&self) -> Self::Canonical;
+
+    /// Is the canonical representation of this scalar equal to `other`?
+    fn ct_eq_canonical(&self, other: &Self::Canonical) -> Choice {
+        self.to_canonical().ct_eq(other)
+    }
+}
+
+/// Conversion of a scalar from its canonical representation into its
+/// internal representation (e.g. Montgomery form).
+pub trait FromCanonical: ToCanonical + Sized {
+    /// Convert a canonical representation into a scalar.
+    ///
+    /// Returns none if the value isn't representable by this type, e.g.
+    /// zero for [`NonZeroScalar`].
+    fn from_canonical(canonical: &Self::Canonical) -> CtOption<Self>;
+}
+
 /// Is this scalar greater than n / 2?
 ///
 /// # Returns
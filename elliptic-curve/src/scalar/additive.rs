@@ -0,0 +1,188 @@
+//! Additive secret sharing of scalars.
+
+use super::{NonZeroScalar, Scalar};
+use crate::{CurveArithmetic, Error, Result};
+use ff::Field;
+use rand_core::CryptoRngCore;
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+/// Additive share of a secret [`NonZeroScalar`].
+///
+/// A secret is split into `n` shares which sum to the secret, such that all
+/// `n` of them are required to recover it: any smaller subset is uniformly
+/// random and reveals nothing about the secret. This is useful as a building
+/// block for e.g. 2-of-2 threshold signing.
+///
+/// Shares are zeroized on drop.
+#[derive(Clone)]
+pub struct AdditiveShare<C>
+where
+    C: CurveArithmetic,
+{
+    /// Value of this share.
+    scalar: Scalar<C>,
+}
+
+impl<C> AdditiveShare<C>
+where
+    C: CurveArithmetic,
+{
+    /// Create a share from its scalar value, e.g. after deserializing it.
+    pub fn new(scalar: Scalar<C>) -> Self {
+        Self { scalar }
+    }
+
+    /// Split `secret` into `N` additive shares.
+    ///
+    /// The first `N - 1` shares are sampled uniformly at random, and the final
+    /// share is computed such that all of them sum to `secret`.
+    ///
+    /// Returns an error if `N` is zero.
+    pub fn split<const N: usize>(
+        secret: &NonZeroScalar<C>,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<[Self; N]> {
+        let mut shares = [Scalar::<C>::ZERO; N];
+        split_into(secret, &mut shares, rng)?;
+        let result = shares.map(Self::new);
+        shares.zeroize();
+        Ok(result)
+    }
+
+    /// Split `secret` into `n` additive shares, returning them as a [`Vec`].
+    ///
+    /// Returns an error if `n` is zero.
+    #[cfg(feature = "alloc")]
+    pub fn split_vec(
+        secret: &NonZeroScalar<C>,
+        n: usize,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<Vec<Self>> {
+        let mut scalars = vec![Scalar::<C>::ZERO; n];
+        split_into(secret, &mut scalars, rng)?;
+        let shares = scalars.iter().copied().map(Self::new).collect();
+        scalars.zeroize();
+        Ok(shares)
+    }
+
+    /// Recombine a complete set of shares into the secret they were split
+    /// from.
+    ///
+    /// Returns an error if no shares are given or if they sum to zero, which
+    /// can't happen if they were generated from a [`NonZeroScalar`] and the
+    /// set is complete.
+    pub fn combine(shares: &[Self]) -> Result<NonZeroScalar<C>> {
+        let sum = shares
+            .iter()
+            .fold(Scalar::<C>::ZERO, |sum, share| sum + share.scalar);
+
+        Option::from(NonZeroScalar::new(sum)).ok_or(Error)
+    }
+}
+
+impl<C> AsRef<Scalar<C>> for AdditiveShare<C>
+where
+    C: CurveArithmetic,
+{
+    fn as_ref(&self) -> &Scalar<C> {
+        &self.scalar
+    }
+}
+
+impl<C> ConstantTimeEq for AdditiveShare<C>
+where
+    C: CurveArithmetic,
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.scalar.ct_eq(&other.scalar)
+    }
+}
+
+impl<C> Zeroize for AdditiveShare<C>
+where
+    C: CurveArithmetic,
+{
+    fn zeroize(&mut self) {
+        self.scalar.zeroize();
+    }
+}
+
+impl<C> Drop for AdditiveShare<C>
+where
+    C: CurveArithmetic,
+{
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<C> ZeroizeOnDrop for AdditiveShare<C> where C: CurveArithmetic {}
+
+/// Split `secret` into additive shares, writing their values to `shares`.
+fn split_into<C>(
+    secret: &NonZeroScalar<C>,
+    shares: &mut [Scalar<C>],
+    rng: &mut impl CryptoRngCore,
+) -> Result<()>
+where
+    C: CurveArithmetic,
+{
+    let (last, rest) = shares.split_last_mut().ok_or(Error)?;
+    let mut remainder = **secret;
+
+    for share in rest {
+        *share = Scalar::<C>::random(&mut *rng);
+        remainder -= *share;
+    }
+
+    *last = remainder;
+    remainder.zeroize();
+    Ok(())
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::AdditiveShare;
+    use crate::dev::{MockCurve, NonZeroScalar};
+    use ff::PrimeField;
+    use hex_literal::hex;
+    use rand_core::OsRng;
+
+    const EXAMPLE_SCALAR: [u8; 32] =
+        hex!("AABBCCDDEEFF0000000000000000000000000000000000000000000000000001");
+
+    fn example_secret() -> NonZeroScalar {
+        NonZeroScalar::from_repr(EXAMPLE_SCALAR.into()).unwrap()
+    }
+
+    #[test]
+    fn split_and_combine() {
+        let secret = example_secret();
+        let shares = AdditiveShare::<MockCurve>::split::<3>(&secret, &mut OsRng).unwrap();
+        let combined = AdditiveShare::combine(&shares).unwrap();
+        assert_eq!(combined.to_repr(), secret.to_repr());
+    }
+
+    #[test]
+    fn split_into_none() {
+        let secret = example_secret();
+        assert!(AdditiveShare::<MockCurve>::split::<0>(&secret, &mut OsRng).is_err());
+        assert!(AdditiveShare::<MockCurve>::combine(&[]).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn split_vec() {
+        let secret = example_secret();
+        let shares = AdditiveShare::<MockCurve>::split_vec(&secret, 5, &mut OsRng).unwrap();
+        assert_eq!(shares.len(), 5);
+        assert_eq!(
+            AdditiveShare::combine(&shares).unwrap().to_repr(),
+            secret.to_repr()
+        );
+    }
+}
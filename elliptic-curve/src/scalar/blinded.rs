@@ -1,11 +1,11 @@
 //! Random blinding support for [`Scalar`]
 
-use super::{invert_vartime, Scalar};
+use super::{invert_vartime, NonZeroScalar, Scalar};
 use crate::{ops::Invert, CurveArithmetic};
-use group::ff::Field;
+use ff::Field;
 use rand_core::CryptoRngCore;
 use subtle::CtOption;
-use zeroize::Zeroize;
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 /// Scalar blinded with a randomly generated masking value.
 ///
@@ -22,7 +22,7 @@ where
     /// Actual scalar value.
     scalar: Scalar<C>,
 
-    /// Mask value.
+    /// Mask value, which is never zero.
     mask: Scalar<C>,
 }
 
@@ -31,10 +31,13 @@ where
     C: CurveArithmetic,
 {
     /// Create a new [`BlindedScalar`] from a scalar and a [`CryptoRngCore`].
+    ///
+    /// The mask is sampled as a [`NonZeroScalar`], since a zero mask would
+    /// cause the inversion of any scalar to fail.
     pub fn new(scalar: Scalar<C>, rng: &mut impl CryptoRngCore) -> Self {
         Self {
             scalar,
-            mask: Scalar::<C>::random(rng),
+            mask: *NonZeroScalar::<C>::random(rng),
         }
     }
 }
@@ -62,12 +65,27 @@ where
     }
 }
 
-impl<C> Drop for BlindedScalar<C>
+impl<C> Zeroize for BlindedScalar<C>
 where
     C: CurveArithmetic,
 {
-    fn drop(&mut self) {
+    fn zeroize(&mut self) {
         self.scalar.zeroize();
         self.mask.zeroize();
+
+        // Write a 1 instead of a 0 to ensure the mask's non-zero invariant
+        // is upheld.
+        self.mask = Scalar::<C>::ONE;
+    }
+}
+
+impl<C> Drop for BlindedScalar<C>
+where
+    C: CurveArithmetic,
+{
+    fn drop(&mut self) {
+        self.zeroize();
     }
 }
+
+impl<C> ZeroizeOnDrop for BlindedScalar<C> where C: CurveArithmetic {}
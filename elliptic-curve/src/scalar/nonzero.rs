@@ -2,12 +2,13 @@
 
 use crate::{
     ops::{Invert, Reduce, ReduceNonZero},
+    scalar::{CtOrd, FromCanonical, ToCanonical},
     CurveArithmetic, Error, FieldBytes, IsHigh, PrimeCurve, Scalar, ScalarPrimitive, SecretKey,
 };
 use base16ct::HexDisplay;
 use core::{
     fmt,
-    ops::{Deref, Mul, Neg},
+    ops::{Deref, Mul, MulAssign, Neg},
     str,
 };
 use crypto_bigint::{ArrayEncoding, Integer};
@@ -79,6 +80,43 @@ where
             scalar: super::invert_vartime::<C>(&self.scalar).unwrap(),
         }
     }
+
+    /// Square this scalar.
+    ///
+    /// The square of a non-zero scalar modulo a prime is always non-zero.
+    #[must_use]
+    pub fn square(&self) -> Self {
+        let scalar = self.scalar.square();
+        debug_assert!(!bool::from(scalar.is_zero()));
+        Self { scalar }
+    }
+
+    /// Exponentiate this scalar by `exp`, given as little endian 64-bit limbs.
+    ///
+    /// Any power of a non-zero scalar modulo a prime is non-zero, including
+    /// the zeroth power which is one. This operation is constant-time with
+    /// respect to the value of the exponent, but not its number of limbs.
+    #[must_use]
+    pub fn pow<S: AsRef<[u64]>>(&self, exp: S) -> Self {
+        let scalar = self.scalar.pow(exp);
+        debug_assert!(!bool::from(scalar.is_zero()));
+        Self { scalar }
+    }
+
+    /// Exponentiate this scalar by `exp`, given as little endian 64-bit limbs,
+    /// in variable-time.
+    ///
+    /// ⚠️ WARNING!
+    ///
+    /// This method should not be used with secret exponents, as its
+    /// variable-time operation can potentially leak secrets through
+    /// sidechannels.
+    #[must_use]
+    pub fn pow_vartime<S: AsRef<[u64]>>(&self, exp: S) -> Self {
+        let scalar = self.scalar.pow_vartime(exp);
+        debug_assert!(!bool::from(scalar.is_zero()));
+        Self { scalar }
+    }
 }
 
 impl<C> AsRef<Scalar<C>> for NonZeroScalar<C>
@@ -191,6 +229,43 @@ where
             scalar: ff::Field::invert(&self.scalar).unwrap(),
         }
     }
+
+    fn invert_vartime(&self) -> Self {
+        NonZeroScalar::invert_vartime(self)
+    }
+}
+
+impl<C> CtOrd for NonZeroScalar<C>
+where
+    C: CurveArithmetic,
+{
+    fn ct_lt(&self, other: &Self) -> Choice {
+        ScalarPrimitive::from(self).ct_lt(&ScalarPrimitive::from(other))
+    }
+
+    fn ct_gt(&self, other: &Self) -> Choice {
+        ScalarPrimitive::from(self).ct_gt(&ScalarPrimitive::from(other))
+    }
+}
+
+impl<C> ToCanonical for NonZeroScalar<C>
+where
+    C: CurveArithmetic,
+{
+    type Canonical = ScalarPrimitive<C>;
+
+    fn to_canonical(&self) -> ScalarPrimitive<C> {
+        self.into()
+    }
+}
+
+impl<C> FromCanonical for NonZeroScalar<C>
+where
+    C: CurveArithmetic,
+{
+    fn from_canonical(canonical: &ScalarPrimitive<C>) -> CtOption<Self> {
+        Self::new((*canonical).into())
+    }
 }
 
 impl<C> IsHigh for NonZeroScalar<C>
@@ -242,6 +317,38 @@ where
     }
 }
 
+impl<C> Mul<&NonZeroScalar<C>> for &NonZeroScalar<C>
+where
+    C: PrimeCurve + CurveArithmetic,
+{
+    type Output = NonZeroScalar<C>;
+
+    #[inline]
+    fn mul(self, other: &NonZeroScalar<C>) -> NonZeroScalar<C> {
+        *self * other
+    }
+}
+
+impl<C> MulAssign<NonZeroScalar<C>> for NonZeroScalar<C>
+where
+    C: PrimeCurve + CurveArithmetic,
+{
+    #[inline]
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<C> MulAssign<&NonZeroScalar<C>> for NonZeroScalar<C>
+where
+    C: PrimeCurve + CurveArithmetic,
+{
+    #[inline]
+    fn mul_assign(&mut self, other: &Self) {
+        *self = *self * other;
+    }
+}
+
 /// Note: implementation is the same as `ReduceNonZero`
 impl<C, I> Reduce<I> for NonZeroScalar<C>
 where
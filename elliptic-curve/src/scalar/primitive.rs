@@ -2,8 +2,8 @@
 
 use crate::{
     bigint::{prelude::*, Limb, NonZero},
-    ops::{Add, AddAssign, Neg, Shr1, Sub, SubAssign},
-    scalar::FromUintUnchecked,
+    ops::{Add, AddAssign, BitShift, Neg, ReduceBytes, ReduceWide, Shr1, Sub, SubAssign},
+    scalar::{CtOrd, FromCanonical, FromUintUnchecked, ToCanonical},
     Curve, Error, FieldBytes, IsHigh, Result,
 };
 use base16ct::HexDisplay;
@@ -66,6 +66,13 @@ where
         }
     }
 
+    /// Create a new scalar from [`Curve::Uint`] in a `const` context,
+    /// without checking it's in range.
+    #[cfg(feature = "dev")]
+    pub(crate) const fn from_uint_const(uint: C::Uint) -> Self {
+        Self { inner: uint }
+    }
+
     /// Create a new scalar from [`Curve::Uint`].
     pub fn new(uint: C::Uint) -> CtOption<Self> {
         CtOption::new(Self { inner: uint }, uint.ct_lt(&Self::MODULUS))
@@ -85,6 +92,24 @@ where
         }
     }
 
+    /// Encode [`ScalarPrimitive`] as big endian bytes into a
+    /// [`heapless::Vec`] with capacity `N`.
+    ///
+    /// Returns an error if `N` is smaller than the size of a serialized scalar.
+    #[cfg(feature = "heapless")]
+    pub fn to_be_heapless_vec<const N: usize>(&self) -> Result<heapless::Vec<u8, N>> {
+        heapless::Vec::from_slice(&self.to_be_bytes()).map_err(|_| Error)
+    }
+
+    /// Encode [`ScalarPrimitive`] as little endian bytes into a
+    /// [`heapless::Vec`] with capacity `N`.
+    ///
+    /// Returns an error if `N` is smaller than the size of a serialized scalar.
+    #[cfg(feature = "heapless")]
+    pub fn to_le_heapless_vec<const N: usize>(&self) -> Result<heapless::Vec<u8, N>> {
+        heapless::Vec::from_slice(&self.to_le_bytes()).map_err(|_| Error)
+    }
+
     /// Decode [`ScalarPrimitive`] from little endian bytes.
     pub fn from_le_bytes(bytes: FieldBytes<C>) -> CtOption<Self> {
         Self::new(C::Uint::from_le_byte_array(bytes))
@@ -210,10 +235,74 @@ where
     }
 }
 
+impl<C> CtOrd for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn ct_lt(&self, other: &Self) -> Choice {
+        self.inner.ct_lt(&other.inner)
+    }
+
+    fn ct_gt(&self, other: &Self) -> Choice {
+        self.inner.ct_gt(&other.inner)
+    }
+}
+
+impl<C> ReduceBytes for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn from_be_slice_reduced(bytes: &[u8]) -> Self {
+        bytes.iter().fold(Self::ZERO, shift_in_byte)
+    }
+
+    fn from_le_slice_reduced(bytes: &[u8]) -> Self {
+        bytes.iter().rev().fold(Self::ZERO, shift_in_byte)
+    }
+}
+
+impl<C> ReduceWide<<C::Uint as Concat>::Output> for ScalarPrimitive<C>
+where
+    C: Curve,
+    C::Uint: Concat,
+    <C::Uint as Concat>::Output: ArrayEncoding + Integer + Split<Output = C::Uint>,
+{
+    fn from_wide_uint_reduced(n: <C::Uint as Concat>::Output) -> Self {
+        let (hi, lo) = n.split();
+        let mut ret = Self::ZERO;
+
+        // Constant-time binary long division, keeping only the remainder
+        for uint in [hi, lo] {
+            for limb in uint.as_ref().iter().rev() {
+                for i in (0..Limb::BITS).rev() {
+                    ret = ret + ret;
+                    let bit = Choice::from(((limb.0 >> i) & 1) as u8);
+                    ret.conditional_assign(&(ret + Self::ONE), bit);
+                }
+            }
+        }
+
+        ret
+    }
+}
+
 impl<C: Curve> DefaultIsZeroes for ScalarPrimitive<C> {}
 
 impl<C: Curve> Eq for ScalarPrimitive<C> {}
 
+/// Decode [`ScalarPrimitive`] from a big endian byte slice, the same as
+/// [`ScalarPrimitive::from_be_slice`].
+impl<C> TryFrom<&[u8]> for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    type Error = Error;
+
+    fn try_from(bytes: &[u8]) -> Result<Self> {
+        Self::from_be_slice(bytes)
+    }
+}
+
 impl<C> PartialEq for ScalarPrimitive<C>
 where
     C: Curve,
@@ -369,6 +458,42 @@ where
     }
 }
 
+impl<C> BitShift for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn shr_assign_bits(&mut self, shift: usize) {
+        self.inner >>= shift;
+    }
+
+    fn shl_assign_bits(&mut self, shift: usize) {
+        for _ in 0..shift {
+            self.inner = self.inner.add_mod(&self.inner, &Self::MODULUS);
+        }
+    }
+}
+
+/// [`ScalarPrimitive`] is always in canonical form.
+impl<C> ToCanonical for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    type Canonical = Self;
+
+    fn to_canonical(&self) -> Self {
+        *self
+    }
+}
+
+impl<C> FromCanonical for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn from_canonical(canonical: &Self) -> CtOption<Self> {
+        CtOption::new(*canonical, Choice::from(1))
+    }
+}
+
 impl<C> IsHigh for ScalarPrimitive<C>
 where
     C: Curve,
@@ -447,3 +572,131 @@ where
             .ok_or_else(|| de::Error::custom("scalar out of range"))
     }
 }
+
+/// Compute `scalar * 256 + byte` modulo the curve order in constant time.
+fn shift_in_byte<C: Curve>(scalar: ScalarPrimitive<C>, byte: &u8) -> ScalarPrimitive<C> {
+    let mut ret = scalar;
+
+    for _ in 0..8 {
+        ret = ret + ret;
+    }
+
+    // The order of any curve is always larger than a single byte
+    ret + ScalarPrimitive::from(u64::from(*byte))
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use crate::{
+        bigint::{ArrayEncoding, U256},
+        dev::{MockCurve, ScalarPrimitive},
+        ops::{BitShift, ReduceBytes, ReduceWide},
+        scalar::CtOrd,
+        Curve,
+    };
+
+    #[test]
+    fn ct_ord() {
+        let a = ScalarPrimitive::from(1u64);
+        let b = -ScalarPrimitive::ONE;
+
+        assert!(bool::from(CtOrd::ct_lt(&a, &b)));
+        assert!(!bool::from(CtOrd::ct_lt(&b, &a)));
+        assert!(!bool::from(CtOrd::ct_lt(&a, &a)));
+        assert!(bool::from(CtOrd::ct_gt(&b, &a)));
+        assert!(!bool::from(CtOrd::ct_gt(&a, &b)));
+        assert!(!bool::from(CtOrd::ct_gt(&b, &b)));
+    }
+
+    #[test]
+    fn try_from_slice() {
+        let scalar = ScalarPrimitive::from(0x1234u64);
+        let bytes = scalar.to_be_bytes();
+        assert_eq!(ScalarPrimitive::try_from(bytes.as_slice()), Ok(scalar));
+        assert!(ScalarPrimitive::try_from(&bytes[1..]).is_err());
+
+        let order = MockCurve::ORDER.to_be_byte_array();
+        assert!(ScalarPrimitive::try_from(order.as_slice()).is_err());
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn heapless_vec() {
+        let scalar = ScalarPrimitive::from(0x1234u64);
+        let bytes = scalar.to_be_heapless_vec::<32>().unwrap();
+        assert_eq!(bytes.as_slice(), scalar.to_be_bytes().as_slice());
+
+        let bytes = scalar.to_le_heapless_vec::<64>().unwrap();
+        assert_eq!(bytes.as_slice(), scalar.to_le_bytes().as_slice());
+
+        assert!(scalar.to_be_heapless_vec::<31>().is_err());
+    }
+
+    #[test]
+    fn reduce_bytes() {
+        let order = MockCurve::ORDER.to_be_byte_array();
+        assert_eq!(
+            ScalarPrimitive::from_be_slice_reduced(&order),
+            ScalarPrimitive::ZERO
+        );
+
+        // n + 1 with an additional leading zero byte
+        let mut bytes = [0u8; 33];
+        bytes[1..].copy_from_slice(&order);
+        bytes[32] += 1;
+        assert_eq!(
+            ScalarPrimitive::from_be_slice_reduced(&bytes),
+            ScalarPrimitive::ONE
+        );
+
+        bytes.reverse();
+        assert_eq!(
+            ScalarPrimitive::from_le_slice_reduced(&bytes),
+            ScalarPrimitive::ONE
+        );
+
+        assert_eq!(
+            ScalarPrimitive::from_be_slice_reduced(&[0x12, 0x34]),
+            ScalarPrimitive::from(0x1234u64)
+        );
+        assert_eq!(
+            ScalarPrimitive::from_le_slice_reduced(&[]),
+            ScalarPrimitive::ZERO
+        );
+    }
+
+    #[test]
+    fn reduce_wide() {
+        let order = U256::ZERO.concat(&MockCurve::ORDER);
+        assert_eq!(
+            ScalarPrimitive::from_wide_uint_reduced(order),
+            ScalarPrimitive::ZERO
+        );
+
+        // 2^256 mod n = 2^256 - n, since n > 2^255
+        let wide = U256::ONE.concat(&U256::ZERO);
+        let expected = ScalarPrimitive::new(U256::ZERO.wrapping_sub(&MockCurve::ORDER)).unwrap();
+        assert_eq!(ScalarPrimitive::from_wide_uint_reduced(wide), expected);
+    }
+
+    #[test]
+    fn shr_assign_bits() {
+        let mut scalar = ScalarPrimitive::from(0x1234_5678u64);
+        scalar.shr_assign_bits(8);
+        assert_eq!(scalar, ScalarPrimitive::from(0x12_3456u64));
+    }
+
+    #[test]
+    fn shl_assign_bits() {
+        let mut scalar = ScalarPrimitive::from(0x1234u64);
+        scalar.shl_assign_bits(16);
+        assert_eq!(scalar, ScalarPrimitive::from(0x1234_0000u64));
+    }
+
+    #[test]
+    fn shl_assign_bits_reduces() {
+        let mut scalar = -ScalarPrimitive::ONE;
+        scalar.shl_assign_bits(1);
+        assert_eq!(scalar, -ScalarPrimitive::from(2u64));
+    }
+}
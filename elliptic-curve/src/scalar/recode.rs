@@ -0,0 +1,145 @@
+//! Signed-digit recoding of scalars for fixed-window multiplication.
+
+use super::{
+    wnaf::{read_bits, MAX_WIDTH, MIN_WIDTH},
+    ScalarPrimitive,
+};
+use crate::{Curve, Error, Result};
+
+#[cfg(feature = "alloc")]
+use {crate::bigint::Integer, alloc::vec::Vec};
+
+/// Recode a scalar into signed digits for fixed-window multiplication.
+///
+/// The signed recoding of a scalar `k` with window width `w` is a sequence
+/// of digits `d_i` such that `k = Σ d_i * 2^(w * i)`, where every digit is in
+/// the range `-2^(w - 1)..2^(w - 1)`. Unlike the wNAF, the number of digits
+/// only depends on the bit length of the scalar, and recoding runs in
+/// constant time, so it's suitable for use with secret scalars, e.g. with a
+/// table of the multiples `0, P, ..., 2^(w - 1)P` and a constant-time lookup.
+///
+/// Digits are written least significant first.
+pub trait RecodeSigned {
+    /// Write the signed recoding of this scalar with the given window `width`
+    /// into the provided buffer, padding any remaining digits with zeroes.
+    ///
+    /// The buffer must hold at least `ceil(n / width) + 1` digits, where `n`
+    /// is the bit length of the scalar's integer representation.
+    ///
+    /// Returns an error if `width` is not in the range `2..=8` or the buffer
+    /// is too small.
+    fn write_signed_digits(&self, width: usize, digits: &mut [i8]) -> Result<()>;
+
+    /// Compute the signed recoding of this scalar with the given window
+    /// `width`.
+    ///
+    /// Returns an error if `width` is not in the range `2..=8`.
+    #[cfg(feature = "alloc")]
+    fn recode_signed(&self, width: usize) -> Result<Vec<i8>>;
+}
+
+impl<C> RecodeSigned for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn write_signed_digits(&self, width: usize, digits: &mut [i8]) -> Result<()> {
+        signed_digits_from_le_bytes(&self.to_le_bytes(), width, digits)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn recode_signed(&self, width: usize) -> Result<Vec<i8>> {
+        if !(MIN_WIDTH..=MAX_WIDTH).contains(&width) {
+            return Err(Error);
+        }
+
+        let mut digits = vec![0i8; (C::Uint::BITS + width - 1) / width + 1];
+        self.write_signed_digits(width, &mut digits)?;
+        Ok(digits)
+    }
+}
+
+/// Compute the signed recoding of a little endian integer.
+fn signed_digits_from_le_bytes(bytes: &[u8], width: usize, digits: &mut [i8]) -> Result<()> {
+    if !(MIN_WIDTH..=MAX_WIDTH).contains(&width) {
+        return Err(Error);
+    }
+
+    let len = (bytes.len() * 8 + width - 1) / width + 1;
+
+    if digits.len() < len {
+        return Err(Error);
+    }
+
+    digits.iter_mut().for_each(|digit| *digit = 0);
+
+    let radix = 1i16 << width;
+    let mut carry = 0i16;
+
+    // Each window plus the incoming carry is in the range `0..=2^w`, and is
+    // recentered by subtracting `2^w` (and carrying one) if it's at least
+    // `2^(w - 1)`, which is computed without branching on its value.
+    for (i, digit) in digits[..len].iter_mut().enumerate() {
+        let window = read_bits(bytes, i * width, width) as i16 + carry;
+        carry = (window + radix / 2) >> width;
+        *digit = (window - (carry << width)) as i8;
+    }
+
+    Ok(())
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::RecodeSigned;
+    use crate::dev::ScalarPrimitive;
+
+    /// Reconstruct an integer from its signed digits.
+    fn from_signed_digits(digits: &[i8], width: usize) -> i128 {
+        digits
+            .iter()
+            .enumerate()
+            .filter(|(_, &digit)| digit != 0)
+            .map(|(i, &digit)| i128::from(digit) << (i * width))
+            .sum()
+    }
+
+    #[test]
+    fn round_trip() {
+        for n in [0u64, 1, 2, 7, 0x80, 0xff, 0xdead_beef, u64::MAX] {
+            for width in 2..=8 {
+                let digits = ScalarPrimitive::from(n).recode_signed(width).unwrap();
+                assert_eq!(from_signed_digits(&digits, width), i128::from(n));
+
+                for &digit in &digits {
+                    let bound = 1i16 << (width - 1);
+                    assert!((-bound..bound).contains(&i16::from(digit)));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn digit_count() {
+        for width in 2..=8 {
+            let zero = ScalarPrimitive::from(0u64).recode_signed(width).unwrap();
+            let max = ScalarPrimitive::from(u64::MAX)
+                .recode_signed(width)
+                .unwrap();
+            assert_eq!(zero.len(), max.len());
+        }
+    }
+
+    #[test]
+    fn invalid_width() {
+        let scalar = ScalarPrimitive::from(42u64);
+        assert!(scalar.recode_signed(1).is_err());
+        assert!(scalar.recode_signed(9).is_err());
+    }
+
+    #[test]
+    fn buffer_too_small() {
+        let mut digits = [0i8; 4];
+        assert!(ScalarPrimitive::from(1u64)
+            .write_signed_digits(4, &mut digits)
+            .is_err());
+    }
+}
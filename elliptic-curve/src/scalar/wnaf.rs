@@ -0,0 +1,235 @@
+//! Windowed non-adjacent form (wNAF) representation of scalars.
+
+use super::ScalarPrimitive;
+use crate::{Curve, Error, Result};
+
+#[cfg(feature = "alloc")]
+use {crate::bigint::Integer, alloc::vec::Vec};
+
+#[cfg(all(feature = "alloc", feature = "arithmetic"))]
+use {
+    crate::{CurveArithmetic, ProjectivePoint, Scalar},
+    group::Group,
+};
+
+/// Minimum supported window width.
+pub(super) const MIN_WIDTH: usize = 2;
+
+/// Maximum supported window width (so that digits fit in an `i8`).
+pub(super) const MAX_WIDTH: usize = 8;
+
+/// Compute the windowed non-adjacent form (wNAF) of a scalar.
+///
+/// The wNAF of a scalar `k` with window width `w` is a sequence of signed
+/// digits `d_i` such that `k = Σ d_i * 2^i`, where every non-zero digit is odd
+/// with `|d_i| < 2^(w - 1)`, and any `w` consecutive digits contain at most one
+/// non-zero digit.
+///
+/// Digits are written least significant first.
+pub trait ToWnaf {
+    /// Write the wNAF of this scalar with the given window `width` into the
+    /// provided buffer, padding any remaining digits with zeroes.
+    ///
+    /// A buffer one digit longer than the bit length of the scalar is always
+    /// sufficient.
+    ///
+    /// Returns an error if `width` is not in the range `2..=8` or the wNAF
+    /// does not fit in the buffer.
+    fn write_wnaf(&self, width: usize, digits: &mut [i8]) -> Result<()>;
+
+    /// Compute the wNAF of this scalar with the given window `width`.
+    ///
+    /// Returns an error if `width` is not in the range `2..=8`.
+    #[cfg(feature = "alloc")]
+    fn to_wnaf(&self, width: usize) -> Result<Vec<i8>>;
+}
+
+impl<C> ToWnaf for ScalarPrimitive<C>
+where
+    C: Curve,
+{
+    fn write_wnaf(&self, width: usize, digits: &mut [i8]) -> Result<()> {
+        wnaf_from_le_bytes(&self.to_le_bytes(), width, digits)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_wnaf(&self, width: usize) -> Result<Vec<i8>> {
+        let mut digits = vec![0i8; C::Uint::BITS + 1];
+        self.write_wnaf(width, &mut digits)?;
+        Ok(digits)
+    }
+}
+
+/// Variable-time scalar multiplication using the wNAF method.
+///
+/// Precomputes the odd multiples `P, 3P, ..., (2^(w - 1) - 1)P` of the
+/// point and then performs a double-and-add over the wNAF of the scalar,
+/// using point subtraction for negative digits.
+///
+/// ⚠️ WARNING!
+///
+/// This function is variable-time with respect to the scalar and is only
+/// suitable for use with public values, e.g. when verifying signatures.
+///
+/// Returns an error if `width` is not in the range `2..=8`.
+#[cfg(all(feature = "alloc", feature = "arithmetic"))]
+pub fn mul_wnaf<C>(
+    point: &ProjectivePoint<C>,
+    scalar: &Scalar<C>,
+    width: usize,
+) -> Result<ProjectivePoint<C>>
+where
+    C: CurveArithmetic,
+{
+    let digits = Into::<ScalarPrimitive<C>>::into(*scalar).to_wnaf(width)?;
+
+    let double = point.double();
+    let mut table = Vec::with_capacity(1 << (width - 2));
+    table.push(*point);
+
+    for i in 1..(1 << (width - 2)) {
+        let next = table[i - 1] + double;
+        table.push(next);
+    }
+
+    let mut ret = ProjectivePoint::<C>::identity();
+
+    for &digit in digits.iter().rev() {
+        ret = ret.double();
+
+        if digit > 0 {
+            ret += table[(digit as usize) / 2];
+        } else if digit < 0 {
+            ret -= table[(-(digit as i16) as usize) / 2];
+        }
+    }
+
+    Ok(ret)
+}
+
+/// Compute the wNAF of a little endian integer.
+fn wnaf_from_le_bytes(bytes: &[u8], width: usize, digits: &mut [i8]) -> Result<()> {
+    if !(MIN_WIDTH..=MAX_WIDTH).contains(&width) {
+        return Err(Error);
+    }
+
+    let window_size = 1u16 << width;
+    let window_mask = window_size - 1;
+    let bit_len = bytes.len() * 8;
+
+    digits.iter_mut().for_each(|digit| *digit = 0);
+
+    let mut pos = 0;
+    let mut carry = 0u16;
+
+    while pos < bit_len || carry != 0 {
+        let window = carry + (read_bits(bytes, pos, width) & window_mask);
+
+        if window & 1 == 0 {
+            pos += 1;
+            continue;
+        }
+
+        let digit = if window < window_size / 2 {
+            carry = 0;
+            window as i16
+        } else {
+            carry = 1;
+            window as i16 - window_size as i16
+        };
+
+        *digits.get_mut(pos).ok_or(Error)? = digit as i8;
+        pos += width;
+    }
+
+    Ok(())
+}
+
+/// Read up to 16 bits starting at bit position `pos` of a little endian
+/// integer, treating bits beyond its length as zero.
+pub(super) fn read_bits(bytes: &[u8], pos: usize, count: usize) -> u16 {
+    (0..count).fold(0, |acc, i| {
+        let bit = bytes
+            .get((pos + i) / 8)
+            .map(|byte| (byte >> ((pos + i) % 8)) & 1)
+            .unwrap_or(0);
+
+        acc | (u16::from(bit) << i)
+    })
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::{mul_wnaf, ToWnaf};
+    use crate::dev::{test_points, test_scalars, MockCurve, Scalar, ScalarPrimitive};
+    use ff::Field;
+
+    /// Reconstruct an integer from its wNAF digits.
+    fn from_wnaf(digits: &[i8]) -> i128 {
+        digits
+            .iter()
+            .enumerate()
+            .filter(|(_, &digit)| digit != 0)
+            .map(|(i, &digit)| i128::from(digit) << i)
+            .sum()
+    }
+
+    #[test]
+    fn round_trip() {
+        for n in [0u64, 1, 2, 7, 0xff, 0xdead_beef, u64::MAX] {
+            for width in 2..=8 {
+                let digits = ScalarPrimitive::from(n).to_wnaf(width).unwrap();
+                assert_eq!(from_wnaf(&digits), i128::from(n));
+            }
+        }
+    }
+
+    #[test]
+    fn non_adjacent() {
+        let width = 5;
+        let digits = ScalarPrimitive::from(0x0123_4567_89ab_cdefu64)
+            .to_wnaf(width)
+            .unwrap();
+
+        for window in digits.windows(width) {
+            assert!(window.iter().filter(|&&digit| digit != 0).count() <= 1);
+        }
+
+        for &digit in &digits {
+            assert!(digit == 0 || (digit % 2 != 0 && digit.abs() < 1 << (width - 1)));
+        }
+    }
+
+    #[test]
+    fn invalid_width() {
+        let scalar = ScalarPrimitive::from(42u64);
+        assert!(scalar.to_wnaf(1).is_err());
+        assert!(scalar.to_wnaf(9).is_err());
+    }
+
+    #[test]
+    fn buffer_too_small() {
+        let mut digits = [0i8; 4];
+        assert!(ScalarPrimitive::from(0xffu64)
+            .write_wnaf(4, &mut digits)
+            .is_err());
+    }
+
+    #[test]
+    fn mul() {
+        let scalars = test_scalars();
+
+        for point in test_points() {
+            for scalar in scalars {
+                for width in 2..=8 {
+                    assert_eq!(
+                        mul_wnaf::<MockCurve>(&point, &scalar, width).unwrap(),
+                        point * scalar
+                    );
+                }
+            }
+
+            assert!(mul_wnaf::<MockCurve>(&point, &Scalar::ONE, 9).is_err());
+        }
+    }
+}
@@ -0,0 +1,395 @@
+//! Schnorr signatures and proofs of knowledge, generic over any prime order
+//! curve with a [`ChallengeScalar`] (i.e. hash-to-scalar) implementation.
+//!
+//! Two variants are provided:
+//!
+//! - [`Signature`]: signatures in the style of [BIP340], with x-only public
+//!   keys and nonce points, and implicitly even y-coordinates.
+//! - [`SchnorrProof`]: non-interactive zero-knowledge proofs of knowledge of
+//!   a discrete logarithm as described in [RFC 8235].
+//!
+//! Instead of the tagged SHA-256 hashes of BIP340, all hashes are computed
+//! using [`ChallengeScalar`] with a caller-provided domain separation tag,
+//! so the signatures produced for secp256k1 are *not* interoperable with
+//! BIP340. In exchange, curve implementations only need to provide
+//! hash-to-scalar support to get Schnorr signatures.
+//!
+//! [BIP340]: https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki
+//! [RFC 8235]: https://www.rfc-editor.org/rfc/rfc8235
+
+use crate::{
+    hash2curve::ExpandMsg, ops::ChallengeScalar, AffineXCoordinate, AffineYIsOdd, CurveArithmetic,
+    Error, FieldBytes, NonZeroScalar, ProjectivePoint, PublicKey, Result, Scalar, XOnlyPublicKey,
+};
+use ff::{Field, PrimeField};
+use group::{Curve as _, Group, GroupEncoding};
+use rand_core::CryptoRngCore;
+use subtle::{ConditionallySelectable, ConstantTimeEq};
+use zeroize::{Zeroize, Zeroizing};
+
+/// Prefix of the nonce derivation messages of [`Signature::sign`].
+const NONCE_PREFIX: u8 = 0x00;
+
+/// Prefix of the challenge messages of [`Signature`].
+const CHALLENGE_PREFIX: u8 = 0x01;
+
+/// Schnorr signature in the style of BIP340.
+///
+/// The signature consists of the x-coordinate of the nonce point 𝑹, which
+/// has an even y-coordinate, and the response 𝑠 = 𝑘 + 𝑒 × 𝑑, where 𝑑 is the
+/// secret key negated if necessary for the public key to have an even
+/// y-coordinate, and 𝑒 is the challenge derived from 𝑹, the x-only public
+/// key and the message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Signature<C>
+where
+    C: CurveArithmetic,
+{
+    /// Affine x-coordinate of 𝑹.
+    r: FieldBytes<C>,
+
+    /// Response 𝑠.
+    s: Scalar<C>,
+}
+
+impl<C> Signature<C>
+where
+    C: CurveArithmetic,
+    Scalar<C>: ChallengeScalar,
+{
+    /// Create a signature from the x-coordinate of its nonce point and its
+    /// response, e.g. after deserializing it.
+    pub fn new(r: FieldBytes<C>, s: Scalar<C>) -> Self {
+        Self { r, s }
+    }
+
+    /// Sign `msg` using `secret_key` and the domain separation tag `dst`.
+    ///
+    /// The nonce is derived deterministically from the secret key, public
+    /// key, `aux_rand` and message. As in BIP340, `aux_rand` should be 32
+    /// bytes of fresh randomness to protect against fault attacks, or may be
+    /// all zeroes for fully deterministic signatures. It has a fixed length
+    /// so that the nonce derivation input is encoded unambiguously.
+    ///
+    /// Returns an error if `X` rejects the domain separation tag `dst`.
+    pub fn sign<'a, X>(
+        secret_key: &NonZeroScalar<C>,
+        msg: &[u8],
+        aux_rand: &[u8; 32],
+        dst: &'a [u8],
+    ) -> Result<Self>
+    where
+        X: ExpandMsg<'a>,
+    {
+        let public_key = PublicKey::from_secret_scalar(secret_key);
+        let (x_only, negated) = XOnlyPublicKey::from_public_key(&public_key);
+        let d = Zeroizing::new(Scalar::<C>::conditional_select(
+            secret_key,
+            &-*secret_key.as_ref(),
+            negated,
+        ));
+        let p = x_only.to_bytes();
+
+        let mut d_bytes = d.to_repr();
+        let k = Scalar::<C>::challenge::<X>(&[&[NONCE_PREFIX], &d_bytes, &p, aux_rand, msg], dst);
+        d_bytes.as_mut_slice().zeroize();
+        let k = Zeroizing::new(k?);
+
+        // A zero nonce would reveal the secret key.
+        if bool::from(k.is_zero()) {
+            return Err(Error);
+        }
+
+        let big_r = (ProjectivePoint::<C>::generator() * *k).to_affine();
+        let k = Zeroizing::new(Scalar::<C>::conditional_select(&k, &-*k, big_r.y_is_odd()));
+        let r = big_r.x();
+
+        let e = challenge::<C, X>(&r, &p, msg, dst)?;
+        let s = *k + e * *d;
+
+        Ok(Self::new(r, s))
+    }
+
+    /// Verify this signature over `msg` using `public_key` and the domain
+    /// separation tag `dst` it was created with.
+    pub fn verify<'a, X>(
+        &self,
+        public_key: &XOnlyPublicKey<C>,
+        msg: &[u8],
+        dst: &'a [u8],
+    ) -> Result<()>
+    where
+        X: ExpandMsg<'a>,
+    {
+        let e = challenge::<C, X>(&self.r, &public_key.to_bytes(), msg, dst)?;
+
+        // 𝑮 × 𝑠 − 𝑷 × 𝑒 = 𝑮 × (𝑘 + 𝑒 × 𝑑) − 𝑮 × 𝑑 × 𝑒 = 𝑹
+        let big_r = ProjectivePoint::<C>::generator() * self.s
+            - ProjectivePoint::<C>::from(*public_key.as_affine()) * e;
+
+        if bool::from(big_r.is_identity()) {
+            return Err(Error);
+        }
+
+        let big_r = big_r.to_affine();
+
+        if bool::from(!big_r.y_is_odd() & big_r.x().ct_eq(&self.r)) {
+            Ok(())
+        } else {
+            Err(Error)
+        }
+    }
+
+    /// Affine x-coordinate of the nonce point 𝑹.
+    pub fn r(&self) -> &FieldBytes<C> {
+        &self.r
+    }
+
+    /// Response 𝑠.
+    pub fn s(&self) -> &Scalar<C> {
+        &self.s
+    }
+}
+
+/// Derive the challenge 𝑒 of a [`Signature`].
+fn challenge<'a, C, X>(
+    r: &FieldBytes<C>,
+    p: &FieldBytes<C>,
+    msg: &[u8],
+    dst: &'a [u8],
+) -> Result<Scalar<C>>
+where
+    C: CurveArithmetic,
+    Scalar<C>: ChallengeScalar,
+    X: ExpandMsg<'a>,
+{
+    Scalar::<C>::challenge::<X>(&[&[CHALLENGE_PREFIX], r, p, msg], dst)
+}
+
+/// Non-interactive zero-knowledge proof of knowledge of the discrete
+/// logarithm of 𝑨 = 𝑮 × 𝑎, as described in RFC 8235.
+///
+/// The proof consists of the commitment 𝑽 = 𝑮 × 𝑣 for a random nonce 𝑣, and
+/// the response 𝑟 = 𝑣 − 𝑎 × 𝑐, where the challenge 𝑐 is derived from 𝑮, 𝑽,
+/// 𝑨 and the context-specific `other_info` (e.g. the user ID) using a domain
+/// separation tag, which the prover and verifier must agree on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SchnorrProof<C>
+where
+    C: CurveArithmetic,
+{
+    /// Commitment 𝑽.
+    commitment: ProjectivePoint<C>,
+
+    /// Response 𝑟.
+    response: Scalar<C>,
+}
+
+impl<C> SchnorrProof<C>
+where
+    C: CurveArithmetic,
+    Scalar<C>: ChallengeScalar,
+    ProjectivePoint<C>: GroupEncoding,
+{
+    /// Create a proof from its commitment and response, e.g. after
+    /// deserializing it.
+    pub fn new(commitment: ProjectivePoint<C>, response: Scalar<C>) -> Self {
+        Self {
+            commitment,
+            response,
+        }
+    }
+
+    /// Prove knowledge of the discrete logarithm `a` of 𝑨 = 𝑮 × `a` with
+    /// respect to the generator `g`.
+    ///
+    /// Returns an error if `X` rejects the domain separation tag `dst`.
+    pub fn prove<'a, X>(
+        a: &NonZeroScalar<C>,
+        g: &ProjectivePoint<C>,
+        other_info: &[u8],
+        dst: &'a [u8],
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<Self>
+    where
+        X: ExpandMsg<'a>,
+    {
+        let big_a = *g * a.as_ref();
+
+        let mut v = *NonZeroScalar::<C>::random(rng);
+        let commitment = *g * v;
+        let c = proof_challenge::<C, X>(g, &commitment, &big_a, other_info, dst)?;
+        let response = v - *a.as_ref() * c;
+        v.zeroize();
+
+        Ok(Self::new(commitment, response))
+    }
+
+    /// Verify that the prover knows the discrete logarithm of `big_a` with
+    /// respect to the generator `g`, using the `other_info` and domain
+    /// separation tag `dst` the proof was created with.
+    ///
+    /// As recommended by RFC 8235, this also checks that `big_a` is not the
+    /// identity.
+    pub fn verify<'a, X>(
+        &self,
+        g: &ProjectivePoint<C>,
+        big_a: &ProjectivePoint<C>,
+        other_info: &[u8],
+        dst: &'a [u8],
+    ) -> Result<()>
+    where
+        X: ExpandMsg<'a>,
+    {
+        if bool::from(big_a.is_identity()) {
+            return Err(Error);
+        }
+
+        let c = proof_challenge::<C, X>(g, &self.commitment, big_a, other_info, dst)?;
+
+        // 𝑮 × 𝑟 + 𝑨 × 𝑐 = 𝑮 × (𝑣 − 𝑎 × 𝑐) + 𝑮 × 𝑎 × 𝑐 = 𝑽
+        let commitment = *g * self.response + *big_a * c;
+
+        if bool::from(commitment.ct_eq(&self.commitment)) {
+            Ok(())
+        } else {
+            Err(Error)
+        }
+    }
+
+    /// Commitment 𝑽.
+    pub fn commitment(&self) -> &ProjectivePoint<C> {
+        &self.commitment
+    }
+
+    /// Response 𝑟.
+    pub fn response(&self) -> &Scalar<C> {
+        &self.response
+    }
+}
+
+/// Derive the challenge 𝑐 of a [`SchnorrProof`].
+fn proof_challenge<'a, C, X>(
+    g: &ProjectivePoint<C>,
+    v: &ProjectivePoint<C>,
+    a: &ProjectivePoint<C>,
+    other_info: &[u8],
+    dst: &'a [u8],
+) -> Result<Scalar<C>>
+where
+    C: CurveArithmetic,
+    Scalar<C>: ChallengeScalar,
+    ProjectivePoint<C>: GroupEncoding,
+    X: ExpandMsg<'a>,
+{
+    let [g, v, a] = [g, v, a].map(GroupEncoding::to_bytes);
+    Scalar::<C>::challenge::<X>(&[g.as_ref(), v.as_ref(), a.as_ref(), other_info], dst)
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::{SchnorrProof, Signature};
+    use crate::{
+        dev::{MockCurve, NonZeroScalar, ProjectivePoint, PublicKey, Scalar},
+        hash2curve::ExpandMsgXmd,
+        XOnlyPublicKey,
+    };
+    use group::Group;
+    use rand_core::{OsRng, RngCore};
+    use sha2::Sha256;
+
+    type Proof = SchnorrProof<MockCurve>;
+    type Sig = Signature<MockCurve>;
+    type Xmd = ExpandMsgXmd<Sha256>;
+
+    const DST: &[u8] = b"SCHNORR-TEST-V1";
+
+    fn keypair() -> (NonZeroScalar, XOnlyPublicKey<MockCurve>) {
+        let secret_key = NonZeroScalar::random(&mut OsRng);
+        let public_key = PublicKey::from_secret_scalar(&secret_key);
+        (secret_key, XOnlyPublicKey::from(public_key))
+    }
+
+    #[test]
+    fn sign_and_verify() {
+        // Cover keys and nonces with both odd and even y-coordinates
+        for _ in 0..16 {
+            let (secret_key, public_key) = keypair();
+            let mut aux_rand = [0u8; 32];
+            OsRng.fill_bytes(&mut aux_rand);
+
+            let sig = Sig::sign::<Xmd>(&secret_key, b"hello", &aux_rand, DST).unwrap();
+            sig.verify::<Xmd>(&public_key, b"hello", DST).unwrap();
+
+            let sig = Sig::new(*sig.r(), *sig.s());
+            sig.verify::<Xmd>(&public_key, b"hello", DST).unwrap();
+            assert!(sig
+                .verify::<Xmd>(&public_key, b"hello", b"OTHER-V1")
+                .is_err());
+        }
+    }
+
+    #[test]
+    fn sign_deterministic() {
+        let (secret_key, _) = keypair();
+        let sig = Sig::sign::<Xmd>(&secret_key, b"hello", &[0; 32], DST).unwrap();
+
+        assert_eq!(
+            Sig::sign::<Xmd>(&secret_key, b"hello", &[0; 32], DST).unwrap(),
+            sig
+        );
+        assert_ne!(
+            Sig::sign::<Xmd>(&secret_key, b"hello", &[1; 32], DST).unwrap(),
+            sig
+        );
+    }
+
+    #[test]
+    fn reject_wrong_message() {
+        let (secret_key, public_key) = keypair();
+        let sig = Sig::sign::<Xmd>(&secret_key, b"hello", &[0; 32], DST).unwrap();
+        assert!(sig.verify::<Xmd>(&public_key, b"hellp", DST).is_err());
+        assert!(sig.verify::<Xmd>(&public_key, b"", DST).is_err());
+    }
+
+    #[test]
+    fn reject_wrong_key() {
+        let (secret_key, _) = keypair();
+        let (_, other_public_key) = keypair();
+        let sig = Sig::sign::<Xmd>(&secret_key, b"hello", &[0; 32], DST).unwrap();
+        assert!(sig.verify::<Xmd>(&other_public_key, b"hello", DST).is_err());
+    }
+
+    #[test]
+    fn prove_and_verify() {
+        let g = ProjectivePoint::generator() * Scalar::from(1u64);
+        let a = NonZeroScalar::random(&mut OsRng);
+        let big_a = g * a.as_ref();
+
+        let proof = Proof::prove::<Xmd>(&a, &g, b"alice", DST, &mut OsRng).unwrap();
+        proof.verify::<Xmd>(&g, &big_a, b"alice", DST).unwrap();
+
+        let proof = Proof::new(*proof.commitment(), *proof.response());
+        proof.verify::<Xmd>(&g, &big_a, b"alice", DST).unwrap();
+        assert!(proof.verify::<Xmd>(&g, &big_a, b"bob", DST).is_err());
+        assert!(proof
+            .verify::<Xmd>(&g, &big_a, b"alice", b"OTHER-V1")
+            .is_err());
+    }
+
+    #[test]
+    fn reject_wrong_statement() {
+        let g = ProjectivePoint::generator() * Scalar::from(1u64);
+        let a = NonZeroScalar::random(&mut OsRng);
+        let big_a = g * a.as_ref();
+        let proof = Proof::prove::<Xmd>(&a, &g, &[], DST, &mut OsRng).unwrap();
+
+        assert!(proof.verify::<Xmd>(&g, &(big_a + g), &[], DST).is_err());
+        assert!(proof
+            .verify::<Xmd>(&g, &ProjectivePoint::identity(), &[], DST)
+            .is_err());
+        assert!(proof
+            .verify::<Xmd>(&(g * Scalar::from(2u64)), &big_a, &[], DST)
+            .is_err());
+    }
+}
@@ -4,12 +4,17 @@
 
 pub use sec1::point::{Coordinates, ModulusSize, Tag};
 
-use crate::{Curve, FieldSize, Result, SecretKey};
+#[cfg(feature = "pkcs8-sec1")]
+pub use sec1::DecodeEcPrivateKey;
+#[cfg(all(feature = "alloc", feature = "pkcs8-sec1"))]
+pub use sec1::EncodeEcPrivateKey;
+
+use crate::{Curve, Error, FieldSize, Result, SecretKey};
 use generic_array::GenericArray;
 use subtle::CtOption;
 
 #[cfg(feature = "arithmetic")]
-use crate::{AffinePoint, CurveArithmetic, Error};
+use crate::{AffinePoint, CurveArithmetic};
 
 /// Encoded elliptic curve point with point compression.
 pub type CompressedPoint<C> = GenericArray<u8, CompressedPointSize<C>>;
@@ -26,6 +31,83 @@ pub type UncompressedPoint<C> = GenericArray<u8, UncompressedPointSize<C>>;
 /// Size of an uncompressed elliptic curve point.
 pub type UncompressedPointSize<C> = <FieldSize<C> as ModulusSize>::UncompressedPointSize;
 
+/// Format of a SEC1 encoded curve point, as identified by its tag byte.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub enum PointFormat {
+    /// Compressed point, i.e. the x-coordinate and the sign of the
+    /// y-coordinate (`0x02` or `0x03`).
+    Compressed = 1,
+
+    /// Uncompressed point, i.e. both coordinates (`0x04`).
+    Uncompressed = 2,
+
+    /// Hybrid point, i.e. both coordinates along with the sign of the
+    /// y-coordinate (`0x06` or `0x07`), as described in ANSI X9.62.
+    Hybrid = 4,
+
+    /// Compact point, i.e. the x-coordinate only (`0x05`).
+    Compact = 8,
+}
+
+impl PointFormat {
+    /// Identify the format of a SEC1 encoded point from its tag byte.
+    ///
+    /// Returns [`Error`] for the identity (`0x00`) or an unknown tag.
+    pub fn from_tag(tag: u8) -> Result<Self> {
+        match tag {
+            0x02 | 0x03 => Ok(Self::Compressed),
+            0x04 => Ok(Self::Uncompressed),
+            0x05 => Ok(Self::Compact),
+            0x06 | 0x07 => Ok(Self::Hybrid),
+            _ => Err(Error),
+        }
+    }
+}
+
+/// Set of [`PointFormat`]s which are accepted when decoding a SEC1 encoded
+/// point, allowing protocols which mandate a particular encoding (e.g. the
+/// uncompressed points of TLS 1.3) to enforce it.
+///
+/// The [`Default`] policy accepts compressed and uncompressed points.
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq)]
+pub struct DecodingPolicy {
+    /// Bitmask of the allowed formats.
+    formats: u8,
+}
+
+impl DecodingPolicy {
+    /// Policy which accepts points in any format.
+    pub const ANY: Self = Self::only(PointFormat::Compressed)
+        .allow(PointFormat::Uncompressed)
+        .allow(PointFormat::Hybrid)
+        .allow(PointFormat::Compact);
+
+    /// Policy which only accepts points in the given format.
+    pub const fn only(format: PointFormat) -> Self {
+        Self {
+            formats: format as u8,
+        }
+    }
+
+    /// Additionally accept points in the given format.
+    pub const fn allow(self, format: PointFormat) -> Self {
+        Self {
+            formats: self.formats | format as u8,
+        }
+    }
+
+    /// Are points in the given format accepted by this policy?
+    pub const fn allows(self, format: PointFormat) -> bool {
+        self.formats & format as u8 != 0
+    }
+}
+
+impl Default for DecodingPolicy {
+    fn default() -> Self {
+        Self::only(PointFormat::Compressed).allow(PointFormat::Uncompressed)
+    }
+}
+
 /// Trait for deserializing a value from a SEC1 encoded curve point.
 ///
 /// This is intended for use with the `AffinePoint` type for a given elliptic curve.
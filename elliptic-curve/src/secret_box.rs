@@ -0,0 +1,89 @@
+//! Heap-allocated secret values.
+
+use alloc::boxed::Box;
+use core::{
+    fmt::{self, Debug},
+    ops::{Deref, DerefMut},
+};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Heap-allocated secret value which is zeroized on drop.
+///
+/// This is useful for storing secrets such as
+/// [`ScalarPrimitive`][`crate::ScalarPrimitive`] on the heap (e.g. to avoid
+/// leaving copies of them on the stack when they are moved), while still
+/// ensuring they are erased from memory when no longer in use. Like the other secret-bearing types in this crate, its
+/// `Debug` impl doesn't reveal its contents.
+pub struct SecretBox<T: Zeroize> {
+    inner: Box<T>,
+}
+
+impl<T: Zeroize> SecretBox<T> {
+    /// Move the given secret value to the heap.
+    pub fn new(value: T) -> Self {
+        Self {
+            inner: Box::new(value),
+        }
+    }
+}
+
+impl<T: Zeroize> From<Box<T>> for SecretBox<T> {
+    fn from(inner: Box<T>) -> Self {
+        Self { inner }
+    }
+}
+
+impl<T: Zeroize + Clone> Clone for SecretBox<T> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<T: Zeroize> AsRef<T> for SecretBox<T> {
+    fn as_ref(&self) -> &T {
+        &self.inner
+    }
+}
+
+impl<T: Zeroize> AsMut<T> for SecretBox<T> {
+    fn as_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+}
+
+impl<T: Zeroize> Deref for SecretBox<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.inner
+    }
+}
+
+impl<T: Zeroize> DerefMut for SecretBox<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+}
+
+impl<T: Zeroize> Debug for SecretBox<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(core::any::type_name::<Self>())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: Zeroize> Zeroize for SecretBox<T> {
+    fn zeroize(&mut self) {
+        self.inner.zeroize();
+    }
+}
+
+impl<T: Zeroize> ZeroizeOnDrop for SecretBox<T> {}
+
+impl<T: Zeroize> Drop for SecretBox<T> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
@@ -0,0 +1,410 @@
+//! Shamir secret sharing over the scalar field.
+//!
+//! A secret scalar is embedded as the constant term of a random polynomial
+//! of degree `t - 1`, and each participant receives the evaluation of the
+//! polynomial at their (nonzero) index. Any `t` of the resulting [`Share`]s
+//! can be used to recover the secret via Lagrange interpolation, while fewer
+//! than `t` shares reveal nothing about it.
+//!
+//! [Feldman verifiable secret sharing][Feldman] is supported via
+//! [`FeldmanCommitment`]: the dealer publishes commitments to the
+//! coefficients of the polynomial, which allows each participant to check
+//! that their share is consistent with those of the other participants.
+//!
+//! This is a building block for threshold signature schemes such as FROST.
+//!
+//! [Feldman]: https://www.cs.umd.edu/~gasarch/TOPICS/secretsharing/feldmanVSS.pdf
+
+use crate::{CurveArithmetic, Error, NonZeroScalar, ProjectivePoint, Result, Scalar};
+use alloc::vec::Vec;
+use ff::Field;
+use group::Group;
+use rand_core::CryptoRngCore;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// Split `secret` into `n` shares, any `threshold` of which can be used to
+/// recover it.
+///
+/// Returns the shares, with indices `1..=n`, along with a
+/// [`FeldmanCommitment`] which can be used to verify them.
+///
+/// Returns an error if `threshold` is zero or exceeds `n`.
+pub fn split<C>(
+    secret: &NonZeroScalar<C>,
+    threshold: usize,
+    n: u32,
+    rng: &mut impl CryptoRngCore,
+) -> Result<(Vec<Share<C>>, FeldmanCommitment<C>)>
+where
+    C: CurveArithmetic,
+{
+    if threshold > n as usize {
+        return Err(Error);
+    }
+
+    let polynomial = Polynomial::random(**secret, threshold, rng)?;
+    let shares = (1..=n)
+        .map(|index| polynomial.share(index))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok((shares, polynomial.commitment()))
+}
+
+/// Recover the secret from a set of at least `threshold` shares using
+/// Lagrange interpolation.
+///
+/// Returns an error if no shares are given, or if any two of them have the
+/// same index.
+///
+/// Note that giving fewer than `threshold` shares produces an unrelated
+/// value rather than an error, as this can't be detected from the shares
+/// themselves.
+pub fn combine<C>(shares: &[Share<C>]) -> Result<Scalar<C>>
+where
+    C: CurveArithmetic,
+{
+    if shares.is_empty() {
+        return Err(Error);
+    }
+
+    let indices = shares.iter().map(Share::index).collect::<Vec<_>>();
+    let mut secret = Scalar::<C>::ZERO;
+
+    for share in shares {
+        secret += lagrange_coefficient::<C>(share.index, &indices)? * share.value;
+    }
+
+    Ok(secret)
+}
+
+/// Compute the Lagrange coefficient λᵢ for the participant with the given
+/// `index`, for interpolating at zero from the shares of the participants
+/// with the given `indices`:
+///
+/// λᵢ = ∏ⱼ 𝑥ⱼ / (𝑥ⱼ − 𝑥ᵢ) for all 𝑗 ≠ 𝑖
+///
+/// `indices` must contain `index`. This is exposed so threshold signature
+/// schemes can apply the coefficients to their partial signatures.
+///
+/// Returns an error if `indices` contains duplicates or zero.
+pub fn lagrange_coefficient<C>(index: u32, indices: &[u32]) -> Result<Scalar<C>>
+where
+    C: CurveArithmetic,
+{
+    let x_i = Scalar::<C>::from(u64::from(index));
+    let mut numerator = Scalar::<C>::ONE;
+    let mut denominator = Scalar::<C>::ONE;
+    let mut found = false;
+
+    for (i, &other) in indices.iter().enumerate() {
+        if other == 0 || indices[..i].contains(&other) {
+            return Err(Error);
+        }
+
+        if other == index {
+            found = true;
+            continue;
+        }
+
+        let x_j = Scalar::<C>::from(u64::from(other));
+        numerator *= x_j;
+        denominator *= x_j - x_i;
+    }
+
+    if !found {
+        return Err(Error);
+    }
+
+    let inverse = Option::<Scalar<C>>::from(denominator.invert()).ok_or(Error)?;
+    Ok(numerator * inverse)
+}
+
+/// Secret polynomial over the scalar field, whose constant term is the
+/// shared secret.
+///
+/// Coefficients are zeroized on drop.
+#[derive(Clone)]
+pub struct Polynomial<C>
+where
+    C: CurveArithmetic,
+{
+    /// Coefficients, starting from the constant term.
+    coefficients: Vec<Scalar<C>>,
+}
+
+impl<C> Polynomial<C>
+where
+    C: CurveArithmetic,
+{
+    /// Generate a random polynomial of degree `threshold - 1` whose constant
+    /// term is `secret`.
+    ///
+    /// Returns an error if `threshold` is zero.
+    pub fn random(
+        secret: Scalar<C>,
+        threshold: usize,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<Self> {
+        if threshold == 0 {
+            return Err(Error);
+        }
+
+        let mut coefficients = Vec::with_capacity(threshold);
+        coefficients.push(secret);
+        coefficients.extend((1..threshold).map(|_| Scalar::<C>::random(&mut *rng)));
+        Ok(Self { coefficients })
+    }
+
+    /// Number of shares required to recover the secret, i.e. the number of
+    /// coefficients.
+    pub fn threshold(&self) -> usize {
+        self.coefficients.len()
+    }
+
+    /// Evaluate the polynomial at `x` using Horner's method.
+    pub fn evaluate(&self, x: &Scalar<C>) -> Scalar<C> {
+        self.coefficients
+            .iter()
+            .rev()
+            .fold(Scalar::<C>::ZERO, |acc, coefficient| acc * x + coefficient)
+    }
+
+    /// Compute the share for the participant with the given `index`.
+    ///
+    /// Returns an error if `index` is zero, as that would reveal the secret.
+    pub fn share(&self, index: u32) -> Result<Share<C>> {
+        if index == 0 {
+            return Err(Error);
+        }
+
+        let value = self.evaluate(&Scalar::<C>::from(u64::from(index)));
+        Ok(Share { index, value })
+    }
+
+    /// Compute the Feldman commitment to this polynomial's coefficients.
+    pub fn commitment(&self) -> FeldmanCommitment<C> {
+        let commitments = self
+            .coefficients
+            .iter()
+            .map(|coefficient| ProjectivePoint::<C>::generator() * coefficient)
+            .collect();
+
+        FeldmanCommitment { commitments }
+    }
+}
+
+impl<C> Zeroize for Polynomial<C>
+where
+    C: CurveArithmetic,
+{
+    fn zeroize(&mut self) {
+        self.coefficients.zeroize();
+    }
+}
+
+impl<C> Drop for Polynomial<C>
+where
+    C: CurveArithmetic,
+{
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<C> ZeroizeOnDrop for Polynomial<C> where C: CurveArithmetic {}
+
+/// Share of a secret: the evaluation of the secret polynomial at the
+/// participant's nonzero index.
+///
+/// The value is zeroized on drop.
+#[derive(Clone)]
+pub struct Share<C>
+where
+    C: CurveArithmetic,
+{
+    /// Index of the participant holding this share.
+    index: u32,
+
+    /// Value of the polynomial at `index`.
+    value: Scalar<C>,
+}
+
+impl<C> Share<C>
+where
+    C: CurveArithmetic,
+{
+    /// Create a share from its index and value, e.g. after deserializing it.
+    ///
+    /// Returns an error if `index` is zero.
+    pub fn new(index: u32, value: Scalar<C>) -> Result<Self> {
+        if index == 0 {
+            return Err(Error);
+        }
+
+        Ok(Self { index, value })
+    }
+
+    /// Index of the participant holding this share.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Value of this share.
+    pub fn value(&self) -> &Scalar<C> {
+        &self.value
+    }
+}
+
+impl<C> Zeroize for Share<C>
+where
+    C: CurveArithmetic,
+{
+    fn zeroize(&mut self) {
+        self.value.zeroize();
+    }
+}
+
+impl<C> Drop for Share<C>
+where
+    C: CurveArithmetic,
+{
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<C> ZeroizeOnDrop for Share<C> where C: CurveArithmetic {}
+
+/// Feldman commitment to a secret polynomial: its coefficients multiplied by
+/// the generator.
+///
+/// The first commitment is the public key corresponding to the secret.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FeldmanCommitment<C>
+where
+    C: CurveArithmetic,
+{
+    /// Commitments to each coefficient, starting from the constant term.
+    commitments: Vec<ProjectivePoint<C>>,
+}
+
+impl<C> FeldmanCommitment<C>
+where
+    C: CurveArithmetic,
+{
+    /// Create a commitment from the commitments to each coefficient, starting
+    /// from the constant term.
+    ///
+    /// Returns an error if `commitments` is empty.
+    pub fn new(commitments: Vec<ProjectivePoint<C>>) -> Result<Self> {
+        if commitments.is_empty() {
+            return Err(Error);
+        }
+
+        Ok(Self { commitments })
+    }
+
+    /// Commitments to each coefficient, starting from the constant term.
+    pub fn commitments(&self) -> &[ProjectivePoint<C>] {
+        &self.commitments
+    }
+
+    /// Public key corresponding to the shared secret, i.e. the commitment to
+    /// the constant term.
+    pub fn public_key(&self) -> ProjectivePoint<C> {
+        self.commitments[0]
+    }
+
+    /// Number of shares required to recover the secret.
+    pub fn threshold(&self) -> usize {
+        self.commitments.len()
+    }
+
+    /// Verify that the given share is consistent with this commitment, i.e.
+    /// that 𝑮 × 𝑦ᵢ = ∑ⱼ 𝑪ⱼ × 𝑥ᵢʲ.
+    pub fn verify(&self, share: &Share<C>) -> Result<()> {
+        let x = Scalar::<C>::from(u64::from(share.index));
+        let expected = self
+            .commitments
+            .iter()
+            .rev()
+            .fold(ProjectivePoint::<C>::identity(), |acc, commitment| {
+                acc * x + commitment
+            });
+
+        if ProjectivePoint::<C>::generator() * share.value == expected {
+            Ok(())
+        } else {
+            Err(Error)
+        }
+    }
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::{combine, lagrange_coefficient, split, Share};
+    use crate::dev::{MockCurve, NonZeroScalar, Scalar};
+    use ff::{Field, PrimeField};
+    use hex_literal::hex;
+    use rand_core::OsRng;
+
+    const EXAMPLE_SCALAR: [u8; 32] =
+        hex!("AABBCCDDEEFF0000000000000000000000000000000000000000000000000001");
+
+    fn example_secret() -> NonZeroScalar {
+        NonZeroScalar::from_repr(EXAMPLE_SCALAR.into()).unwrap()
+    }
+
+    #[test]
+    fn split_and_combine() {
+        let secret = example_secret();
+        let (shares, commitment) = split::<MockCurve>(&secret, 3, 5, &mut OsRng).unwrap();
+        assert_eq!(shares.len(), 5);
+        assert_eq!(commitment.threshold(), 3);
+
+        for share in &shares {
+            commitment.verify(share).unwrap();
+        }
+
+        for subset in [&shares[..3], &shares[2..], &shares[1..4], &shares[..]] {
+            assert_eq!(combine(subset).unwrap().to_repr(), secret.to_repr());
+        }
+
+        assert_ne!(combine(&shares[..2]).unwrap().to_repr(), secret.to_repr());
+    }
+
+    #[test]
+    fn verify_rejects_invalid_share() {
+        let secret = example_secret();
+        let (shares, commitment) = split::<MockCurve>(&secret, 2, 3, &mut OsRng).unwrap();
+
+        let share = Share::new(shares[0].index(), *shares[0].value() + Scalar::ONE).unwrap();
+        assert!(commitment.verify(&share).is_err());
+
+        let share = Share::new(2, *shares[0].value()).unwrap();
+        assert!(commitment.verify(&share).is_err());
+    }
+
+    #[test]
+    fn invalid_parameters() {
+        let secret = example_secret();
+        assert!(split::<MockCurve>(&secret, 0, 3, &mut OsRng).is_err());
+        assert!(split::<MockCurve>(&secret, 4, 3, &mut OsRng).is_err());
+        assert!(combine::<MockCurve>(&[]).is_err());
+        assert!(Share::<MockCurve>::new(0, Scalar::ONE).is_err());
+        assert!(lagrange_coefficient::<MockCurve>(1, &[1, 1, 2]).is_err());
+        assert!(lagrange_coefficient::<MockCurve>(1, &[2, 3]).is_err());
+    }
+
+    #[test]
+    fn reject_duplicate_indices() {
+        assert!(lagrange_coefficient::<MockCurve>(1, &[1, 2, 2]).is_err());
+        assert!(lagrange_coefficient::<MockCurve>(3, &[2, 3, 2]).is_err());
+        assert!(lagrange_coefficient::<MockCurve>(1, &[1, 0]).is_err());
+
+        let secret = example_secret();
+        let (shares, _) = split::<MockCurve>(&secret, 2, 3, &mut OsRng).unwrap();
+        assert!(combine(&[shares[0].clone(), shares[1].clone(), shares[1].clone()]).is_err());
+        assert!(combine(&[shares[0].clone(), shares[0].clone()]).is_err());
+    }
+}
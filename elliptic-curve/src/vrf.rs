@@ -0,0 +1,328 @@
+//! Verifiable random functions (VRFs), including a generic implementation of
+//! ECVRF as specified in [RFC 9381].
+//!
+//! A VRF is the public-key analogue of a keyed hash: only the holder of the
+//! secret key can compute the output for a given input, but anyone with the
+//! public key can use the accompanying proof to verify that the output is
+//! correct. This is used e.g. for leader election in consensus protocols and
+//! to prevent zone enumeration in DNSSEC.
+//!
+//! Curve implementations can impl [`EcvrfSuite`], which consists mostly of
+//! the constants of an ECVRF ciphersuite along with hooks for the
+//! encode-to-curve and nonce generation methods, to get impls of [`Vrf`] for
+//! [`SecretKey`] and [`VrfVerifier`] for [`PublicKey`].
+//!
+//! [RFC 9381]: https://www.rfc-editor.org/rfc/rfc9381
+
+use crate::{
+    ops::ReduceBytes, point::ClearCofactor, scalar::ScalarPrimitive, CurveArithmetic, Error,
+    NonZeroScalar, ProjectivePoint, PublicKey, Result, Scalar, SecretKey,
+};
+use digest::{Digest, Output};
+use group::{Group, GroupEncoding};
+use subtle::ConstantTimeEq;
+use zeroize::Zeroize;
+
+/// Domain separator for challenge generation.
+const CHALLENGE_GENERATION_DOMAIN_SEPARATOR_FRONT: u8 = 0x02;
+
+/// Domain separator for proof to hash.
+const PROOF_TO_HASH_DOMAIN_SEPARATOR_FRONT: u8 = 0x03;
+
+/// Trailing domain separator for challenge generation and proof to hash.
+const DOMAIN_SEPARATOR_BACK: u8 = 0x00;
+
+/// Compute VRF outputs along with proofs of their correctness.
+pub trait Vrf {
+    /// Proof that an output was computed correctly.
+    type Proof;
+
+    /// VRF output.
+    type Output;
+
+    /// Compute the proof for the given input `alpha`.
+    fn prove(&self, alpha: &[u8]) -> Result<Self::Proof>;
+
+    /// Compute the VRF output from a proof.
+    ///
+    /// The result is only meaningful if the proof has been verified, or was
+    /// computed by [`Vrf::prove`].
+    fn proof_to_hash(proof: &Self::Proof) -> Self::Output;
+
+    /// Compute the VRF output and proof for the given input `alpha`.
+    fn evaluate(&self, alpha: &[u8]) -> Result<(Self::Output, Self::Proof)> {
+        let proof = self.prove(alpha)?;
+        Ok((Self::proof_to_hash(&proof), proof))
+    }
+}
+
+/// Verify VRF proofs.
+pub trait VrfVerifier {
+    /// Proof that an output was computed correctly.
+    type Proof;
+
+    /// VRF output.
+    type Output;
+
+    /// Verify the proof for the given input `alpha`, returning the VRF output
+    /// if it's valid.
+    fn verify(&self, alpha: &[u8], proof: &Self::Proof) -> Result<Self::Output>;
+}
+
+/// ECVRF ciphersuite, as specified in [section 5.5 of RFC 9381][suites].
+///
+/// Scalars are derived from challenges using [`EcvrfSuite::string_to_scalar`]
+/// and points are serialized using [`GroupEncoding`], which for the suites
+/// defined in RFC 9381 is respectively the big endian encoding and SEC1
+/// compressed encoding for P-256, and the little endian encoding and RFC8032
+/// encoding for edwards25519.
+///
+/// [suites]: https://www.rfc-editor.org/rfc/rfc9381#section-5.5
+pub trait EcvrfSuite: CurveArithmetic {
+    /// Single byte identifying the ciphersuite, e.g. `0x01` for
+    /// ECVRF-P256-SHA256-TAI.
+    const SUITE_STRING: u8;
+
+    /// Length of the challenge in bytes (`cLen`): e.g. 16 for the suites
+    /// defined in RFC 9381.
+    ///
+    /// Must not exceed the output size of [`EcvrfSuite::Hash`]: proving and
+    /// verifying return an error otherwise.
+    const CHALLENGE_LEN: usize;
+
+    /// Hash function used for challenge generation and proof to hash.
+    type Hash: Digest;
+
+    /// Hash the input `alpha` to a point using the suite's encode-to-curve
+    /// method, e.g. `ECVRF_encode_to_curve_try_and_increment`, or
+    /// `encode_to_curve` from RFC 9380 with the domain separation tag
+    /// specified by the suite.
+    fn encode_to_curve(
+        public_key: &ProjectivePoint<Self>,
+        alpha: &[u8],
+    ) -> Result<ProjectivePoint<Self>>;
+
+    /// Deterministically generate the nonce 𝑘 from the secret key and the
+    /// serialized output of [`EcvrfSuite::encode_to_curve`], e.g. using
+    /// RFC6979 or as specified in RFC8032.
+    fn generate_nonce(secret_key: &NonZeroScalar<Self>, h_string: &[u8]) -> NonZeroScalar<Self>;
+
+    /// Convert the (truncated) challenge string to a scalar.
+    ///
+    /// The default implementation interprets it as a big endian integer.
+    fn string_to_scalar(bytes: &[u8]) -> Scalar<Self> {
+        ScalarPrimitive::<Self>::from_be_slice_reduced(bytes).into()
+    }
+}
+
+/// ECVRF proof 𝜋, consisting of the point 𝜞 = 𝑯 × 𝑥, the challenge 𝑐 and
+/// the response 𝑠, where 𝑯 is the input encoded to the curve and 𝑥 is the
+/// secret key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EcvrfProof<C>
+where
+    C: CurveArithmetic,
+{
+    /// Point 𝜞.
+    gamma: ProjectivePoint<C>,
+
+    /// Challenge 𝑐.
+    challenge: Scalar<C>,
+
+    /// Response 𝑠.
+    response: Scalar<C>,
+}
+
+impl<C> EcvrfProof<C>
+where
+    C: CurveArithmetic,
+{
+    /// Create a proof from its components, e.g. after deserializing it.
+    pub fn new(gamma: ProjectivePoint<C>, challenge: Scalar<C>, response: Scalar<C>) -> Self {
+        Self {
+            gamma,
+            challenge,
+            response,
+        }
+    }
+
+    /// Point 𝜞.
+    pub fn gamma(&self) -> &ProjectivePoint<C> {
+        &self.gamma
+    }
+
+    /// Challenge 𝑐.
+    pub fn challenge(&self) -> &Scalar<C> {
+        &self.challenge
+    }
+
+    /// Response 𝑠.
+    pub fn response(&self) -> &Scalar<C> {
+        &self.response
+    }
+}
+
+impl<C> Vrf for SecretKey<C>
+where
+    C: EcvrfSuite,
+    ProjectivePoint<C>: ClearCofactor + GroupEncoding,
+{
+    type Proof = EcvrfProof<C>;
+    type Output = Output<C::Hash>;
+
+    fn prove(&self, alpha: &[u8]) -> Result<EcvrfProof<C>> {
+        let x = self.to_nonzero_scalar();
+        let y = ProjectivePoint::<C>::generator() * x.as_ref();
+        let h = C::encode_to_curve(&y, alpha)?;
+        let gamma = h * x.as_ref();
+
+        let mut k = *C::generate_nonce(&x, h.to_bytes().as_ref());
+        let u = ProjectivePoint::<C>::generator() * k;
+        let v = h * k;
+        let challenge = generate_challenge::<C>(&[y, h, gamma, u, v]);
+        let response = challenge.map(|challenge| k + challenge * x.as_ref());
+        k.zeroize();
+
+        Ok(EcvrfProof::new(gamma, challenge?, response?))
+    }
+
+    fn proof_to_hash(proof: &EcvrfProof<C>) -> Output<C::Hash> {
+        C::Hash::new()
+            .chain_update([C::SUITE_STRING, PROOF_TO_HASH_DOMAIN_SEPARATOR_FRONT])
+            .chain_update(proof.gamma.clear_cofactor().to_bytes())
+            .chain_update([DOMAIN_SEPARATOR_BACK])
+            .finalize()
+    }
+}
+
+impl<C> VrfVerifier for PublicKey<C>
+where
+    C: EcvrfSuite,
+    ProjectivePoint<C>: ClearCofactor + GroupEncoding,
+{
+    type Proof = EcvrfProof<C>;
+    type Output = Output<C::Hash>;
+
+    fn verify(&self, alpha: &[u8], proof: &EcvrfProof<C>) -> Result<Output<C::Hash>> {
+        let y = self.to_projective();
+        let h = C::encode_to_curve(&y, alpha)?;
+
+        // 𝑼 = 𝑩 × 𝑠 − 𝒀 × 𝑐 and 𝑽 = 𝑯 × 𝑠 − 𝜞 × 𝑐
+        let u = ProjectivePoint::<C>::generator() * proof.response + y * -proof.challenge;
+        let v = h * proof.response + proof.gamma * -proof.challenge;
+        let challenge = generate_challenge::<C>(&[y, h, proof.gamma, u, v])?;
+
+        if bool::from(challenge.ct_eq(&proof.challenge)) {
+            Ok(SecretKey::<C>::proof_to_hash(proof))
+        } else {
+            Err(Error)
+        }
+    }
+}
+
+/// Compute the challenge from the given points, as specified in
+/// [section 5.4.3 of RFC 9381][challenge].
+///
+/// Returns an error if [`EcvrfSuite::CHALLENGE_LEN`] exceeds the output size
+/// of [`EcvrfSuite::Hash`].
+///
+/// [challenge]: https://www.rfc-editor.org/rfc/rfc9381#section-5.4.3
+fn generate_challenge<C>(points: &[ProjectivePoint<C>]) -> Result<Scalar<C>>
+where
+    C: EcvrfSuite,
+    ProjectivePoint<C>: GroupEncoding,
+{
+    let mut hasher =
+        C::Hash::new().chain_update([C::SUITE_STRING, CHALLENGE_GENERATION_DOMAIN_SEPARATOR_FRONT]);
+
+    for point in points {
+        hasher.update(point.to_bytes());
+    }
+
+    let c_string = hasher.chain_update([DOMAIN_SEPARATOR_BACK]).finalize();
+    let c_string = c_string.get(..C::CHALLENGE_LEN).ok_or(Error)?;
+    Ok(C::string_to_scalar(c_string))
+}
+
+#[cfg(all(test, feature = "dev"))]
+mod tests {
+    use super::{EcvrfProof, EcvrfSuite, Vrf, VrfVerifier};
+    use crate::{
+        dev::{MockCurve, NonZeroScalar, ProjectivePoint, PublicKey, Scalar, SecretKey},
+        ops::ReduceBytes,
+        Result,
+    };
+    use ff::{Field, PrimeField};
+    use group::{Group, GroupEncoding};
+    use rand_core::OsRng;
+    use sha2::{Digest, Sha256};
+
+    /// Toy suite which hashes inputs to scalar multiples of the generator.
+    impl EcvrfSuite for MockCurve {
+        const SUITE_STRING: u8 = 0xFF;
+        const CHALLENGE_LEN: usize = 16;
+        type Hash = Sha256;
+
+        fn encode_to_curve(public_key: &ProjectivePoint, alpha: &[u8]) -> Result<ProjectivePoint> {
+            let digest = Sha256::new()
+                .chain_update(public_key.to_bytes())
+                .chain_update(alpha)
+                .finalize();
+
+            Ok(ProjectivePoint::generator() * Scalar::from_be_slice_reduced(&digest))
+        }
+
+        fn generate_nonce(secret_key: &NonZeroScalar, h_string: &[u8]) -> NonZeroScalar {
+            let digest = Sha256::new()
+                .chain_update(secret_key.to_repr())
+                .chain_update(h_string)
+                .finalize();
+
+            NonZeroScalar::new(Scalar::from_be_slice_reduced(&digest)).unwrap()
+        }
+    }
+
+    #[test]
+    fn prove_and_verify() {
+        let secret_key = SecretKey::random(&mut OsRng);
+        let public_key = secret_key.public_key();
+
+        let (output, proof) = secret_key.evaluate(b"sample").unwrap();
+        assert_eq!(public_key.verify(b"sample", &proof).unwrap(), output);
+        assert_eq!(secret_key.prove(b"sample").unwrap(), proof);
+        assert_ne!(secret_key.evaluate(b"other").unwrap().0, output);
+        assert!(public_key.verify(b"other", &proof).is_err());
+
+        let other_key = PublicKey::from_secret_scalar(&NonZeroScalar::random(&mut OsRng));
+        assert!(other_key.verify(b"sample", &proof).is_err());
+    }
+
+    #[test]
+    fn reject_modified_proof() {
+        let secret_key = SecretKey::random(&mut OsRng);
+        let public_key = secret_key.public_key();
+        let proof = secret_key.prove(b"sample").unwrap();
+
+        let modified = [
+            EcvrfProof::new(
+                *proof.gamma() + ProjectivePoint::generator() * Scalar::ONE,
+                *proof.challenge(),
+                *proof.response(),
+            ),
+            EcvrfProof::new(
+                *proof.gamma(),
+                *proof.challenge() + Scalar::ONE,
+                *proof.response(),
+            ),
+            EcvrfProof::new(
+                *proof.gamma(),
+                *proof.challenge(),
+                *proof.response() + Scalar::ONE,
+            ),
+        ];
+
+        for proof in &modified {
+            assert!(public_key.verify(b"sample", proof).is_err());
+        }
+    }
+}
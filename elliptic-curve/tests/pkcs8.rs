@@ -45,6 +45,18 @@ fn decode_pkcs8_public_key_from_der() {
     );
 }
 
+#[test]
+#[cfg(feature = "digest")]
+fn public_key_fingerprint() {
+    use sha2::{Digest, Sha256};
+
+    let public_key = PublicKey::from_public_key_der(&PKCS8_PUBLIC_KEY_DER[..]).unwrap();
+    assert_eq!(
+        public_key.fingerprint::<Sha256>().unwrap(),
+        Sha256::digest(PKCS8_PUBLIC_KEY_DER)
+    );
+}
+
 #[test]
 #[cfg(feature = "pem")]
 fn decode_pkcs8_public_key_from_pem() {
@@ -54,3 +66,36 @@ fn decode_pkcs8_public_key_from_pem() {
     let der_key = PublicKey::from_public_key_der(&PKCS8_PUBLIC_KEY_DER[..]).unwrap();
     assert_eq!(public_key, der_key);
 }
+
+#[test]
+#[cfg(feature = "pkcs8-encryption")]
+fn encrypted_pkcs8_private_key_round_trip() {
+    use pkcs8::{der::Decode, pkcs5::pbes2, PrivateKeyInfo};
+
+    const PASSWORD: &[u8] = b"hunter42";
+
+    // Use PBKDF2 with a low iteration count to keep the test fast
+    let params =
+        pbes2::Parameters::pbkdf2_sha256_aes256cbc(2048, &[0x42; 16], &[0x24; 16]).unwrap();
+
+    let private_key = example_private_key();
+    let encrypted = PrivateKeyInfo::from_der(private_key.as_bytes())
+        .unwrap()
+        .encrypt_with_params(params, PASSWORD)
+        .unwrap();
+
+    let secret_key = SecretKey::from_pkcs8_encrypted_der(encrypted.as_bytes(), PASSWORD).unwrap();
+    assert_eq!(secret_key.to_be_bytes().as_slice(), &EXAMPLE_SCALAR);
+    assert!(SecretKey::from_pkcs8_encrypted_der(encrypted.as_bytes(), b"hunter2").is_err());
+}
+
+#[cfg(feature = "pkcs8-sec1")]
+#[test]
+fn sec1_private_key_round_trip() {
+    use elliptic_curve::sec1::{DecodeEcPrivateKey, EncodeEcPrivateKey};
+
+    let secret_key = SecretKey::from_be_bytes(&EXAMPLE_SCALAR).unwrap();
+    let der = EncodeEcPrivateKey::to_sec1_der(&secret_key).unwrap();
+    let decoded = <SecretKey as DecodeEcPrivateKey>::from_sec1_der(der.as_bytes()).unwrap();
+    assert_eq!(decoded.to_be_bytes().as_slice(), &EXAMPLE_SCALAR);
+}
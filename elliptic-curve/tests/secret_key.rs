@@ -8,3 +8,33 @@ use elliptic_curve::dev::SecretKey;
 fn undersize_secret_key() {
     assert!(SecretKey::from_be_bytes(&[]).is_err());
 }
+
+#[cfg(feature = "hash2curve")]
+#[test]
+fn derive_from_seed() {
+    use sha2::Sha256;
+
+    const INFO: &[u8] = b"ELLIPTIC-CURVE-TEST-V1";
+
+    let sk = SecretKey::derive_from_seed::<Sha256>(&[0x42; 32], INFO).unwrap();
+
+    // `OS2IP(expand_message_xmd(SHA-256, seed, INFO, 48)) mod n`, as specified by RFC 9380
+    assert_eq!(
+        sk.to_be_bytes().as_slice(),
+        hex_literal::hex!("f21a1ec16c997a80335b04614799d5757844a68e3d0a299fde2b102434cac453")
+    );
+    assert_eq!(
+        SecretKey::derive_from_seed::<Sha256>(&[0x42; 32], INFO).unwrap(),
+        sk
+    );
+    assert_ne!(
+        SecretKey::derive_from_seed::<Sha256>(&[0x43; 32], INFO).unwrap(),
+        sk
+    );
+    assert_ne!(
+        SecretKey::derive_from_seed::<Sha256>(&[0x42; 32], b"OTHER-TEST-V1").unwrap(),
+        sk
+    );
+    assert!(SecretKey::derive_from_seed::<Sha256>(&[0x42; 15], INFO).is_err());
+    assert!(SecretKey::derive_from_seed::<Sha256>(&[0x42; 32], &[]).is_err());
+}
@@ -0,0 +1,141 @@
+//! KEM combiner traits
+
+use crate::{
+    errors::Error,
+    kem::{Decapsulator, EncappedKey, Encapsulator, SharedSecret},
+};
+
+use rand_core::{CryptoRng, RngCore};
+
+#[cfg(feature = "digest")]
+use {digest::Digest, generic_array::GenericArray, zeroize::Zeroize};
+
+/// Recipient public key of a combined KEM, consisting of the recipient public keys of both
+/// component KEMs.
+pub type CombinedPublicKey<C> = (
+    <<C as KemCombiner>::First as EncappedKey>::RecipientPublicKey,
+    <<C as KemCombiner>::Second as EncappedKey>::RecipientPublicKey,
+);
+
+/// Trait impl'd by hybrid KEMs which compose two KEMs into one, e.g. a classical KEM such as
+/// `DHKEM(X25519, HKDF-SHA256)` with a post-quantum KEM such as ML-KEM-768.
+///
+/// The combined KEM encapsulates to both component KEMs and derives its shared secret from both
+/// component shared secrets using [`KemCombiner::combine`], so it remains secure as long as
+/// either of the component KEMs is. The combined encapsulated key is the pair of component
+/// encapsulated keys, serialized as defined by [`KemCombiner::join`] and [`KemCombiner::split`].
+pub trait KemCombiner {
+    /// The encapsulated key of the first component KEM.
+    type First: EncappedKey;
+
+    /// The encapsulated key of the second component KEM.
+    type Second: EncappedKey;
+
+    /// The encapsulated key of the combined KEM. Its recipient public key is the pair of
+    /// component recipient public keys.
+    type EncappedKey: EncappedKey<RecipientPublicKey = CombinedPublicKey<Self>>;
+
+    /// Combines the component encapsulated keys into the combined encapsulated key.
+    fn join(first: Self::First, second: Self::Second) -> Self::EncappedKey;
+
+    /// Splits the combined encapsulated key into the component encapsulated keys.
+    fn split(encapped_key: &Self::EncappedKey) -> Result<(Self::First, Self::Second), Error>;
+
+    /// The secret-combination KDF, which derives the combined shared secret from the component
+    /// shared secrets.
+    ///
+    /// Implementations must use a KDF or hash function which is a dual PRF in its secret inputs,
+    /// and should additionally bind the combined encapsulated key and the recipient public key,
+    /// e.g. `SHA3-256(label || ss1 || ss2 || enc || pk)`, so the combined secret stays secure
+    /// even if one of the component KEMs turns out to be broken. With the `digest` feature,
+    /// [`hash_combine`] provides such a combiner over any hash function.
+    fn combine(
+        first: &SharedSecret<Self::First>,
+        second: &SharedSecret<Self::Second>,
+        encapped_key: &Self::EncappedKey,
+        recip_pubkey: &CombinedPublicKey<Self>,
+    ) -> SharedSecret<Self::EncappedKey>;
+
+    /// Encapsulates a fresh shared secret with the given recipient, using the given component
+    /// encapsulators. Returns the combined encapsulated key and shared secret on success, or an
+    /// error if either component encapsulation failed.
+    fn encap<E1, E2, R>(
+        first: &E1,
+        second: &E2,
+        csprng: &mut R,
+        recip_pubkey: &CombinedPublicKey<Self>,
+    ) -> Result<(Self::EncappedKey, SharedSecret<Self::EncappedKey>), Error>
+    where
+        E1: Encapsulator<Self::First>,
+        E2: Encapsulator<Self::Second>,
+        R: CryptoRng + RngCore,
+    {
+        let (ek1, ss1) = first.try_encap(csprng, &recip_pubkey.0)?;
+        let (ek2, ss2) = second.try_encap(csprng, &recip_pubkey.1)?;
+        let encapped_key = Self::join(ek1, ek2);
+        let shared_secret = Self::combine(&ss1, &ss2, &encapped_key, recip_pubkey);
+        Ok((encapped_key, shared_secret))
+    }
+
+    /// Decapsulates the given combined encapsulated key using the component decapsulators.
+    /// `recip_pubkey` must be the public key corresponding to the decapsulators. Returns the
+    /// combined shared secret on success, or an error if either component decapsulation failed.
+    fn decap<D1, D2>(
+        first: &D1,
+        second: &D2,
+        encapped_key: &Self::EncappedKey,
+        recip_pubkey: &CombinedPublicKey<Self>,
+    ) -> Result<SharedSecret<Self::EncappedKey>, Error>
+    where
+        D1: Decapsulator<Self::First>,
+        D2: Decapsulator<Self::Second>,
+    {
+        let (ek1, ek2) = Self::split(encapped_key)?;
+        let ss1 = first.try_decap(&ek1)?;
+        let ss2 = second.try_decap(&ek2)?;
+        Ok(Self::combine(&ss1, &ss2, encapped_key, recip_pubkey))
+    }
+}
+
+/// Default secret-combination KDF for [`KemCombiner::combine`], which hashes a domain separation
+/// label, both component shared secrets and the combined encapsulated key using `D`.
+///
+/// Block `i` of the combined shared secret is
+/// `D(len(label) || label || ss1 || ss2 || enc || i)`, where `len(label)` and `i` are big endian
+/// `u64` and `u32` integers respectively, and the output is truncated to the shared secret size.
+/// `D` should be a dual PRF in its secret inputs, such as SHA3-256 or SHA-256. As the recipient
+/// public key has no byte representation in general, combiners which should bind it must hash
+/// it separately.
+#[cfg(feature = "digest")]
+#[cfg_attr(docsrs, doc(cfg(feature = "digest")))]
+pub fn hash_combine<D, EK1, EK2, EK>(
+    label: &[u8],
+    first: &SharedSecret<EK1>,
+    second: &SharedSecret<EK2>,
+    encapped_key: &EK,
+) -> SharedSecret<EK>
+where
+    D: Digest,
+    EK1: EncappedKey,
+    EK2: EncappedKey,
+    EK: EncappedKey,
+{
+    let mut bytes = GenericArray::<u8, EK::SharedSecretSize>::default();
+
+    for (i, chunk) in bytes.chunks_mut(<D as Digest>::output_size()).enumerate() {
+        let mut block = D::new()
+            .chain_update((label.len() as u64).to_be_bytes())
+            .chain_update(label)
+            .chain_update(first.as_bytes())
+            .chain_update(second.as_bytes())
+            .chain_update(encapped_key)
+            .chain_update((i as u32).to_be_bytes())
+            .finalize();
+        chunk.copy_from_slice(&block[..chunk.len()]);
+        block.as_mut_slice().zeroize();
+    }
+
+    let shared_secret = SharedSecret::new(bytes.clone());
+    bytes.as_mut_slice().zeroize();
+    shared_secret
+}
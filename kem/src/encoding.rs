@@ -0,0 +1,45 @@
+//! Fixed-size byte encodings of KEM outputs
+
+use crate::{errors::Error, kem::EncappedKey};
+
+use generic_array::{typenum::Unsigned, ArrayLength, GenericArray};
+
+/// Fixed-size byte encoding of a type which impls [`EncodedSizeUser`].
+pub type Encoded<T> = GenericArray<u8, <T as EncodedSizeUser>::EncodedSize>;
+
+/// Trait impl'd by types with a fixed-size byte encoding, which allows transport protocols to
+/// serialize KEM outputs generically, e.g. to frame them without a length prefix.
+///
+/// This is blanket impl'd for all [`EncappedKey`] types, using their `EncappedKeySize`.
+pub trait EncodedSizeUser: Sized {
+    /// The size, in bytes, of the encoded form.
+    type EncodedSize: ArrayLength<u8>;
+
+    /// Parses a value from its fixed-size byte encoding.
+    fn from_encoded_bytes(bytes: &Encoded<Self>) -> Result<Self, Error>;
+
+    /// Serializes this value into its fixed-size byte encoding.
+    fn to_encoded_bytes(&self) -> Encoded<Self>;
+
+    /// Parses a value from a byte slice, returning an error if its length is not exactly
+    /// `EncodedSize` bytes.
+    fn from_encoded_slice(bytes: &[u8]) -> Result<Self, Error> {
+        if bytes.len() != Self::EncodedSize::USIZE {
+            return Err(Error);
+        }
+
+        Self::from_encoded_bytes(GenericArray::from_slice(bytes))
+    }
+}
+
+impl<EK: EncappedKey> EncodedSizeUser for EK {
+    type EncodedSize = EK::EncappedKeySize;
+
+    fn from_encoded_bytes(bytes: &Encoded<Self>) -> Result<Self, Error> {
+        EK::from_bytes(bytes)
+    }
+
+    fn to_encoded_bytes(&self) -> Encoded<Self> {
+        self.as_bytes().clone()
+    }
+}
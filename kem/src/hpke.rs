@@ -0,0 +1,72 @@
+//! HPKE KEM Traits
+
+use crate::{
+    errors::Error,
+    kem::{Decapsulator, EncappedKey, Encapsulator, SharedSecret},
+};
+
+use generic_array::{ArrayLength, GenericArray};
+use rand_core::{CryptoRng, RngCore};
+
+/// Trait impl'd by KEMs which implement the `Kem` interface of HPKE, as defined in
+/// [RFC 9180 Section 4][rfc9180], e.g. `DHKEM(P-256, HKDF-SHA256)` or `DHKEM(X25519, HKDF-SHA256)`.
+///
+/// This maps the HPKE `Encap`, `Decap`, `SerializePublicKey` and `DeserializePublicKey`
+/// functions onto the [`Encapsulator`] and [`Decapsulator`] traits, which allows HPKE
+/// implementations to be generic over the KEM. The serialized form of the encapsulated key
+/// (`enc`) is given by [`EncappedKey::as_bytes`], with a size of `Nenc` bytes.
+///
+/// [rfc9180]: https://www.rfc-editor.org/rfc/rfc9180.html#section-4
+pub trait HpkeKem {
+    /// The KEM identifier of this algorithm as listed in the HPKE IANA registry, e.g. `0x0010`
+    /// for `DHKEM(P-256, HKDF-SHA256)` or `0x0020` for `DHKEM(X25519, HKDF-SHA256)`.
+    const KEM_ID: u16;
+
+    /// The size, in bytes, of a serialized public key (`Npk`).
+    type PublicKeySize: ArrayLength<u8>;
+
+    /// The public key of a recipient.
+    type PublicKey;
+
+    /// The private key of a recipient, which is used in decapsulation.
+    type PrivateKey: Decapsulator<Self::EncappedKey>;
+
+    /// The encapsulated key (`enc`) produced by this KEM.
+    type EncappedKey: EncappedKey<RecipientPublicKey = Self::PublicKey>;
+
+    /// The unauthenticated encapsulator for this KEM, which carries no state.
+    type Encapsulator: Encapsulator<Self::EncappedKey> + Default;
+
+    /// Serializes a public key into its fixed-size byte representation (`SerializePublicKey`).
+    fn serialize_public_key(public_key: &Self::PublicKey) -> GenericArray<u8, Self::PublicKeySize>;
+
+    /// Parses a public key from its byte representation (`DeserializePublicKey`).
+    fn deserialize_public_key(
+        bytes: &GenericArray<u8, Self::PublicKeySize>,
+    ) -> Result<Self::PublicKey, Error>;
+
+    /// Returns the KEM `suite_id`, i.e. `concat("KEM", I2OSP(kem_id, 2))`, which is used for
+    /// domain separation in the KEM's key derivation.
+    fn suite_id() -> [u8; 5] {
+        let [hi, lo] = Self::KEM_ID.to_be_bytes();
+        [b'K', b'E', b'M', hi, lo]
+    }
+
+    /// Generates a fresh shared secret for the given recipient public key, returning the
+    /// encapsulated key and the shared secret (`Encap`).
+    fn encap<R: CryptoRng + RngCore>(
+        csprng: &mut R,
+        recip_pubkey: &Self::PublicKey,
+    ) -> Result<(Self::EncappedKey, SharedSecret<Self::EncappedKey>), Error> {
+        Self::Encapsulator::default().try_encap(csprng, recip_pubkey)
+    }
+
+    /// Recovers the shared secret from the given encapsulated key using the recipient's private
+    /// key (`Decap`).
+    fn decap(
+        private_key: &Self::PrivateKey,
+        encapped_key: &Self::EncappedKey,
+    ) -> Result<SharedSecret<Self::EncappedKey>, Error> {
+        private_key.try_decap(encapped_key)
+    }
+}
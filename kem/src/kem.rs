@@ -35,6 +35,14 @@ pub trait EncappedKey: AsRef<[u8]> + Debug + Sized {
     }
 }
 
+/// Extraction step of an extract-then-expand KDF such as HKDF, which condenses input keying
+/// material into a pseudorandom key.
+pub trait Extract: Sized {
+    /// Extracts a pseudorandom key from the input keying material `ikm` using the given `salt`,
+    /// which may be empty.
+    fn extract(salt: &[u8], ikm: &[u8]) -> Self;
+}
+
 /// The shared secret that results from key exchange.
 pub struct SharedSecret<EK: EncappedKey>(GenericArray<u8, EK::SharedSecretSize>);
 
@@ -57,6 +65,15 @@ impl<EK: EncappedKey> SharedSecret<EK> {
     pub fn as_bytes(&self) -> &[u8] {
         &self.0
     }
+
+    /// Extracts a pseudorandom key from the shared secret using the given KDF (e.g. HKDF) and
+    /// `salt`, which may be empty.
+    ///
+    /// The raw shared secret of many KEMs is not uniformly random, so it should always be passed
+    /// through a KDF before being used as a key.
+    pub fn extract<K: Extract>(&self, salt: &[u8]) -> K {
+        K::extract(salt, self.as_bytes())
+    }
 }
 
 /// Represents the functionality of a key encapsulator. For unauthenticated encapsulation, `Self`
@@ -73,6 +90,47 @@ pub trait Encapsulator<EK: EncappedKey> {
     ) -> Result<(EK, SharedSecret<EK>), Error>;
 }
 
+/// Represents the functionality of a key encapsulator which takes the randomness used for
+/// encapsulation as an explicit seed rather than drawing it from an RNG.
+///
+/// This is useful for known-answer tests, and for protocols which derive the encapsulation
+/// randomness from a transcript. The seed must be uniformly random and never reused, otherwise
+/// the resulting shared secret is not secret.
+pub trait EncapsulateDeterministic<EK: EncappedKey> {
+    /// The size, in bytes, of the seed consumed by encapsulation.
+    type SeedSize: ArrayLength<u8>;
+
+    /// Attempts to encapsulate a shared secret with the given recipient, deterministically
+    /// derived from `seed`. Otherwise behaves exactly like [`Encapsulator::try_encap`]. Returns
+    /// the shared secret and encapsulated key on success, or an error if something went wrong.
+    fn try_encap_deterministic(
+        &self,
+        seed: &GenericArray<u8, Self::SeedSize>,
+        recip_pubkey: &EK::RecipientPublicKey,
+    ) -> Result<(EK, SharedSecret<EK>), Error>;
+}
+
+/// Represents the functionality of an authenticated-key encapsulator (AKEM), where `Self`
+/// holds the sender's private key, as in the HPKE `AuthEncap` function.
+///
+/// Unlike [`Encapsulator`], which leaves it up to the implementation whether `Self` carries an
+/// identity, the resulting shared secret is always bound to the sender's identity. The
+/// recipient can recover it using the matching [`AuthDecapsulator`] with the sender's public
+/// key.
+pub trait EncapsulateAuth<EK: EncappedKey> {
+    /// Returns the public key corresponding to the sender identity held by `Self`.
+    fn sender_pubkey(&self) -> EK::SenderPublicKey;
+
+    /// Attempts to encapsulate a fresh shared secret with the given recipient, bound to the
+    /// sender identity held by `Self`. Returns the shared secret and encapsulated key on
+    /// success, or an error if something went wrong.
+    fn try_auth_encap<R: CryptoRng + RngCore>(
+        &self,
+        csprng: &mut R,
+        recip_pubkey: &EK::RecipientPublicKey,
+    ) -> Result<(EK, SharedSecret<EK>), Error>;
+}
+
 /// Represents the functionality of a key decapsulator, where `Self` is a cryptographic key.
 pub trait Decapsulator<EK: EncappedKey> {
     /// Attempt to decapsulate the given encapsulated key. Returns the shared secret on success, or
@@ -81,7 +139,8 @@ pub trait Decapsulator<EK: EncappedKey> {
 }
 
 /// Represents the functionality of a authenticated-key decapsulator, where `Self` is a
-/// cryptographic key.
+/// cryptographic key. This is the counterpart of [`EncapsulateAuth`], as in the HPKE
+/// `AuthDecap` function.
 pub trait AuthDecapsulator<EK: EncappedKey> {
     /// Attempt to decapsulate the given encapsulated key. The resulting shared secret is bound to
     /// the provided sender identity, thus providing authenticity. Returns the shared secret
@@ -12,8 +12,16 @@
 #[cfg(feature = "std")]
 extern crate std;
 
+mod combiner;
+mod encoding;
 mod errors;
+mod hpke;
 mod kem;
+mod multi;
 
-pub use crate::{errors::*, kem::*};
+pub use crate::{combiner::*, encoding::*, errors::*, hpke::*, kem::*, multi::*};
 pub use generic_array;
+
+#[cfg(feature = "digest")]
+#[cfg_attr(docsrs, doc(cfg(feature = "digest")))]
+pub use digest;
@@ -0,0 +1,98 @@
+//! Multi-recipient encapsulation traits
+
+use crate::{
+    errors::Error,
+    kem::{Decapsulator, EncappedKey, Encapsulator, SharedSecret},
+};
+
+use generic_array::{ArrayLength, GenericArray};
+use rand_core::{CryptoRng, RngCore};
+use zeroize::Zeroizing;
+
+/// The encapsulation of a multi-recipient shared secret for a single recipient, consisting of
+/// an encapsulated key and the shared secret wrapped under the secret it encapsulates.
+#[derive(Debug)]
+pub struct RecipientEncapsulation<EK: EncappedKey> {
+    /// The encapsulated key for this recipient.
+    pub encapped_key: EK,
+
+    /// The multi-recipient shared secret, wrapped under the shared secret encapsulated in
+    /// `encapped_key`. Depending on the implementation of [`EncapsulateMulti`], the wrap may be
+    /// unauthenticated.
+    pub wrapped_secret: GenericArray<u8, EK::SharedSecretSize>,
+}
+
+/// Represents the functionality of a key encapsulator which establishes one shared secret with
+/// a group of recipients, as used in MLS and other group messaging protocols.
+///
+/// The provided implementation generates a fresh shared secret using the given RNG, and wraps it
+/// for each recipient in turn with the one-time pad given by a fresh encapsulation to that
+/// recipient. This requires the shared secrets of the KEM to be uniformly random, as is the case
+/// for KEMs which derive them using a KDF, e.g. the DHKEMs of HPKE.
+///
+/// # Security
+///
+/// The wrap of the provided implementation is unauthenticated: it isn't bound to the
+/// encapsulated key or to the position of the recipient, and flipping bits of a wrapped secret
+/// flips the same bits of the secret recovered by [`DecapsulateMulti::try_decap_multi`] without
+/// being detected. It must only be used when the multi-recipient shared secret is subsequently
+/// authenticated, e.g. by using it as an AEAD key, or when the encapsulations are transmitted
+/// over an authenticated channel. Otherwise, implementations must override it with an
+/// authenticated wrap, e.g. HPKE single-shot encryption with the encapsulated key and the index
+/// of the recipient as associated data, along with [`DecapsulateMulti`].
+pub trait EncapsulateMulti<EK: EncappedKey>: Encapsulator<EK> {
+    /// Attempts to encapsulate a fresh shared secret with each of the given recipients, pushing
+    /// the per-recipient encapsulations into `encapsulations` in the same order as
+    /// `recip_pubkeys`. Returns the shared secret on success, or an error if encapsulating to
+    /// any of the recipients failed.
+    fn try_encap_multi<R: CryptoRng + RngCore>(
+        &self,
+        csprng: &mut R,
+        recip_pubkeys: &[EK::RecipientPublicKey],
+        encapsulations: &mut impl Extend<RecipientEncapsulation<EK>>,
+    ) -> Result<SharedSecret<EK>, Error> {
+        // Zeroized on drop, including when returning early due to an error
+        let mut secret = Zeroizing::new(GenericArray::<u8, EK::SharedSecretSize>::default());
+        csprng.try_fill_bytes(&mut secret).map_err(|_| Error)?;
+
+        for recip_pubkey in recip_pubkeys {
+            let (encapped_key, pad) = self.try_encap(csprng, recip_pubkey)?;
+            let wrapped_secret = xor(&secret, pad.as_bytes());
+
+            encapsulations.extend(Some(RecipientEncapsulation {
+                encapped_key,
+                wrapped_secret,
+            }));
+        }
+
+        Ok(SharedSecret::new((*secret).clone()))
+    }
+}
+
+/// Represents the functionality of a key decapsulator which recovers a shared secret produced by
+/// [`EncapsulateMulti`] from the encapsulation for its recipient.
+///
+/// The provided implementation is the counterpart of the provided implementation of
+/// [`EncapsulateMulti::try_encap_multi`]. It can't detect tampering with the wrapped secret: see
+/// the security considerations of [`EncapsulateMulti`].
+pub trait DecapsulateMulti<EK: EncappedKey>: Decapsulator<EK> {
+    /// Attempts to decapsulate the given per-recipient encapsulation. Returns the shared secret on
+    /// success, or an error if something went wrong.
+    fn try_decap_multi(
+        &self,
+        encapsulation: &RecipientEncapsulation<EK>,
+    ) -> Result<SharedSecret<EK>, Error> {
+        let pad = self.try_decap(&encapsulation.encapped_key)?;
+        Ok(SharedSecret::new(xor(
+            &encapsulation.wrapped_secret,
+            pad.as_bytes(),
+        )))
+    }
+}
+
+/// XORs the given shared secret with a pad of the same length.
+fn xor<N: ArrayLength<u8>>(secret: &GenericArray<u8, N>, pad: &[u8]) -> GenericArray<u8, N> {
+    let mut ret = secret.clone();
+    ret.iter_mut().zip(pad).for_each(|(a, b)| *a ^= b);
+    ret
+}
@@ -0,0 +1,271 @@
+use hpke::{
+    kem::{Kem as KemTrait, X25519HkdfSha256},
+    Deserializable as HpkeDeserializable, Serializable as HpkeSerializable,
+};
+use kem::{
+    generic_array::{
+        typenum::{U32, U64},
+        GenericArray,
+    },
+    CombinedPublicKey, Decapsulator, EncappedKey, Encapsulator, Error, KemCombiner, SharedSecret,
+};
+
+#[cfg(feature = "digest")]
+use kem::{
+    generic_array::typenum::{U0, U48},
+    hash_combine,
+};
+use rand::rngs::OsRng;
+use rand_core::{CryptoRng, RngCore};
+use sha2::{Digest, Sha256};
+
+// Define the pubkey and encapped key types of the component KEM. For the purposes of this test,
+// both component KEMs are DHKEM(X25519, HKDF-SHA256).
+#[derive(Clone)]
+struct X25519PublicKey(<X25519HkdfSha256 as KemTrait>::PublicKey);
+
+#[derive(Debug)]
+struct X25519EncappedKey(GenericArray<u8, U32>);
+impl EncappedKey for X25519EncappedKey {
+    type SharedSecretSize = U32;
+    type EncappedKeySize = U32;
+    type RecipientPublicKey = X25519PublicKey;
+    type SenderPublicKey = X25519PublicKey;
+
+    fn from_bytes(bytes: &GenericArray<u8, U32>) -> Result<Self, Error> {
+        Ok(X25519EncappedKey(*bytes))
+    }
+}
+impl AsRef<[u8]> for X25519EncappedKey {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+type X25519PrivateKey = <X25519HkdfSha256 as KemTrait>::PrivateKey;
+
+struct X25519Encap;
+impl Encapsulator<X25519EncappedKey> for X25519Encap {
+    fn try_encap<R: RngCore + CryptoRng>(
+        &self,
+        csprng: &mut R,
+        recip_pubkey: &X25519PublicKey,
+    ) -> Result<(X25519EncappedKey, SharedSecret<X25519EncappedKey>), Error> {
+        <X25519HkdfSha256 as KemTrait>::encap(&recip_pubkey.0, None, csprng)
+            .map(|(ss, ek)| (X25519EncappedKey(ek.to_bytes()), SharedSecret::new(ss.0)))
+            .map_err(|_| Error)
+    }
+}
+
+impl Decapsulator<X25519EncappedKey> for X25519PrivateKey {
+    fn try_decap(
+        &self,
+        encapped_key: &X25519EncappedKey,
+    ) -> Result<SharedSecret<X25519EncappedKey>, Error> {
+        let encapped_key =
+            <<X25519HkdfSha256 as KemTrait>::EncappedKey as HpkeDeserializable>::from_bytes(
+                &encapped_key.0,
+            )
+            .map_err(|_| Error)?;
+
+        <X25519HkdfSha256 as KemTrait>::decap(self, None, &encapped_key)
+            .map(|ss| SharedSecret::new(ss.0))
+            .map_err(|_| Error)
+    }
+}
+
+// The combined encapped key is the concatenation of both component encapped keys
+#[derive(Debug)]
+struct HybridEncappedKey(GenericArray<u8, U64>);
+impl EncappedKey for HybridEncappedKey {
+    type SharedSecretSize = U32;
+    type EncappedKeySize = U64;
+    type RecipientPublicKey = (X25519PublicKey, X25519PublicKey);
+    type SenderPublicKey = ();
+
+    fn from_bytes(bytes: &GenericArray<u8, U64>) -> Result<Self, Error> {
+        Ok(HybridEncappedKey(*bytes))
+    }
+}
+impl AsRef<[u8]> for HybridEncappedKey {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+// Combine the shared secrets with SHA-256 over a label, both secrets, the ciphertext and the
+// recipient public keys
+struct Hybrid;
+impl KemCombiner for Hybrid {
+    type First = X25519EncappedKey;
+    type Second = X25519EncappedKey;
+    type EncappedKey = HybridEncappedKey;
+
+    fn join(first: X25519EncappedKey, second: X25519EncappedKey) -> HybridEncappedKey {
+        let mut bytes = GenericArray::default();
+        bytes[..32].copy_from_slice(&first.0);
+        bytes[32..].copy_from_slice(&second.0);
+        HybridEncappedKey(bytes)
+    }
+
+    fn split(
+        encapped_key: &HybridEncappedKey,
+    ) -> Result<(X25519EncappedKey, X25519EncappedKey), Error> {
+        let (first, second) = encapped_key.0.split_at(32);
+        Ok((
+            X25519EncappedKey(GenericArray::clone_from_slice(first)),
+            X25519EncappedKey(GenericArray::clone_from_slice(second)),
+        ))
+    }
+
+    fn combine(
+        first: &SharedSecret<X25519EncappedKey>,
+        second: &SharedSecret<X25519EncappedKey>,
+        encapped_key: &HybridEncappedKey,
+        recip_pubkey: &CombinedPublicKey<Self>,
+    ) -> SharedSecret<HybridEncappedKey> {
+        let digest = Sha256::new()
+            .chain_update(b"test hybrid KEM")
+            .chain_update(first.as_bytes())
+            .chain_update(second.as_bytes())
+            .chain_update(encapped_key)
+            .chain_update(recip_pubkey.0 .0.to_bytes())
+            .chain_update(recip_pubkey.1 .0.to_bytes())
+            .finalize();
+        SharedSecret::new(digest)
+    }
+}
+
+fn gen_keypair<R: RngCore + CryptoRng>(csprng: &mut R) -> (X25519PrivateKey, X25519PublicKey) {
+    let (sk, pk) = X25519HkdfSha256::gen_keypair(csprng);
+    (sk, X25519PublicKey(pk))
+}
+
+#[test]
+fn test_combiner() {
+    let mut rng = OsRng;
+    let (sk1, pk1) = gen_keypair(&mut rng);
+    let (sk2, pk2) = gen_keypair(&mut rng);
+    let pk = (pk1, pk2);
+
+    let (ek, ss1) = Hybrid::encap(&X25519Encap, &X25519Encap, &mut rng, &pk).unwrap();
+
+    // Round-trip the combined encapped key through its serialized form
+    let ek = HybridEncappedKey::from_bytes(ek.as_bytes()).unwrap();
+    let ss2 = Hybrid::decap(&sk1, &sk2, &ek, &pk).unwrap();
+    assert_eq!(ss1.as_bytes(), ss2.as_bytes());
+
+    // Decapsulating with the component keys swapped fails to produce the same secret
+    let ss3 = Hybrid::decap(&sk2, &sk1, &ek, &pk).unwrap();
+    assert_ne!(ss1.as_bytes(), ss3.as_bytes());
+}
+
+// Combine the shared secrets with the default combiner over SHA-256
+#[cfg(feature = "digest")]
+struct HashHybrid;
+
+#[cfg(feature = "digest")]
+impl KemCombiner for HashHybrid {
+    type First = X25519EncappedKey;
+    type Second = X25519EncappedKey;
+    type EncappedKey = HybridEncappedKey;
+
+    fn join(first: X25519EncappedKey, second: X25519EncappedKey) -> HybridEncappedKey {
+        Hybrid::join(first, second)
+    }
+
+    fn split(
+        encapped_key: &HybridEncappedKey,
+    ) -> Result<(X25519EncappedKey, X25519EncappedKey), Error> {
+        Hybrid::split(encapped_key)
+    }
+
+    fn combine(
+        first: &SharedSecret<X25519EncappedKey>,
+        second: &SharedSecret<X25519EncappedKey>,
+        encapped_key: &HybridEncappedKey,
+        _recip_pubkey: &CombinedPublicKey<Self>,
+    ) -> SharedSecret<HybridEncappedKey> {
+        hash_combine::<Sha256, _, _, _>(b"test hybrid KEM", first, second, encapped_key)
+    }
+}
+
+// An encapped key whose shared secret is longer than the output of SHA-256
+#[cfg(feature = "digest")]
+#[derive(Debug)]
+struct WideEncappedKey;
+
+#[cfg(feature = "digest")]
+impl EncappedKey for WideEncappedKey {
+    type SharedSecretSize = U48;
+    type EncappedKeySize = U0;
+    type RecipientPublicKey = ();
+    type SenderPublicKey = ();
+
+    fn from_bytes(_bytes: &GenericArray<u8, U0>) -> Result<Self, Error> {
+        Ok(WideEncappedKey)
+    }
+}
+
+#[cfg(feature = "digest")]
+impl AsRef<[u8]> for WideEncappedKey {
+    fn as_ref(&self) -> &[u8] {
+        &[]
+    }
+}
+
+#[cfg(feature = "digest")]
+#[test]
+fn test_hash_combiner() {
+    let mut rng = OsRng;
+    let (sk1, pk1) = gen_keypair(&mut rng);
+    let (sk2, pk2) = gen_keypair(&mut rng);
+    let pk = (pk1, pk2);
+
+    let (ek, ss1) = HashHybrid::encap(&X25519Encap, &X25519Encap, &mut rng, &pk).unwrap();
+    let ss2 = HashHybrid::decap(&sk1, &sk2, &ek, &pk).unwrap();
+    assert_eq!(ss1.as_bytes(), ss2.as_bytes());
+
+    // The combined secret is the hash of the label, both secrets and the encapped key
+    let (ek1, ek2) = HashHybrid::split(&ek).unwrap();
+    let (first, second) = (sk1.try_decap(&ek1).unwrap(), sk2.try_decap(&ek2).unwrap());
+    let expected = Sha256::new()
+        .chain_update(15u64.to_be_bytes())
+        .chain_update(b"test hybrid KEM")
+        .chain_update(first.as_bytes())
+        .chain_update(second.as_bytes())
+        .chain_update(&ek)
+        .chain_update(0u32.to_be_bytes())
+        .finalize();
+    assert_eq!(ss1.as_bytes(), expected.as_slice());
+
+    // Changing the label, either secret or the encapped key changes the combined secret
+    let combined = |label: &[u8], first, second, ek| {
+        hash_combine::<Sha256, X25519EncappedKey, X25519EncappedKey, HybridEncappedKey>(
+            label, first, second, ek,
+        )
+    };
+    let other_ek = HybridEncappedKey(GenericArray::default());
+    for ss in [
+        combined(b"other label", &first, &second, &ek),
+        combined(b"test hybrid KEM", &second, &first, &ek),
+        combined(b"test hybrid KEM", &first, &second, &other_ek),
+    ] {
+        assert_ne!(ss1.as_bytes(), ss.as_bytes());
+    }
+
+    // Secrets longer than the hash output are expanded with a block counter
+    let wide: SharedSecret<WideEncappedKey> =
+        hash_combine::<Sha256, _, _, _>(b"wide", &first, &second, &WideEncappedKey);
+    let block = |i: u32| {
+        Sha256::new()
+            .chain_update(4u64.to_be_bytes())
+            .chain_update(b"wide")
+            .chain_update(first.as_bytes())
+            .chain_update(second.as_bytes())
+            .chain_update(i.to_be_bytes())
+            .finalize()
+    };
+    assert_eq!(&wide.as_bytes()[..32], block(0).as_slice());
+    assert_eq!(&wide.as_bytes()[32..], &block(1)[..16]);
+}
@@ -3,10 +3,15 @@ use hpke::{
     Deserializable as HpkeDeserializable, Serializable as HpkeSerializable,
 };
 use kem::{
-    generic_array::GenericArray, AuthDecapsulator, Decapsulator, EncappedKey, Encapsulator, Error,
-    SharedSecret,
+    generic_array::{typenum::U32, GenericArray},
+    AuthDecapsulator, DecapsulateMulti, Decapsulator, EncappedKey, EncapsulateAuth,
+    EncapsulateDeterministic, EncapsulateMulti, Encapsulator, EncodedSizeUser, Error, Extract,
+    HpkeKem, SharedSecret,
+};
+use rand::{
+    rngs::{OsRng, StdRng},
+    SeedableRng,
 };
-use rand::rngs::OsRng;
 use rand_core::{CryptoRng, RngCore};
 
 // Define the pubkey type. This has no trait bounds required by the library
@@ -64,7 +69,23 @@ impl Encapsulator<X25519EncappedKey> for X25519AuthEncap {
     }
 }
 
+// The same encapsulator through the AKEM interface
+impl EncapsulateAuth<X25519EncappedKey> for X25519AuthEncap {
+    fn sender_pubkey(&self) -> X25519PublicKey {
+        self.1.clone()
+    }
+
+    fn try_auth_encap<R: RngCore + CryptoRng>(
+        &self,
+        csprng: &mut R,
+        recip_pubkey: &X25519PublicKey,
+    ) -> Result<(X25519EncappedKey, X25519SharedSecret), Error> {
+        self.try_encap(csprng, recip_pubkey)
+    }
+}
+
 // Define an unauthenticated encapsulator. This doesn't need any state at all.
+#[derive(Default)]
 struct X25519Encap;
 impl Encapsulator<X25519EncappedKey> for X25519Encap {
     fn try_encap<R: RngCore + CryptoRng>(
@@ -83,6 +104,19 @@ impl Encapsulator<X25519EncappedKey> for X25519Encap {
     }
 }
 
+// Derandomize the unauthenticated encapsulator by driving it with an RNG seeded from the input
+impl EncapsulateDeterministic<X25519EncappedKey> for X25519Encap {
+    type SeedSize = U32;
+
+    fn try_encap_deterministic(
+        &self,
+        seed: &GenericArray<u8, U32>,
+        recip_pubkey: &X25519PublicKey,
+    ) -> Result<(X25519EncappedKey, X25519SharedSecret), Error> {
+        self.try_encap(&mut StdRng::from_seed((*seed).into()), recip_pubkey)
+    }
+}
+
 // Define an decapsulator. Since authenticated and unauthenticated encapped keys are represented by
 // the same type (which, outside of testing, should not be the case), this can do both auth'd and
 // unauth'd decapsulation.
@@ -101,6 +135,11 @@ impl Decapsulator<X25519EncappedKey> for X25519PrivateKey {
             .map_err(|_| Error)
     }
 }
+
+// Use the provided multi-recipient encapsulation, which is fine since HPKE shared secrets are KDF
+// outputs
+impl EncapsulateMulti<X25519EncappedKey> for X25519Encap {}
+impl DecapsulateMulti<X25519EncappedKey> for X25519PrivateKey {}
 impl AuthDecapsulator<X25519EncappedKey> for X25519PrivateKey {
     fn try_auth_decap(
         &self,
@@ -125,6 +164,30 @@ impl AuthDecapsulator<X25519EncappedKey> for X25519PrivateKey {
     }
 }
 
+// Tie all of the above together as DHKEM(X25519, HKDF-SHA256)
+struct DhKemX25519;
+impl HpkeKem for DhKemX25519 {
+    const KEM_ID: u16 = 0x0020;
+    type PublicKeySize =
+        <<X25519HkdfSha256 as KemTrait>::PublicKey as HpkeSerializable>::OutputSize;
+    type PublicKey = X25519PublicKey;
+    type PrivateKey = X25519PrivateKey;
+    type EncappedKey = X25519EncappedKey;
+    type Encapsulator = X25519Encap;
+
+    fn serialize_public_key(public_key: &X25519PublicKey) -> GenericArray<u8, Self::PublicKeySize> {
+        public_key.0.to_bytes()
+    }
+
+    fn deserialize_public_key(
+        bytes: &GenericArray<u8, Self::PublicKeySize>,
+    ) -> Result<X25519PublicKey, Error> {
+        <<X25519HkdfSha256 as KemTrait>::PublicKey as HpkeDeserializable>::from_bytes(bytes)
+            .map(X25519PublicKey)
+            .map_err(|_| Error)
+    }
+}
+
 // A simple wrapper around the keypair generation function
 fn gen_keypair<R: RngCore + CryptoRng>(csprng: &mut R) -> (X25519PrivateKey, X25519PublicKey) {
     let (sk, pk) = X25519HkdfSha256::gen_keypair(csprng);
@@ -161,3 +224,123 @@ fn test_hpke() {
     let ss2 = sk_recip.try_auth_decap(&ek, &pk_sender).unwrap();
     assert_ne!(ss1.as_bytes(), ss2.as_bytes());
 }
+
+#[test]
+fn test_hpke_kem() {
+    let mut rng = OsRng;
+    let (sk_recip, pk_recip) = gen_keypair(&mut rng);
+
+    assert_eq!(DhKemX25519::suite_id(), *b"KEM\x00\x20");
+
+    // Round-trip the public key through its serialized form before encapsulating to it
+    let pk_bytes = DhKemX25519::serialize_public_key(&pk_recip);
+    let pk_recip = DhKemX25519::deserialize_public_key(&pk_bytes).unwrap();
+
+    let (ek, ss1) = DhKemX25519::encap(&mut rng, &pk_recip).unwrap();
+    let ek = X25519EncappedKey::from_bytes(ek.as_bytes()).unwrap();
+    let ss2 = DhKemX25519::decap(&sk_recip, &ek).unwrap();
+    assert_eq!(ss1.as_bytes(), ss2.as_bytes());
+}
+
+#[test]
+fn test_hpke_deterministic() {
+    let mut rng = OsRng;
+    let (sk_recip, pk_recip) = gen_keypair(&mut rng);
+    let seed = GenericArray::from([0x42; 32]);
+
+    // Encapsulating twice with the same seed yields the same results
+    let (ek1, ss1) = X25519Encap
+        .try_encap_deterministic(&seed, &pk_recip)
+        .unwrap();
+    let (ek2, ss2) = X25519Encap
+        .try_encap_deterministic(&seed, &pk_recip)
+        .unwrap();
+    assert_eq!(ek1.as_bytes(), ek2.as_bytes());
+    assert_eq!(ss1.as_bytes(), ss2.as_bytes());
+
+    // And the recipient can still decapsulate
+    let ss3 = sk_recip.try_decap(&ek1).unwrap();
+    assert_eq!(ss1.as_bytes(), ss3.as_bytes());
+}
+
+#[test]
+fn test_hpke_auth() {
+    let mut rng = OsRng;
+    let (sk_sender, pk_sender) = gen_keypair(&mut rng);
+    let (sk_recip, pk_recip) = gen_keypair(&mut rng);
+
+    let encapper = X25519AuthEncap(sk_sender, pk_sender);
+    let (ek, ss1) = encapper.try_auth_encap(&mut rng, &pk_recip).unwrap();
+    let ss2 = sk_recip
+        .try_auth_decap(&ek, &encapper.sender_pubkey())
+        .unwrap();
+    assert_eq!(ss1.as_bytes(), ss2.as_bytes());
+
+    // Decapsulating against a different sender identity yields a different secret
+    let (_, pk_other) = gen_keypair(&mut rng);
+    let ss3 = sk_recip.try_auth_decap(&ek, &pk_other).unwrap();
+    assert_ne!(ss1.as_bytes(), ss3.as_bytes());
+}
+
+#[test]
+fn test_encoded_size_user() {
+    let mut rng = OsRng;
+    let (sk_recip, pk_recip) = gen_keypair(&mut rng);
+
+    // Serialize the encapsulated key for transport, then parse it on the other side
+    let (ek, ss1) = X25519Encap.try_encap(&mut rng, &pk_recip).unwrap();
+    let encoded = ek.to_encoded_bytes();
+    assert_eq!(encoded.as_slice(), ek.as_ref());
+
+    let ek = X25519EncappedKey::from_encoded_slice(&encoded).unwrap();
+    let ss2 = sk_recip.try_decap(&ek).unwrap();
+    assert_eq!(ss1.as_bytes(), ss2.as_bytes());
+
+    // Encodings of the wrong length are rejected
+    assert!(X25519EncappedKey::from_encoded_slice(&encoded[1..]).is_err());
+}
+
+#[test]
+fn test_hpke_multi() {
+    let mut rng = OsRng;
+    let recipients: Vec<_> = (0..3).map(|_| gen_keypair(&mut rng)).collect();
+    let pubkeys: Vec<_> = recipients.iter().map(|(_, pk)| pk.clone()).collect();
+
+    // Every recipient recovers the same shared secret from their own encapsulation
+    let mut encapsulations = Vec::new();
+    let ss1 = X25519Encap
+        .try_encap_multi(&mut rng, &pubkeys, &mut encapsulations)
+        .unwrap();
+    assert_eq!(encapsulations.len(), recipients.len());
+
+    for ((sk, _), encapsulation) in recipients.iter().zip(&encapsulations) {
+        let ss2 = sk.try_decap_multi(encapsulation).unwrap();
+        assert_eq!(ss1.as_bytes(), ss2.as_bytes());
+    }
+
+    // A recipient can't recover the shared secret from another recipient's encapsulation
+    let ss2 = recipients[0].0.try_decap_multi(&encapsulations[1]).unwrap();
+    assert_ne!(ss1.as_bytes(), ss2.as_bytes());
+}
+
+/// Toy KDF whose pseudorandom key is the concatenation of the salt and input keying material
+#[derive(Debug, PartialEq)]
+struct ConcatKdf(Vec<u8>);
+
+impl Extract for ConcatKdf {
+    fn extract(salt: &[u8], ikm: &[u8]) -> Self {
+        ConcatKdf([salt, ikm].concat())
+    }
+}
+
+#[test]
+fn test_shared_secret_extract() {
+    let ss = SharedSecret::<X25519EncappedKey>::new(GenericArray::clone_from_slice(&[7u8; 32]));
+
+    // The shared secret is passed to the KDF as input keying material
+    let prk: ConcatKdf = ss.extract(b"salt");
+    assert_eq!(prk.0, [&b"salt"[..], &[7; 32]].concat());
+
+    let prk: ConcatKdf = ss.extract(&[]);
+    assert_eq!(prk, ConcatKdf(vec![7; 32]));
+}
@@ -0,0 +1,107 @@
+//! Builder for password hashes.
+
+use crate::{Decimal, Error, Ident, Output, ParamsString, PasswordHash, Result, Salt, Value};
+
+/// Builder for constructing a [`PasswordHash`] programmatically, e.g. to
+/// serialize it as a PHC string.
+///
+/// ```
+/// use password_hash::{Ident, PasswordHashBuilder, Salt};
+///
+/// let hash = PasswordHashBuilder::new(Ident::new_unwrap("example"))
+///     .version(1)
+///     .add_decimal("m", 4096)?
+///     .add_str("p", "1")?
+///     .salt(Salt::new("saltsaltsaltsalt")?)
+///     .build()?;
+///
+/// assert_eq!(hash.to_string(), "$example$v=1$m=4096,p=1$saltsaltsaltsalt");
+/// # Ok::<(), password_hash::Error>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct PasswordHashBuilder<'a> {
+    algorithm: Ident<'a>,
+    version: Option<Decimal>,
+    params: ParamsString,
+    salt: Option<Salt<'a>>,
+    hash: Option<Output>,
+}
+
+impl<'a> PasswordHashBuilder<'a> {
+    /// Create a new builder for a password hash using the given algorithm.
+    pub fn new(algorithm: Ident<'a>) -> Self {
+        Self {
+            algorithm,
+            version: None,
+            params: ParamsString::new(),
+            salt: None,
+            hash: None,
+        }
+    }
+
+    /// Set the algorithm version.
+    pub fn version(mut self, version: Decimal) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Set the algorithm-specific parameters, replacing any added so far.
+    pub fn params(mut self, params: ParamsString) -> Self {
+        self.params = params;
+        self
+    }
+
+    /// Add a parameter with a [`Decimal`] value.
+    pub fn add_decimal(mut self, name: impl TryInto<Ident<'a>>, value: Decimal) -> Result<Self> {
+        self.params.add_decimal(name, value)?;
+        Ok(self)
+    }
+
+    /// Add a parameter with a string value.
+    pub fn add_str(
+        mut self,
+        name: impl TryInto<Ident<'a>>,
+        value: impl TryInto<Value<'a>>,
+    ) -> Result<Self> {
+        self.params.add_str(name, value)?;
+        Ok(self)
+    }
+
+    /// Add a parameter with a "B64"-encoded bytestring value.
+    pub fn add_b64_bytes(mut self, name: impl TryInto<Ident<'a>>, bytes: &[u8]) -> Result<Self> {
+        self.params.add_b64_bytes(name, bytes)?;
+        Ok(self)
+    }
+
+    /// Set the [`Salt`].
+    pub fn salt(mut self, salt: Salt<'a>) -> Self {
+        self.salt = Some(salt);
+        self
+    }
+
+    /// Set the hash [`Output`].
+    ///
+    /// A salt must also be set, as required by the PHC string format.
+    pub fn hash(mut self, hash: Output) -> Self {
+        self.hash = Some(hash);
+        self
+    }
+
+    /// Build the [`PasswordHash`].
+    ///
+    /// Returns [`Error::PhcStringField`] if a hash output was provided
+    /// without a salt.
+    pub fn build(self) -> Result<PasswordHash<'a>> {
+        if self.hash.is_some() && self.salt.is_none() {
+            return Err(Error::PhcStringField);
+        }
+
+        Ok(PasswordHash {
+            algorithm: self.algorithm,
+            version: self.version,
+            params: self.params,
+            salt: self.salt,
+            hash: self.hash,
+        })
+    }
+}
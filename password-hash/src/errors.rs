@@ -58,6 +58,9 @@ pub enum Error {
     /// Password hash string contains trailing data.
     PhcStringTrailingData,
 
+    /// Password hash string exceeds the maximum allowed length.
+    PhcStringTooLong,
+
     /// Salt invalid.
     SaltInvalid(InvalidValue),
 
@@ -93,6 +96,7 @@ impl fmt::Display for Error {
             Self::PhcStringTrailingData => {
                 write!(f, "password hash string contains trailing characters")
             }
+            Self::PhcStringTooLong => write!(f, "password hash string too long"),
             Self::SaltInvalid(val_err) => write!(f, "salt invalid: {}", val_err),
             Self::Version => write!(f, "invalid algorithm version"),
         }
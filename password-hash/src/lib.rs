@@ -31,8 +31,10 @@ pub use rand_core;
 
 pub mod errors;
 
+mod builder;
 mod encoding;
 mod ident;
+mod options;
 mod output;
 mod params;
 mod salt;
@@ -40,9 +42,11 @@ mod traits;
 mod value;
 
 pub use crate::{
+    builder::PasswordHashBuilder,
     encoding::Encoding,
     errors::{Error, Result},
     ident::Ident,
+    options::{ParseMode, ParseOptions},
     output::Output,
     params::ParamsString,
     salt::{Salt, SaltString},
@@ -50,6 +54,9 @@ pub use crate::{
     value::{Decimal, Value},
 };
 
+#[cfg(feature = "async")]
+pub use crate::traits::{AsyncPasswordHasher, AsyncPasswordVerifier};
+
 use core::fmt::{self, Debug};
 
 #[cfg(feature = "alloc")]
@@ -131,6 +138,47 @@ impl<'a> PasswordHash<'a> {
 
     /// Parse a password hash from the given [`Encoding`].
     pub fn parse(s: &'a str, encoding: Encoding) -> Result<Self> {
+        Self::parse_with_options(s, &ParseOptions::new().with_encoding(encoding))
+    }
+
+    /// Parse a password hash using the given [`ParseOptions`].
+    ///
+    /// In addition to the checks performed by [`PasswordHash::parse`], this
+    /// enforces the configured maximum length and, in [`ParseMode::Strict`],
+    /// rejects unknown parameters and non-canonically encoded salts.
+    pub fn parse_with_options(s: &'a str, options: &ParseOptions<'_>) -> Result<Self> {
+        if let Some(max_length) = options.max_length() {
+            if s.len() > max_length {
+                return Err(Error::PhcStringTooLong);
+            }
+        }
+
+        let hash = Self::parse_lenient(s, options.encoding())?;
+
+        if options.mode() == ParseMode::Strict {
+            for (name, _) in hash.params.iter() {
+                if !options.known_params().contains(&name.as_str()) {
+                    return Err(Error::ParamNameInvalid);
+                }
+            }
+
+            // Re-encode the salt rather than relying on the decoder to reject
+            // non-zero trailing bits, which older `base64ct` releases accept
+            if let Some(salt) = &hash.salt {
+                let mut buf = [0u8; Salt::MAX_LENGTH];
+                let decoded = salt.b64_decode(&mut buf)?;
+
+                if SaltString::b64_encode(decoded)?.as_str() != salt.as_str() {
+                    return Err(Error::SaltInvalid(errors::InvalidValue::Malformed));
+                }
+            }
+        }
+
+        Ok(hash)
+    }
+
+    /// Parse a password hash, performing only syntactic validation.
+    fn parse_lenient(s: &'a str, encoding: Encoding) -> Result<Self> {
         if s.is_empty() {
             return Err(Error::PhcStringField);
         }
@@ -0,0 +1,98 @@
+//! Options for parsing password hashes.
+
+use crate::Encoding;
+
+/// Mode used when parsing a [`PasswordHash`][`crate::PasswordHash`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum ParseMode {
+    /// Accept any syntactically valid PHC string.
+    Lenient,
+
+    /// Additionally reject PHC strings containing parameters which aren't
+    /// [known][`ParseOptions::known_params`], or a salt which isn't
+    /// canonically "B64"-encoded.
+    Strict,
+}
+
+impl Default for ParseMode {
+    fn default() -> Self {
+        Self::Lenient
+    }
+}
+
+/// Options for parsing a [`PasswordHash`][`crate::PasswordHash`] with
+/// [`PasswordHash::parse_with_options`][`crate::PasswordHash::parse_with_options`].
+///
+/// This is useful when validating password hashes from untrusted sources,
+/// e.g. when importing them from a third party.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct ParseOptions<'a> {
+    encoding: Encoding,
+    mode: ParseMode,
+    known_params: &'a [&'a str],
+    max_length: Option<usize>,
+}
+
+impl<'a> ParseOptions<'a> {
+    /// Create options for lenient parsing with the default [`Encoding`].
+    pub const fn new() -> Self {
+        Self {
+            encoding: Encoding::B64,
+            mode: ParseMode::Lenient,
+            known_params: &[],
+            max_length: None,
+        }
+    }
+
+    /// Create options for strict parsing with the default [`Encoding`],
+    /// accepting only the given parameter names.
+    pub const fn strict(known_params: &'a [&'a str]) -> Self {
+        Self::new()
+            .with_mode(ParseMode::Strict)
+            .with_known_params(known_params)
+    }
+
+    /// Set the [`Encoding`] of the hash output.
+    pub const fn with_encoding(mut self, encoding: Encoding) -> Self {
+        self.encoding = encoding;
+        self
+    }
+
+    /// Set the [`ParseMode`].
+    pub const fn with_mode(mut self, mode: ParseMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Set the parameter names accepted in [`ParseMode::Strict`].
+    pub const fn with_known_params(mut self, known_params: &'a [&'a str]) -> Self {
+        self.known_params = known_params;
+        self
+    }
+
+    /// Set the maximum length of the PHC string in bytes.
+    pub const fn with_max_length(mut self, max_length: usize) -> Self {
+        self.max_length = Some(max_length);
+        self
+    }
+
+    /// Get the [`Encoding`] of the hash output.
+    pub const fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    /// Get the [`ParseMode`].
+    pub const fn mode(&self) -> ParseMode {
+        self.mode
+    }
+
+    /// Get the parameter names accepted in [`ParseMode::Strict`].
+    pub const fn known_params(&self) -> &'a [&'a str] {
+        self.known_params
+    }
+
+    /// Get the maximum length of the PHC string in bytes, if any.
+    pub const fn max_length(&self) -> Option<usize> {
+        self.max_length
+    }
+}
@@ -3,6 +3,9 @@
 use crate::{Decimal, Error, Ident, ParamsString, PasswordHash, Result, Salt};
 use core::fmt::Debug;
 
+#[cfg(feature = "async")]
+use {alloc::boxed::Box, async_trait::async_trait};
+
 /// Trait for password hashing functions.
 pub trait PasswordHasher {
     /// Algorithm-specific parameters.
@@ -37,6 +40,22 @@ pub trait PasswordHasher {
     ) -> Result<PasswordHash<'a>> {
         self.hash_password_customized(password, None, None, Self::Params::default(), salt)
     }
+
+    /// Determine whether the provided [`PasswordHash`] was computed using
+    /// different parameters than `params`, e.g. outdated cost parameters, and
+    /// should therefore be recomputed.
+    ///
+    /// This is intended to be used after successfully verifying a password,
+    /// so the hash can be transparently upgraded using the password.
+    ///
+    /// The default implementation compares the serialized parameters parsed
+    /// from `hash` with those of `params`. Implementations which support
+    /// several algorithms or versions should override it to check them too.
+    fn needs_rehash(&self, hash: &PasswordHash<'_>, params: &Self::Params) -> Result<bool> {
+        let current: ParamsString = Self::Params::try_from(hash)?.try_into()?;
+        let target: ParamsString = params.clone().try_into()?;
+        Ok(current != target)
+    }
 }
 
 /// Trait for password verification.
@@ -76,6 +95,59 @@ impl<T: PasswordHasher> PasswordVerifier for T {
     }
 }
 
+/// Trait for asynchronously computing password hashes, e.g. on a thread
+/// pool, GPU, or external service, so async applications don't block while
+/// doing so.
+///
+/// Automatically impl'd for any type that impls [`PasswordHasher`] (which
+/// doesn't avoid blocking, but allows sync hashers to be used with APIs
+/// expecting this trait).
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+#[async_trait]
+pub trait AsyncPasswordHasher: Send + Sync {
+    /// Compute a [`PasswordHash`] from the provided password and salt value
+    /// using the default recommended parameters for the algorithm.
+    async fn hash_password_async<'a>(
+        &self,
+        password: &[u8],
+        salt: Salt<'a>,
+    ) -> Result<PasswordHash<'a>>;
+}
+
+#[cfg(feature = "async")]
+#[async_trait]
+impl<T: PasswordHasher + Send + Sync> AsyncPasswordHasher for T {
+    async fn hash_password_async<'a>(
+        &self,
+        password: &[u8],
+        salt: Salt<'a>,
+    ) -> Result<PasswordHash<'a>> {
+        self.hash_password(password, salt)
+    }
+}
+
+/// Trait for asynchronous password verification.
+///
+/// Automatically impl'd for any type that impls [`PasswordVerifier`].
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+#[async_trait]
+pub trait AsyncPasswordVerifier: Send + Sync {
+    /// Compute this password hashing function against the provided password
+    /// using the parameters from the provided password hash and see if the
+    /// computed output matches.
+    async fn verify_password_async(&self, password: &[u8], hash: &PasswordHash<'_>) -> Result<()>;
+}
+
+#[cfg(feature = "async")]
+#[async_trait]
+impl<T: PasswordVerifier + Send + Sync> AsyncPasswordVerifier for T {
+    async fn verify_password_async(&self, password: &[u8], hash: &PasswordHash<'_>) -> Result<()> {
+        self.verify_password(password, hash)
+    }
+}
+
 /// Trait for password hashing algorithms which support the legacy
 /// [Modular Crypt Format (MCF)][MCF].
 ///
@@ -65,6 +65,53 @@ impl<'a> TryFrom<StubParams> for ParamsString {
     }
 }
 
+/// Stub password hashing function with a cost parameter.
+pub struct StubCostHasher;
+
+impl PasswordHasher for StubCostHasher {
+    type Params = CostParams;
+
+    fn hash_password_customized<'a>(
+        &self,
+        password: &[u8],
+        algorithm: Option<Ident<'a>>,
+        version: Option<Decimal>,
+        params: CostParams,
+        salt: impl Into<Salt<'a>>,
+    ) -> Result<PasswordHash<'a>> {
+        let mut hash = StubPasswordHasher
+            .hash_password_customized(password, algorithm, version, StubParams, salt)?;
+        hash.params = params.try_into()?;
+        Ok(hash)
+    }
+}
+
+/// Stub parameters with a cost, serialized as `c`
+#[derive(Clone, Debug, Default)]
+pub struct CostParams {
+    /// Stub cost parameter.
+    pub cost: Decimal,
+}
+
+impl<'a> TryFrom<&PasswordHash<'a>> for CostParams {
+    type Error = Error;
+
+    fn try_from(hash: &PasswordHash<'a>) -> Result<Self> {
+        let cost = hash.params.get_decimal("c").unwrap_or_default();
+        Ok(Self { cost })
+    }
+}
+
+impl TryFrom<CostParams> for ParamsString {
+    type Error = Error;
+
+    fn try_from(params: CostParams) -> Result<Self> {
+        let mut output = Self::new();
+        output.add_decimal("c", params.cost)?;
+        Ok(output)
+    }
+}
+
 #[test]
 fn verify_password_hash() {
     let valid_password = "test password";
@@ -86,3 +133,80 @@ fn verify_password_hash() {
         Err(Error::Password)
     );
 }
+
+#[test]
+fn needs_rehash() {
+    let salt = Salt::new("test-salt").unwrap();
+    let hash = PasswordHash::generate(StubPasswordHasher, "test password", salt).unwrap();
+    assert!(!StubPasswordHasher.needs_rehash(&hash, &StubParams).unwrap());
+
+    // Hashes computed with outdated parameters need to be recomputed
+    let hash = PasswordHash::generate(StubCostHasher, "test password", salt).unwrap();
+    let params = CostParams { cost: 2 };
+    assert!(!StubCostHasher
+        .needs_rehash(&hash, &CostParams::default())
+        .unwrap());
+    assert!(StubCostHasher.needs_rehash(&hash, &params).unwrap());
+
+    let hash = StubCostHasher
+        .hash_password_customized(b"test password", None, None, params.clone(), salt)
+        .unwrap();
+    assert_eq!(hash.params.get_decimal("c"), Some(2));
+    assert!(!StubCostHasher.needs_rehash(&hash, &params).unwrap());
+    assert!(StubCostHasher
+        .needs_rehash(&hash, &CostParams::default())
+        .unwrap());
+}
+
+#[cfg(feature = "async")]
+mod asynchronous {
+    use super::*;
+    use core::{
+        future::Future,
+        task::{Context, Poll, RawWaker, RawWakerVTable, Waker},
+    };
+    use password_hash::{AsyncPasswordHasher, AsyncPasswordVerifier};
+
+    /// Minimal executor which polls a future on the current thread until it
+    /// completes.
+    fn block_on<F: Future>(future: F) -> F::Output {
+        const VTABLE: RawWakerVTable = RawWakerVTable::new(
+            |_| RawWaker::new(core::ptr::null(), &VTABLE),
+            |_| (),
+            |_| (),
+            |_| (),
+        );
+
+        let waker = unsafe { Waker::from_raw(RawWaker::new(core::ptr::null(), &VTABLE)) };
+        let mut context = Context::from_waker(&waker);
+        let mut future = Box::pin(future);
+
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+                return output;
+            }
+        }
+    }
+
+    #[test]
+    fn hash_and_verify_async() {
+        let salt = Salt::new("test-salt").unwrap();
+        let hash =
+            block_on(StubPasswordHasher.hash_password_async(b"test password", salt)).unwrap();
+
+        // Matches the synchronous implementation
+        assert_eq!(
+            hash,
+            PasswordHash::generate(StubPasswordHasher, "test password", salt).unwrap()
+        );
+
+        assert_eq!(
+            block_on(StubPasswordHasher.verify_password_async(b"test password", &hash)),
+            Ok(())
+        );
+        assert_eq!(
+            block_on(StubPasswordHasher.verify_password_async(b"wrong password", &hash)),
+            Err(Error::Password)
+        );
+    }
+}
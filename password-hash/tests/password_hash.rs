@@ -4,7 +4,9 @@
 //! of the string encoding, and ensures password hashes round trip under each
 //! of the conditions.
 
-use password_hash::{Ident, ParamsString, PasswordHash, Salt};
+use password_hash::{
+    Error, Ident, Output, ParamsString, ParseOptions, PasswordHash, PasswordHashBuilder, Salt,
+};
 
 const EXAMPLE_ALGORITHM: Ident = Ident::new_unwrap("argon2d");
 const EXAMPLE_SALT: &str = "saltsaltsaltsaltsalt";
@@ -144,3 +146,62 @@ fn all_fields() {
     let ph2 = PasswordHash::try_from(s.as_str()).unwrap();
     assert_eq!(ph, ph2);
 }
+
+#[test]
+fn builder() {
+    let salt = Salt::new(EXAMPLE_SALT).unwrap();
+    let hash = Output::new(EXAMPLE_HASH).unwrap();
+    let ph = PasswordHashBuilder::new(EXAMPLE_ALGORITHM)
+        .version(19)
+        .params(example_params())
+        .salt(salt)
+        .hash(hash)
+        .build()
+        .unwrap();
+
+    assert_eq!(
+        ph,
+        PasswordHash {
+            algorithm: EXAMPLE_ALGORITHM,
+            version: Some(19),
+            params: example_params(),
+            salt: Some(salt),
+            hash: Some(hash),
+        }
+    );
+
+    let err = PasswordHashBuilder::new(EXAMPLE_ALGORITHM)
+        .hash(hash)
+        .build()
+        .unwrap_err();
+    assert_eq!(err, Error::PhcStringField);
+}
+
+#[test]
+fn strict_parsing() {
+    let s = "$argon2d$v=19$a=1,b=2,c=3$saltsaltsaltsaltsalt";
+    let options = ParseOptions::strict(&["a", "b", "c"]);
+    let ph = PasswordHash::parse_with_options(s, &options).unwrap();
+    assert_eq!(ph, PasswordHash::new(s).unwrap());
+
+    // Unknown parameter
+    let options = ParseOptions::strict(&["a", "b"]);
+    assert_eq!(
+        PasswordHash::parse_with_options(s, &options),
+        Err(Error::ParamNameInvalid)
+    );
+    assert!(PasswordHash::parse_with_options(s, &ParseOptions::new()).is_ok());
+
+    // Non-canonical salt encoding
+    let s = "$argon2d$saltsaltsaltsaltsa";
+    assert!(PasswordHash::new(s).is_ok());
+    assert!(PasswordHash::parse_with_options(s, &ParseOptions::strict(&[])).is_err());
+
+    // Maximum length
+    let options = ParseOptions::new().with_max_length(16);
+    assert!(PasswordHash::parse_with_options("$argon2d", &options).is_ok());
+    assert_eq!(
+        PasswordHash::parse_with_options(s, &options),
+        Err(Error::PhcStringTooLong)
+    );
+}
@@ -7,11 +7,20 @@
 #![forbid(unsafe_code)]
 #![warn(missing_docs, rust_2018_idioms, unused_qualifications)]
 
+#[cfg(feature = "futures-core")]
+mod stream;
+
 pub use signature::{self, Error};
 
+#[cfg(feature = "futures-core")]
+pub use crate::stream::{sign_stream, verify_stream};
+
 #[cfg(feature = "digest")]
 pub use signature::digest::{self, Digest};
 
+#[cfg(feature = "rand_core")]
+pub use signature::rand_core::{self, CryptoRngCore};
+
 use async_trait::async_trait;
 
 /// Asynchronously sign the provided message bytestring using `Self`
@@ -71,3 +80,119 @@ where
         self.try_sign_digest(digest)
     }
 }
+
+/// Asynchronously sign the given message using the provided external
+/// randomness source.
+///
+/// This trait is an async equivalent of the [`signature::RandomizedSigner`]
+/// trait.
+#[cfg(feature = "rand_core")]
+#[async_trait]
+pub trait AsyncRandomizedSigner<S>
+where
+    Self: Send + Sync,
+    S: Send + 'static,
+{
+    /// Attempt to sign the given message, returning a digital signature on
+    /// success, or an error if something went wrong.
+    async fn sign_with_rng_async<R>(&self, rng: &mut R, msg: &[u8]) -> Result<S, Error>
+    where
+        R: CryptoRngCore + Send;
+}
+
+#[cfg(feature = "rand_core")]
+#[async_trait]
+impl<S, T> AsyncRandomizedSigner<S> for T
+where
+    S: Send + 'static,
+    T: signature::RandomizedSigner<S> + Send + Sync,
+{
+    async fn sign_with_rng_async<R>(&self, rng: &mut R, msg: &[u8]) -> Result<S, Error>
+    where
+        R: CryptoRngCore + Send,
+    {
+        self.try_sign_with_rng(rng, msg)
+    }
+}
+
+/// Asynchronously sign the given prehashed message [`Digest`] using the
+/// provided external randomness source.
+///
+/// This trait is an async equivalent of the
+/// [`signature::RandomizedDigestSigner`] trait.
+#[cfg(all(feature = "digest", feature = "rand_core"))]
+#[async_trait]
+pub trait AsyncRandomizedDigestSigner<D, S>
+where
+    Self: Send + Sync,
+    D: Digest + Send + 'static,
+    S: 'static,
+{
+    /// Attempt to sign the given prehashed message [`Digest`], returning a
+    /// digital signature on success, or an error if something went wrong.
+    async fn sign_digest_with_rng_async<R>(&self, rng: &mut R, digest: D) -> Result<S, Error>
+    where
+        R: CryptoRngCore + Send;
+}
+
+#[cfg(all(feature = "digest", feature = "rand_core"))]
+#[async_trait]
+impl<D, S, T> AsyncRandomizedDigestSigner<D, S> for T
+where
+    D: Digest + Send + 'static,
+    S: Send + 'static,
+    T: signature::RandomizedDigestSigner<D, S> + Send + Sync,
+{
+    async fn sign_digest_with_rng_async<R>(&self, rng: &mut R, digest: D) -> Result<S, Error>
+    where
+        R: CryptoRngCore + Send,
+    {
+        self.try_sign_digest_with_rng(rng, digest)
+    }
+}
+
+/// Opaque reference to a key held by an external signer, e.g. an HSM, TPM,
+/// or cloud KMS, which never exposes the key material itself.
+///
+/// Types which impl this trait can impl [`AsyncSigner`] by sending signing
+/// requests for the referenced key to the backend, and [`AsyncKeypair`] by
+/// requesting its public key.
+pub trait KeyHandle: Send + Sync {
+    /// Identifier of the key within its backend, e.g. a KMS key ARN or a
+    /// PKCS#11 `CKA_ID`.
+    type KeyId: ?Sized;
+
+    /// Get the identifier of the referenced key.
+    fn key_id(&self) -> &Self::KeyId;
+}
+
+/// Asynchronously retrieve the verifying key corresponding to a signing key,
+/// e.g. one held by a remote signer which must be queried for it.
+///
+/// This trait is an async equivalent of the [`signature::Keypair`] trait.
+#[async_trait]
+pub trait AsyncKeypair
+where
+    Self: Send + Sync,
+{
+    /// Verifying key type for this keypair.
+    type VerifyingKey: Send + 'static;
+
+    /// Attempt to get the verifying key which can verify signatures produced
+    /// by the signing key portion of this keypair, returning an error if
+    /// something went wrong, e.g. communicating with the backend.
+    async fn verifying_key_async(&self) -> Result<Self::VerifyingKey, Error>;
+}
+
+#[async_trait]
+impl<T> AsyncKeypair for T
+where
+    T: signature::Keypair + Send + Sync,
+    T::VerifyingKey: Send + 'static,
+{
+    type VerifyingKey = T::VerifyingKey;
+
+    async fn verifying_key_async(&self) -> Result<T::VerifyingKey, Error> {
+        Ok(self.verifying_key())
+    }
+}
@@ -0,0 +1,65 @@
+//! Adapters for signing and verifying messages read from a [`Stream`].
+
+use crate::Error;
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_core::Stream;
+use signature::{StreamSigner, StreamState, StreamVerifier};
+
+/// Attempt to sign the message consisting of the concatenated chunks of
+/// `stream`, using a [`StreamSigner`].
+///
+/// Fallible streams can be mapped to infallible ones which stop at the first
+/// error, after which the error can be checked before using the signature.
+pub async fn sign_stream<S, T, St>(signer: &T, mut stream: St) -> Result<S, Error>
+where
+    T: StreamSigner<S>,
+    St: Stream + Unpin,
+    St::Item: AsRef<[u8]>,
+{
+    let mut state = signer.begin_sign();
+
+    while let Some(chunk) = Next(&mut stream).await {
+        state.update(chunk.as_ref());
+    }
+
+    signer.try_finalize_sign(state)
+}
+
+/// Verify the provided signature for the message consisting of the
+/// concatenated chunks of `stream`, using a [`StreamVerifier`].
+pub async fn verify_stream<S, T, St>(
+    verifier: &T,
+    mut stream: St,
+    signature: &S,
+) -> Result<(), Error>
+where
+    T: StreamVerifier<S>,
+    St: Stream + Unpin,
+    St::Item: AsRef<[u8]>,
+{
+    let mut state = verifier.begin_verify();
+
+    while let Some(chunk) = Next(&mut stream).await {
+        state.update(chunk.as_ref());
+    }
+
+    verifier.finalize_verify(state, signature)
+}
+
+/// Future which resolves to the next item of a [`Stream`].
+struct Next<'a, St>(&'a mut St);
+
+impl<St> Future for Next<'_, St>
+where
+    St: Stream + Unpin,
+{
+    type Output = Option<St::Item>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut *self.0).poll_next(cx)
+    }
+}
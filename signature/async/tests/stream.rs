@@ -0,0 +1,133 @@
+//! Tests for the `Stream` adapters
+
+#![cfg(feature = "futures-core")]
+
+use async_signature::{
+    sign_stream,
+    signature::{StreamSigner, StreamState, StreamVerifier},
+    verify_stream, Error,
+};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, RawWaker, RawWakerVTable, Waker},
+};
+use futures_core::Stream;
+
+/// Dummy signature which contains the signed message
+#[derive(Debug, PartialEq)]
+struct DummySignature(Vec<u8>);
+
+/// State which records the message input into it
+#[derive(Default)]
+struct Recorder(Vec<u8>);
+
+impl StreamState for Recorder {
+    fn update(&mut self, data: &[u8]) {
+        self.0.extend_from_slice(data);
+    }
+}
+
+/// Dummy key for which signatures are valid iff they equal the message
+struct DummyKey;
+
+impl StreamSigner<DummySignature> for DummyKey {
+    type State = Recorder;
+
+    fn begin_sign(&self) -> Recorder {
+        Recorder::default()
+    }
+
+    fn try_finalize_sign(&self, state: Recorder) -> Result<DummySignature, Error> {
+        Ok(DummySignature(state.0))
+    }
+}
+
+impl StreamVerifier<DummySignature> for DummyKey {
+    type State = Recorder;
+
+    fn begin_verify(&self) -> Recorder {
+        Recorder::default()
+    }
+
+    fn finalize_verify(&self, state: Recorder, signature: &DummySignature) -> Result<(), Error> {
+        if state.0 == signature.0 {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+/// Stream of chunks which is pending before yielding each of them
+struct Chunks {
+    chunks: Vec<&'static [u8]>,
+    pending: bool,
+}
+
+impl Chunks {
+    fn new(chunks: &[&'static [u8]]) -> Self {
+        Self {
+            chunks: chunks.iter().rev().copied().collect(),
+            pending: false,
+        }
+    }
+}
+
+impl Stream for Chunks {
+    type Item = &'static [u8];
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.pending = !self.pending;
+
+        if self.pending {
+            cx.waker().wake_by_ref();
+            Poll::Pending
+        } else {
+            Poll::Ready(self.chunks.pop())
+        }
+    }
+}
+
+/// Minimal executor which polls a future on the current thread until it
+/// completes
+fn block_on<F: Future>(future: F) -> F::Output {
+    const VTABLE: RawWakerVTable = RawWakerVTable::new(
+        |_| RawWaker::new(core::ptr::null(), &VTABLE),
+        |_| (),
+        |_| (),
+        |_| (),
+    );
+
+    let waker = unsafe { Waker::from_raw(RawWaker::new(core::ptr::null(), &VTABLE)) };
+    let mut context = Context::from_waker(&waker);
+    let mut future = Box::pin(future);
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+const CHUNKS: &[&[u8]] = &[b"hello", b"", b", ", b"world"];
+
+#[test]
+fn sign_and_verify_stream() {
+    let signature = block_on(sign_stream(&DummyKey, Chunks::new(CHUNKS))).unwrap();
+    assert_eq!(signature, DummySignature(b"hello, world".to_vec()));
+
+    assert!(block_on(verify_stream(&DummyKey, Chunks::new(CHUNKS), &signature)).is_ok());
+    assert!(block_on(verify_stream(
+        &DummyKey,
+        Chunks::new(&CHUNKS[..3]),
+        &signature
+    ))
+    .is_err());
+}
+
+#[test]
+fn empty_stream() {
+    let signature = block_on(sign_stream(&DummyKey, Chunks::new(&[]))).unwrap();
+    assert_eq!(signature, DummySignature(Vec::new()));
+}
@@ -0,0 +1,40 @@
+//! Traits for aggregating multiple signatures into one
+
+use crate::error::Error;
+
+/// Aggregate signature, which combines signatures over (possibly distinct)
+/// messages by (possibly distinct) signers into a single signature, e.g. BLS
+/// signatures or half-aggregated Schnorr signatures.
+///
+/// The type parameter `S` is the type of the individual signatures, which can
+/// be `Self` for schemes where aggregate signatures have the same form as the
+/// signatures they're aggregated from (e.g. BLS).
+pub trait AggregateSignature<S>: Sized {
+    /// Aggregate the given signatures into a single signature.
+    ///
+    /// Returns `Error` if no signatures are given or if any of them is
+    /// malformed.
+    fn aggregate(signatures: &[S]) -> Result<Self, Error>;
+}
+
+/// Verify an aggregate signature using the keys `Self` (e.g. public keys) of
+/// the signers.
+pub trait AggregateVerifier<S>: Sized {
+    /// Verify that `signature` is an authentic aggregate of signatures over
+    /// `msgs[i]` by `verifying_keys[i]`, for all `i`.
+    ///
+    /// Returns `Error` if the slices have different lengths or the signature
+    /// is inauthentic, or otherwise returns `()`.
+    ///
+    /// # ⚠️ Security Warning
+    ///
+    /// For schemes such as BLS where signatures by different signers over the
+    /// same message aggregate linearly, implementations must prevent
+    /// rogue-key attacks, e.g. by requiring the messages to be distinct or
+    /// the keys to have been accompanied by a proof of possession.
+    fn verify_aggregate(
+        msgs: &[&[u8]],
+        verifying_keys: &[Self],
+        signature: &S,
+    ) -> Result<(), Error>;
+}
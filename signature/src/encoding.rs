@@ -1,5 +1,7 @@
 //! Encoding support.
 
+use crate::error::Error;
+
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
@@ -24,3 +26,36 @@ pub trait SignatureEncoding:
         self.to_bytes().as_ref().to_vec()
     }
 }
+
+/// Encode signatures as ASN.1 DER, e.g. the `Ecdsa-Sig-Value` encoding of
+/// ECDSA signatures used by X.509 and CMS.
+pub trait ToDer: SignatureEncoding {
+    /// DER representation of a signature.
+    type DerRepr: 'static + AsRef<[u8]> + Clone + Send + Sync;
+
+    /// Encode signature as ASN.1 DER.
+    fn to_der(&self) -> Self::DerRepr;
+}
+
+/// Decode signatures from ASN.1 DER.
+pub trait FromDer: SignatureEncoding {
+    /// Decode signature from ASN.1 DER, returning `Error` if it's malformed.
+    fn from_der(bytes: &[u8]) -> Result<Self, Error>;
+}
+
+/// Encode signatures in a fixed-size form, e.g. the concatenation of the
+/// ECDSA `r` and `s` scalars required by JOSE.
+pub trait ToFixed: SignatureEncoding {
+    /// Fixed-size representation of a signature.
+    type FixedRepr: 'static + AsRef<[u8]> + Clone + Send + Sync;
+
+    /// Encode signature in its fixed-size form.
+    fn to_fixed(&self) -> Self::FixedRepr;
+}
+
+/// Decode signatures from a fixed-size form.
+pub trait FromFixed: SignatureEncoding {
+    /// Decode signature from its fixed-size form, returning `Error` if it's
+    /// malformed or has the wrong length.
+    fn from_fixed(bytes: &[u8]) -> Result<Self, Error>;
+}
@@ -10,32 +10,89 @@ use std::boxed::Box;
 /// A result with the `signature` crate's [`Error`] type.
 pub type Result<T> = core::result::Result<T, Error>;
 
+/// Kinds of signature errors.
+///
+/// These are deliberately coarse-grained: in particular, implementations
+/// should report all failures to parse or verify a signature as
+/// [`ErrorKind::InvalidSignature`], as distinguishing between them can leak
+/// information to an attacker.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// Signature is malformed or inauthentic.
+    InvalidSignature,
+
+    /// Key is malformed or unsuitable for the requested operation.
+    InvalidKey,
+
+    /// Digest algorithm or prehash length isn't supported.
+    UnsupportedDigest,
+
+    /// External signer (e.g. HSM, KMS) failed or couldn't be reached.
+    BackendFailure,
+
+    /// Other error.
+    Other,
+}
+
+impl ErrorKind {
+    /// Get a description of this kind of error.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::InvalidSignature => "invalid signature",
+            Self::InvalidKey => "invalid key",
+            Self::UnsupportedDigest => "unsupported digest",
+            Self::BackendFailure => "backend failure",
+            Self::Other => "other error",
+        }
+    }
+}
+
+impl Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// Signature errors.
 ///
 /// This type is deliberately opaque as to avoid sidechannel leakage which
 /// could potentially be used recover signing private keys or forge signatures
-/// (e.g. [BB'06]).
+/// (e.g. [BB'06]), exposing only a coarse-grained [`ErrorKind`].
 ///
 /// When the `std` feature is enabled, it impls [`std::error::Error`] and
 /// supports an optional [`std::error::Error::source`], which can be used by
 /// things like remote signers (e.g. HSM, KMS) to report I/O or auth errors.
 ///
 /// [BB'06]: https://en.wikipedia.org/wiki/Daniel_Bleichenbacher
-#[derive(Default)]
 #[non_exhaustive]
 pub struct Error {
+    /// Kind of error.
+    kind: ErrorKind,
+
     /// Source of the error (if applicable).
     #[cfg(feature = "std")]
     source: Option<Box<dyn std::error::Error + Send + Sync + 'static>>,
 }
 
 impl Error {
-    /// Create a new error with no associated source
+    /// Create a new error with no associated source, of kind
+    /// [`ErrorKind::Other`].
     pub fn new() -> Self {
-        Self::default()
+        Self::from_kind(ErrorKind::Other)
     }
 
-    /// Create a new error with an associated source.
+    /// Create a new error of the given kind with no associated source.
+    pub fn from_kind(kind: ErrorKind) -> Self {
+        Self {
+            kind,
+            #[cfg(feature = "std")]
+            source: None,
+        }
+    }
+
+    /// Create a new error with an associated source, of kind
+    /// [`ErrorKind::BackendFailure`].
     ///
     /// **NOTE:** The "source" should **NOT** be used to propagate cryptographic
     /// errors e.g. signature parsing or verification errors. The intended use
@@ -44,11 +101,34 @@ impl Error {
     #[cfg(feature = "std")]
     pub fn from_source(
         source: impl Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    ) -> Self {
+        Self::from_source_with_kind(ErrorKind::BackendFailure, source)
+    }
+
+    /// Create a new error of the given kind with an associated source.
+    ///
+    /// See [`Error::from_source`] regarding the intended use of the source.
+    #[cfg(feature = "std")]
+    pub fn from_source_with_kind(
+        kind: ErrorKind,
+        source: impl Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
     ) -> Self {
         Self {
+            kind,
             source: Some(source.into()),
         }
     }
+
+    /// Get the kind of this error.
+    pub fn kind(&self) -> ErrorKind {
+        self.kind
+    }
+}
+
+impl Default for Error {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Debug for Error {
@@ -86,6 +166,12 @@ impl Display for Error {
     }
 }
 
+impl From<ErrorKind> for Error {
+    fn from(kind: ErrorKind) -> Error {
+        Self::from_kind(kind)
+    }
+}
+
 #[cfg(feature = "std")]
 impl From<Box<dyn std::error::Error + Send + Sync + 'static>> for Error {
     fn from(source: Box<dyn std::error::Error + Send + Sync + 'static>) -> Error {
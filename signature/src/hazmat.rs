@@ -6,7 +6,7 @@
 //! Using them incorrectly can introduce security vulnerabilities. Please
 //! carefully read the documentation before attempting to use them.
 
-use crate::Error;
+use crate::{Error, ErrorKind};
 
 #[cfg(feature = "rand_core")]
 use crate::rand_core::CryptoRngCore;
@@ -26,6 +26,30 @@ pub trait PrehashSigner<S> {
     /// Allowed lengths are algorithm-dependent and up to a particular
     /// implementation to decide.
     fn sign_prehash(&self, prehash: &[u8]) -> Result<S, Error>;
+
+    /// Expected length of the prehash in bytes, i.e. the output size of the
+    /// digest algorithm this signer is intended to be used with, or `None`
+    /// if prehashes of any supported length are accepted.
+    ///
+    /// Generic code (e.g. for X.509 or CMS) which computes prehashes on
+    /// behalf of a signer can use this to guard against signing a prehash
+    /// computed with the wrong digest algorithm.
+    ///
+    /// Note that only the length is declared, not the algorithm itself (e.g.
+    /// by OID), so digests with the same output size (e.g. SHA-256 and
+    /// SHA3-256) can't be told apart.
+    fn prehash_len(&self) -> Option<usize> {
+        None
+    }
+
+    /// Check that `prehash` has the length returned by
+    /// [`PrehashSigner::prehash_len`], returning `Error` if it doesn't.
+    fn check_prehash_len(&self, prehash: &[u8]) -> Result<(), Error> {
+        match self.prehash_len() {
+            Some(len) if prehash.len() != len => Err(ErrorKind::UnsupportedDigest.into()),
+            _ => Ok(()),
+        }
+    }
 }
 
 /// Sign the provided message prehash using the provided external randomness source, returning a digital signature.
@@ -1,5 +1,8 @@
 //! Signing keypairs.
 
+#[cfg(feature = "rand_core")]
+use crate::{error::Error, rand_core::CryptoRngCore};
+
 /// Signing keypair with an associated verifying key.
 ///
 /// This represents a type which holds both a signing key and a verifying key.
@@ -27,3 +30,67 @@ impl<K: KeypairRef> Keypair for K {
         self.as_ref().clone()
     }
 }
+
+/// Generate a fresh signing keypair using the provided randomness source.
+///
+/// This allows code such as test harnesses or provisioning tools to create
+/// keys for any signature algorithm, with the verifying key available via
+/// [`Keypair::verifying_key`].
+///
+/// # Example
+///
+/// ```
+/// use signature::{rand_core::CryptoRngCore, Error, Keypair, KeypairGenerate};
+///
+/// /// Toy keypair whose verifying key is the signing key plus one
+/// struct ToyKeypair {
+///     signing_key: u64,
+/// }
+///
+/// impl Keypair for ToyKeypair {
+///     type VerifyingKey = u64;
+///
+///     fn verifying_key(&self) -> u64 {
+///         self.signing_key.wrapping_add(1)
+///     }
+/// }
+///
+/// impl KeypairGenerate for ToyKeypair {
+///     fn try_generate(rng: &mut impl CryptoRngCore) -> Result<Self, Error> {
+///         let mut bytes = [0u8; 8];
+///         rng.try_fill_bytes(&mut bytes).map_err(|_| Error::new())?;
+///         Ok(Self {
+///             signing_key: u64::from_le_bytes(bytes),
+///         })
+///     }
+/// }
+/// # struct ConstRng;
+/// # impl signature::rand_core::RngCore for ConstRng {
+/// #     fn next_u32(&mut self) -> u32 { 7 }
+/// #     fn next_u64(&mut self) -> u64 { 7 }
+/// #     fn fill_bytes(&mut self, dest: &mut [u8]) { dest.fill(7) }
+/// #     fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), signature::rand_core::Error> {
+/// #         Ok(self.fill_bytes(dest))
+/// #     }
+/// # }
+/// # impl signature::rand_core::CryptoRng for ConstRng {}
+/// # let mut rng = ConstRng;
+///
+/// // e.g. `rand_core::OsRng`
+/// let keypair = ToyKeypair::generate(&mut rng);
+/// assert_eq!(keypair.verifying_key(), keypair.signing_key + 1);
+/// ```
+#[cfg(feature = "rand_core")]
+pub trait KeypairGenerate: Keypair + Sized {
+    /// Generate a new keypair.
+    ///
+    /// Panics in the event of a key generation error.
+    fn generate(rng: &mut impl CryptoRngCore) -> Self {
+        Self::try_generate(rng).expect("key generation failed")
+    }
+
+    /// Attempt to generate a new keypair, returning an error if something
+    /// went wrong, e.g. the RNG failed or an external key store rejected
+    /// the request.
+    fn try_generate(rng: &mut impl CryptoRngCore) -> Result<Self, Error>;
+}
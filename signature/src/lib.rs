@@ -127,7 +127,11 @@
 //!   by computing a cryptographically secure digest of the input message.
 //! - `rand_core`: enables the [`RandomizedSigner`] trait for signature
 //!   systems which rely on a cryptographically secure random number generator
-//!   for security.
+//!   for security, and the [`KeypairGenerate`] trait. It also enables the
+//!   provided methods of [`HedgedSigner`] which draw the additional
+//!   randomness from an RNG.
+//! - `zeroize`: zeroizes the randomness drawn by the provided methods of
+//!   [`HedgedSigner`] after use.
 //!
 //! NOTE: the [`async-signature`] crate contains experimental `async` support
 //! for [`Signer`] and [`DigestSigner`].
@@ -144,16 +148,23 @@ extern crate std;
 
 pub mod hazmat;
 
+mod aggregate;
 mod encoding;
 mod error;
 mod keypair;
+mod policy;
 mod signer;
+mod stream;
+mod threshold;
 mod verifier;
 
 #[cfg(feature = "digest")]
 mod prehash_signature;
 
-pub use crate::{encoding::*, error::*, keypair::*, signer::*, verifier::*};
+pub use crate::{
+    aggregate::*, encoding::*, error::*, keypair::*, policy::*, signer::*, stream::*, threshold::*,
+    verifier::*,
+};
 
 #[cfg(feature = "derive")]
 pub use derive::{Signer, Verifier};
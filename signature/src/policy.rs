@@ -0,0 +1,146 @@
+//! Verification subject to an acceptable-algorithms policy
+
+use crate::{
+    error::{Error, ErrorKind},
+    verifier::Verifier,
+};
+
+/// Properties of a verifying key which a [`PolicyVerifier`] can constrain.
+pub trait VerifyingKeyInfo {
+    /// Size of the key in bits, e.g. the size of the modulus for RSA or of
+    /// the field for ECDSA.
+    fn key_size(&self) -> usize;
+
+    /// Name of the digest algorithm used to verify signatures, e.g.
+    /// `"SHA-256"`, or `None` if there is none.
+    fn digest_algorithm(&self) -> Option<&str> {
+        None
+    }
+}
+
+/// Wrapper which enforces caller-provided constraints on a [`Verifier`],
+/// allowing security-sensitive applications to centralize their policy as to
+/// which algorithms and keys are acceptable.
+///
+/// Signatures are only verified if the key satisfies all of the constraints
+/// of the policy, and rejected with [`ErrorKind::InvalidKey`] or
+/// [`ErrorKind::UnsupportedDigest`] otherwise. The policy is constructed
+/// using a builder-style API, and by default imposes no constraints.
+///
+/// Since this crate doesn't depend on a clock, the expiry of a key is checked
+/// against the time returned by a caller-provided closure, with both given as
+/// timestamps in whichever unit the caller prefers, e.g. seconds since the
+/// Unix epoch.
+#[derive(Clone, Debug)]
+pub struct PolicyVerifier<'a, V, T = fn() -> u64> {
+    /// Wrapped verifier.
+    verifier: V,
+
+    /// Minimum size of the key in bits.
+    min_key_size: usize,
+
+    /// Names of the allowed digest algorithms, if restricted.
+    allowed_digests: Option<&'a [&'a str]>,
+
+    /// Expiry timestamp of the key along with a closure returning the
+    /// current time, if the key expires.
+    expiry: Option<(u64, T)>,
+}
+
+impl<'a, V> PolicyVerifier<'a, V> {
+    /// Wrap the given verifier with a policy which imposes no constraints.
+    pub fn new(verifier: V) -> Self {
+        Self {
+            verifier,
+            min_key_size: 0,
+            allowed_digests: None,
+            expiry: None,
+        }
+    }
+}
+
+impl<'a, V, T> PolicyVerifier<'a, V, T> {
+    /// Require the key to be at least `bits` in size.
+    pub fn min_key_size(mut self, bits: usize) -> Self {
+        self.min_key_size = bits;
+        self
+    }
+
+    /// Require the digest algorithm to be one of the given names, which are
+    /// compared case-insensitively.
+    ///
+    /// Keys whose algorithms don't use a digest are rejected.
+    pub fn allowed_digests(mut self, digests: &'a [&'a str]) -> Self {
+        self.allowed_digests = Some(digests);
+        self
+    }
+
+    /// Reject signatures once the time returned by `now` is past the
+    /// `not_after` timestamp of the key.
+    pub fn expires_at<U>(self, not_after: u64, now: U) -> PolicyVerifier<'a, V, U>
+    where
+        U: Fn() -> u64,
+    {
+        PolicyVerifier {
+            verifier: self.verifier,
+            min_key_size: self.min_key_size,
+            allowed_digests: self.allowed_digests,
+            expiry: Some((not_after, now)),
+        }
+    }
+
+    /// Borrow the wrapped verifier.
+    pub fn inner(&self) -> &V {
+        &self.verifier
+    }
+
+    /// Unwrap the wrapped verifier.
+    pub fn into_inner(self) -> V {
+        self.verifier
+    }
+}
+
+impl<'a, V, T> PolicyVerifier<'a, V, T>
+where
+    V: VerifyingKeyInfo,
+    T: Fn() -> u64,
+{
+    /// Check that the key satisfies the policy, without verifying a
+    /// signature.
+    pub fn check_policy(&self) -> Result<(), Error> {
+        if self.verifier.key_size() < self.min_key_size {
+            return Err(ErrorKind::InvalidKey.into());
+        }
+
+        if let Some(allowed_digests) = self.allowed_digests {
+            let allowed = self.verifier.digest_algorithm().map_or(false, |digest| {
+                allowed_digests
+                    .iter()
+                    .any(|allowed| allowed.eq_ignore_ascii_case(digest))
+            });
+
+            if !allowed {
+                return Err(ErrorKind::UnsupportedDigest.into());
+            }
+        }
+
+        if let Some((not_after, now)) = &self.expiry {
+            if now() > *not_after {
+                return Err(ErrorKind::InvalidKey.into());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, S, V, T> Verifier<S> for PolicyVerifier<'a, V, T>
+where
+    V: Verifier<S> + VerifyingKeyInfo,
+    T: Fn() -> u64,
+{
+    fn verify(&self, msg: &[u8], signature: &S) -> Result<(), Error> {
+        self.check_policy()?;
+        self.verifier.verify(msg, signature)
+    }
+}
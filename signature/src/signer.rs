@@ -48,6 +48,30 @@ impl<S, T: Signer<S>> SignerMut<S> for T {
     }
 }
 
+/// Sign the provided message bytestring along with a context string using
+/// `Self`, returning a digital signature.
+///
+/// The context binds signatures to the protocol or purpose they were
+/// produced for (i.e. domain separation), so that a signature produced for
+/// one context won't verify in another, as supported by e.g. Ed25519ctx,
+/// Ed448 and ML-DSA.
+///
+/// Context strings are typically limited to 255 bytes.
+pub trait ContextSigner<S> {
+    /// Sign the given message with the given context and return a digital
+    /// signature.
+    fn sign_with_context(&self, context: &[u8], msg: &[u8]) -> S {
+        self.try_sign_with_context(context, msg)
+            .expect("signature operation failed")
+    }
+
+    /// Attempt to sign the given message with the given context, returning a
+    /// digital signature on success, or an error if something went wrong.
+    ///
+    /// Returns `Error` if the context is unsupported, e.g. too long.
+    fn try_sign_with_context(&self, context: &[u8], msg: &[u8]) -> Result<S, Error>;
+}
+
 /// Sign the given prehashed message [`Digest`] using `Self`.
 ///
 /// ## Notes
@@ -99,6 +123,69 @@ pub trait RandomizedSigner<S> {
     fn try_sign_with_rng(&self, rng: &mut impl CryptoRngCore, msg: &[u8]) -> Result<S, Error>;
 }
 
+/// Sign the provided message using a deterministically derived nonce which
+/// is hedged with additional caller-provided randomness, e.g. the
+/// additional data `k'` of [RFC 6979 § 3.6].
+///
+/// Mixing randomness into an otherwise deterministic nonce protects against
+/// fault attacks, which can extract the key from two signatures over the
+/// same message, as well as against a weak RNG. When `extra_entropy` is
+/// empty, signatures are fully deterministic, and so remain compatible with
+/// deterministic test vectors.
+///
+/// [RFC 6979 § 3.6]: https://www.rfc-editor.org/rfc/rfc6979#section-3.6
+pub trait HedgedSigner<S> {
+    /// Sign the given message hedged with `extra_entropy`, returning a
+    /// digital signature.
+    ///
+    /// Panics in the event of a signing error.
+    fn sign_hedged(&self, msg: &[u8], extra_entropy: &[u8]) -> S {
+        self.try_sign_hedged(msg, extra_entropy)
+            .expect("signature operation failed")
+    }
+
+    /// Attempt to sign the given message hedged with `extra_entropy`,
+    /// returning a digital signature on success, or an error if something
+    /// went wrong.
+    ///
+    /// Returns `Error` if `extra_entropy` is unsupported, e.g. too long.
+    fn try_sign_hedged(&self, msg: &[u8], extra_entropy: &[u8]) -> Result<S, Error>;
+
+    /// Sign the given message hedged with 32 bytes of randomness drawn from
+    /// the provided RNG, returning a digital signature.
+    ///
+    /// Panics in the event of a signing error.
+    #[cfg(feature = "rand_core")]
+    fn sign_hedged_with_rng(&self, rng: &mut impl CryptoRngCore, msg: &[u8]) -> S {
+        self.try_sign_hedged_with_rng(rng, msg)
+            .expect("signature operation failed")
+    }
+
+    /// Attempt to sign the given message hedged with 32 bytes of randomness
+    /// drawn from the provided RNG, returning a digital signature on success,
+    /// or an error if something went wrong.
+    ///
+    /// When the `zeroize` feature is enabled, the randomness is zeroized
+    /// afterwards.
+    #[cfg(feature = "rand_core")]
+    fn try_sign_hedged_with_rng(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        msg: &[u8],
+    ) -> Result<S, Error> {
+        let mut extra_entropy = [0u8; 32];
+        let result = rng
+            .try_fill_bytes(&mut extra_entropy)
+            .map_err(|_| Error::new())
+            .and_then(|()| self.try_sign_hedged(msg, &extra_entropy));
+
+        #[cfg(feature = "zeroize")]
+        zeroize::Zeroize::zeroize(&mut extra_entropy);
+
+        result
+    }
+}
+
 /// Combination of [`DigestSigner`] and [`RandomizedSigner`] with support for
 /// computing a signature over a digest which requires entropy from an RNG.
 #[cfg(all(feature = "digest", feature = "rand_core"))]
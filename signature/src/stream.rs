@@ -0,0 +1,92 @@
+//! Traits for signing and verifying messages incrementally
+
+use crate::error::Error;
+
+#[cfg(feature = "std")]
+use std::io::{self, Read};
+
+/// Size of the buffer used when reading messages from an [`io::Read`].
+#[cfg(feature = "std")]
+const READ_BUFFER_SIZE: usize = 4096;
+
+/// State of an in-progress streaming signature operation, which the
+/// message is input into incrementally.
+pub trait StreamState {
+    /// Input the next chunk of the message.
+    fn update(&mut self, data: &[u8]);
+}
+
+#[cfg(feature = "digest")]
+impl<D: digest::Update> StreamState for D {
+    fn update(&mut self, data: &[u8]) {
+        digest::Update::update(self, data);
+    }
+}
+
+/// Sign a message which is input incrementally, returning a digital
+/// signature.
+///
+/// This allows signing large messages (e.g. files) without buffering them in
+/// their entirety, as required by [`Signer`][`crate::Signer`].
+pub trait StreamSigner<S> {
+    /// State of an in-progress signing operation.
+    type State: StreamState;
+
+    /// Begin signing a new message.
+    fn begin_sign(&self) -> Self::State;
+
+    /// Attempt to sign the message input into `state`, returning a digital
+    /// signature on success, or an error if something went wrong.
+    fn try_finalize_sign(&self, state: Self::State) -> Result<S, Error>;
+
+    /// Attempt to sign the message read from `reader` until EOF.
+    ///
+    /// I/O errors are returned as the [`Error`]'s source.
+    #[cfg(feature = "std")]
+    fn try_sign_reader(&self, reader: &mut impl Read) -> Result<S, Error> {
+        let mut state = self.begin_sign();
+        read_into(reader, &mut state)?;
+        self.try_finalize_sign(state)
+    }
+}
+
+/// Verify a signature over a message which is input incrementally.
+pub trait StreamVerifier<S> {
+    /// State of an in-progress verification operation.
+    type State: StreamState;
+
+    /// Begin verifying a new message.
+    fn begin_verify(&self) -> Self::State;
+
+    /// Verify that the provided signature for the message input into
+    /// `state` is authentic.
+    ///
+    /// Returns `Error` if it is inauthentic, or otherwise returns `()`.
+    fn finalize_verify(&self, state: Self::State, signature: &S) -> Result<(), Error>;
+
+    /// Verify the provided signature for the message read from `reader`
+    /// until EOF.
+    ///
+    /// I/O errors are returned as the [`Error`]'s source.
+    #[cfg(feature = "std")]
+    fn verify_reader(&self, reader: &mut impl Read, signature: &S) -> Result<(), Error> {
+        let mut state = self.begin_verify();
+        read_into(reader, &mut state)?;
+        self.finalize_verify(state, signature)
+    }
+}
+
+/// Input the contents of `reader` into `state` until EOF.
+#[cfg(feature = "std")]
+fn read_into(reader: &mut impl Read, state: &mut impl StreamState) -> Result<(), Error> {
+    let mut buffer = [0u8; READ_BUFFER_SIZE];
+
+    loop {
+        match reader.read(&mut buffer) {
+            Ok(0) => return Ok(()),
+            Ok(n) => state.update(&buffer[..n]),
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(Error::from_source(e)),
+        }
+    }
+}
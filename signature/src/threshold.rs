@@ -0,0 +1,92 @@
+//! Traits for multi-round threshold signing protocols
+
+use crate::error::Error;
+
+#[cfg(feature = "rand_core")]
+use crate::rand_core::CryptoRngCore;
+
+/// Participant in a threshold signing protocol (e.g. FROST), holding a share
+/// of a signing key, some threshold of which must cooperate to produce a
+/// signature of type `S`.
+///
+/// Signing proceeds in rounds, with the messages exchanged in each round
+/// represented by the associated types of this trait:
+///
+/// 1. [`Commit`]: each participant generates secret [`Nonces`] and
+///    broadcasts the corresponding [`Commitment`].
+/// 2. [`SignRound`]: each participant consumes their nonces to compute a
+///    [`SignatureShare`] over the message, given the commitments of all
+///    participants in the session.
+/// 3. [`ThresholdAggregator`]: the shares are combined into a signature.
+///
+/// [`Nonces`]: ThresholdSigner::Nonces
+/// [`Commitment`]: ThresholdSigner::Commitment
+/// [`SignatureShare`]: ThresholdSigner::SignatureShare
+pub trait ThresholdSigner<S> {
+    /// Identifier of a participant.
+    type Identifier;
+
+    /// Commitment broadcast in the first round.
+    type Commitment;
+
+    /// Secret state carried over from the first round to the second.
+    type Nonces;
+
+    /// Signature share output by the second round.
+    type SignatureShare;
+
+    /// Get the identifier of this participant.
+    fn identifier(&self) -> Self::Identifier;
+}
+
+/// First round of threshold signing: commit to the nonces used to sign.
+#[cfg(feature = "rand_core")]
+pub trait Commit<S>: ThresholdSigner<S> {
+    /// Generate fresh nonces using the provided randomness source, returning
+    /// them along with the commitment to broadcast to the other participants.
+    fn commit(
+        &self,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<(Self::Nonces, Self::Commitment), Error>;
+}
+
+/// Second round of threshold signing: compute a signature share.
+pub trait SignRound<S>: ThresholdSigner<S> {
+    /// Compute this participant's signature share over `msg`, given the
+    /// `commitments` of all participants in the session (including this one).
+    ///
+    /// The nonces are consumed, as reusing them for a different message or
+    /// set of commitments would reveal this participant's key share.
+    fn sign_round(
+        &self,
+        nonces: Self::Nonces,
+        msg: &[u8],
+        commitments: &[(Self::Identifier, Self::Commitment)],
+    ) -> Result<Self::SignatureShare, Error>;
+}
+
+/// Combine signature shares into a signature, using `Self` (e.g. the group
+/// verifying key).
+pub trait ThresholdAggregator<S> {
+    /// Type of the participants whose shares are combined.
+    type Signer: ThresholdSigner<S>;
+
+    /// Combine the signature shares over `msg` produced from the given
+    /// `commitments` into a signature.
+    ///
+    /// Returns `Error` if there are too few shares, they don't correspond to
+    /// the commitments, or any of them is invalid.
+    #[allow(clippy::type_complexity)]
+    fn aggregate(
+        &self,
+        msg: &[u8],
+        commitments: &[(
+            <Self::Signer as ThresholdSigner<S>>::Identifier,
+            <Self::Signer as ThresholdSigner<S>>::Commitment,
+        )],
+        signature_shares: &[(
+            <Self::Signer as ThresholdSigner<S>>::Identifier,
+            <Self::Signer as ThresholdSigner<S>>::SignatureShare,
+        )],
+    ) -> Result<S, Error>;
+}
@@ -14,6 +14,21 @@ pub trait Verifier<S> {
     fn verify(&self, msg: &[u8], signature: &S) -> Result<(), Error>;
 }
 
+/// Verify the provided message bytestring along with a context string using
+/// `Self` (e.g. a public key).
+///
+/// This is the verification counterpart of
+/// [`ContextSigner`][`crate::ContextSigner`]: signatures only verify with the
+/// same context they were produced with.
+pub trait ContextVerifier<S> {
+    /// Use `Self` to verify that the provided signature for a given message
+    /// bytestring and context is authentic.
+    ///
+    /// Returns `Error` if it is inauthentic or the context is unsupported, or
+    /// otherwise returns `()`.
+    fn verify_with_context(&self, context: &[u8], msg: &[u8], signature: &S) -> Result<(), Error>;
+}
+
 /// Verify the provided signature for the given prehashed message [`Digest`]
 /// is authentic.
 ///
@@ -39,3 +54,34 @@ pub trait DigestVerifier<D: Digest, S> {
     /// Verify the signature against the given [`Digest`] output.
     fn verify_digest(&self, digest: D, signature: &S) -> Result<(), Error>;
 }
+
+/// Verify a batch of signatures, each over a message and by a key `Self`
+/// (e.g. a public key).
+///
+/// Signature algorithms which support it (e.g. Ed25519 or Schnorr) can
+/// override [`BatchVerifier::verify_batch`] with an optimized implementation,
+/// e.g. randomized batch verification which checks a random linear
+/// combination of the verification equations. Otherwise, the provided
+/// implementation verifies each signature in turn using [`Verifier`].
+pub trait BatchVerifier<S>: Verifier<S> + Sized {
+    /// Verify that `signatures[i]` is an authentic signature over `msgs[i]`
+    /// by `verifying_keys[i]`, for all `i`.
+    ///
+    /// Returns `Error` if the slices have different lengths or if any of the
+    /// signatures is inauthentic. Note that optimized implementations
+    /// generally can't determine which signature is inauthentic.
+    fn verify_batch(
+        msgs: &[&[u8]],
+        signatures: &[S],
+        verifying_keys: &[Self],
+    ) -> Result<(), Error> {
+        if msgs.len() != signatures.len() || msgs.len() != verifying_keys.len() {
+            return Err(Error::new());
+        }
+
+        msgs.iter()
+            .zip(signatures)
+            .zip(verifying_keys)
+            .try_for_each(|((msg, signature), key)| key.verify(msg, signature))
+    }
+}
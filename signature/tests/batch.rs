@@ -0,0 +1,66 @@
+//! Tests for the provided `BatchVerifier::verify_batch` implementation
+
+use signature::{BatchVerifier, Error, Verifier};
+
+/// Toy signature: the message XORed with the key
+#[derive(Debug)]
+struct ToySignature(Vec<u8>);
+
+/// Toy verifying key
+struct ToyVerifier(u8);
+
+impl ToyVerifier {
+    fn sign(&self, msg: &[u8]) -> ToySignature {
+        ToySignature(msg.iter().map(|byte| byte ^ self.0).collect())
+    }
+}
+
+impl Verifier<ToySignature> for ToyVerifier {
+    fn verify(&self, msg: &[u8], signature: &ToySignature) -> Result<(), Error> {
+        if self.sign(msg).0 == signature.0 {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+impl BatchVerifier<ToySignature> for ToyVerifier {}
+
+fn batch() -> (Vec<&'static [u8]>, Vec<ToySignature>, Vec<ToyVerifier>) {
+    let msgs: Vec<&[u8]> = vec![b"first", b"second", b"third"];
+    let keys = vec![ToyVerifier(1), ToyVerifier(2), ToyVerifier(3)];
+    let signatures = msgs
+        .iter()
+        .zip(&keys)
+        .map(|(msg, key)| key.sign(msg))
+        .collect();
+    (msgs, signatures, keys)
+}
+
+#[test]
+fn verify_batch() {
+    let (msgs, signatures, keys) = batch();
+    assert!(ToyVerifier::verify_batch(&msgs, &signatures, &keys).is_ok());
+    assert!(ToyVerifier::verify_batch(&[], &[], &[]).is_ok());
+}
+
+#[test]
+fn reject_inauthentic_signature() {
+    let (msgs, mut signatures, keys) = batch();
+    signatures[1].0[0] ^= 1;
+    assert!(ToyVerifier::verify_batch(&msgs, &signatures, &keys).is_err());
+
+    // Signatures must be verified by the key at the same index
+    let (msgs, mut signatures, keys) = batch();
+    signatures.swap(0, 2);
+    assert!(ToyVerifier::verify_batch(&msgs, &signatures, &keys).is_err());
+}
+
+#[test]
+fn reject_length_mismatch() {
+    let (msgs, signatures, keys) = batch();
+    assert!(ToyVerifier::verify_batch(&msgs[..2], &signatures, &keys).is_err());
+    assert!(ToyVerifier::verify_batch(&msgs, &signatures[..2], &keys).is_err());
+    assert!(ToyVerifier::verify_batch(&msgs, &signatures, &keys[..2]).is_err());
+}
@@ -0,0 +1,61 @@
+//! Tests for `Error` and `ErrorKind`
+
+use signature::{Error, ErrorKind};
+
+#[test]
+fn kind() {
+    assert_eq!(Error::new().kind(), ErrorKind::Other);
+    assert_eq!(Error::default().kind(), ErrorKind::Other);
+
+    let error = Error::from_kind(ErrorKind::InvalidKey);
+    assert_eq!(error.kind(), ErrorKind::InvalidKey);
+    assert_eq!(
+        Error::from(ErrorKind::InvalidSignature).kind(),
+        ErrorKind::InvalidSignature
+    );
+}
+
+#[test]
+fn kind_as_str() {
+    assert_eq!(ErrorKind::InvalidSignature.to_string(), "invalid signature");
+    assert_eq!(ErrorKind::BackendFailure.as_str(), "backend failure");
+}
+
+#[test]
+fn format_without_source() {
+    // The kind isn't included, so as not to change the output for existing users
+    let error = Error::from_kind(ErrorKind::InvalidSignature);
+    assert_eq!(error.to_string(), "signature error");
+
+    #[cfg(not(feature = "std"))]
+    assert_eq!(format!("{:?}", error), "signature::Error {}");
+
+    #[cfg(feature = "std")]
+    assert_eq!(format!("{:?}", error), "signature::Error { source: None }");
+}
+
+#[cfg(feature = "std")]
+mod source {
+    use super::*;
+    use std::error::Error as _;
+
+    #[test]
+    fn from_source() {
+        let error = Error::from_source("connection refused");
+        assert_eq!(error.kind(), ErrorKind::BackendFailure);
+        assert_eq!(error.to_string(), "signature error: connection refused");
+        assert_eq!(
+            format!("{:?}", error),
+            "signature::Error { source: Some(connection refused) }"
+        );
+        assert_eq!(error.source().unwrap().to_string(), "connection refused");
+    }
+
+    #[test]
+    fn from_source_with_kind() {
+        let error = Error::from_source_with_kind(ErrorKind::InvalidKey, "key is revoked");
+        assert_eq!(error.kind(), ErrorKind::InvalidKey);
+        assert_eq!(error.to_string(), "signature error: key is revoked");
+        assert!(error.source().is_some());
+    }
+}
@@ -0,0 +1,92 @@
+//! Tests for `HedgedSigner`
+
+use signature::{Error, HedgedSigner};
+
+/// Toy signature: an FNV-1a hash of the key, extra entropy, and message
+#[derive(Debug, PartialEq)]
+struct ToySignature(u64);
+
+/// Toy signer which supports up to 32 bytes of extra entropy
+struct ToySigner(u8);
+
+impl HedgedSigner<ToySignature> for ToySigner {
+    fn try_sign_hedged(&self, msg: &[u8], extra_entropy: &[u8]) -> Result<ToySignature, Error> {
+        if extra_entropy.len() > 32 {
+            return Err(Error::new());
+        }
+
+        let input = [&[self.0, extra_entropy.len() as u8], extra_entropy, msg].concat();
+        Ok(ToySignature(
+            input.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+                (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+            }),
+        ))
+    }
+}
+
+#[test]
+fn empty_extra_entropy_is_deterministic() {
+    let signature = ToySigner(1).sign_hedged(b"message", &[]);
+    assert_eq!(ToySigner(1).sign_hedged(b"message", &[]), signature);
+    assert_ne!(ToySigner(1).sign_hedged(b"other message", &[]), signature);
+    assert_ne!(ToySigner(2).sign_hedged(b"message", &[]), signature);
+}
+
+#[test]
+fn extra_entropy_changes_signature() {
+    let signature = ToySigner(1).sign_hedged(b"message", &[]);
+    assert_ne!(ToySigner(1).sign_hedged(b"message", &[0]), signature);
+    assert_ne!(
+        ToySigner(1).sign_hedged(b"message", &[1; 32]),
+        ToySigner(1).sign_hedged(b"message", &[2; 32])
+    );
+    assert!(ToySigner(1).try_sign_hedged(b"message", &[0; 33]).is_err());
+}
+
+#[cfg(feature = "rand_core")]
+mod rng {
+    use super::*;
+    use signature::rand_core::{self, CryptoRng, RngCore};
+
+    /// Deterministic RNG which outputs a constant byte, or fails if it's zero
+    struct ConstRng(u8);
+
+    impl RngCore for ConstRng {
+        fn next_u32(&mut self) -> u32 {
+            rand_core::impls::next_u32_via_fill(self)
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            rand_core::impls::next_u64_via_fill(self)
+        }
+
+        fn fill_bytes(&mut self, dest: &mut [u8]) {
+            self.try_fill_bytes(dest).unwrap()
+        }
+
+        fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+            if self.0 == 0 {
+                return Err(rand_core::Error::from(
+                    core::num::NonZeroU32::new(rand_core::Error::CUSTOM_START).unwrap(),
+                ));
+            }
+
+            dest.fill(self.0);
+            Ok(())
+        }
+    }
+
+    impl CryptoRng for ConstRng {}
+
+    #[test]
+    fn sign_hedged_with_rng() {
+        // Hedged with 32 bytes drawn from the RNG
+        assert_eq!(
+            ToySigner(1).sign_hedged_with_rng(&mut ConstRng(7), b"message"),
+            ToySigner(1).sign_hedged(b"message", &[7; 32])
+        );
+        assert!(ToySigner(1)
+            .try_sign_hedged_with_rng(&mut ConstRng(0), b"message")
+            .is_err());
+    }
+}
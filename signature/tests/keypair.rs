@@ -0,0 +1,81 @@
+//! Tests for the provided `KeypairGenerate::generate` implementation
+
+#![cfg(feature = "rand_core")]
+
+use signature::{
+    rand_core::{self, CryptoRng, CryptoRngCore, RngCore},
+    Error, Keypair, KeypairGenerate,
+};
+
+/// Toy keypair whose verifying key is the signing key plus one
+#[derive(Debug, PartialEq)]
+struct ToyKeypair {
+    signing_key: u64,
+}
+
+impl Keypair for ToyKeypair {
+    type VerifyingKey = u64;
+
+    fn verifying_key(&self) -> u64 {
+        self.signing_key.wrapping_add(1)
+    }
+}
+
+impl KeypairGenerate for ToyKeypair {
+    fn try_generate(rng: &mut impl CryptoRngCore) -> Result<Self, Error> {
+        let mut bytes = [0u8; 8];
+        rng.try_fill_bytes(&mut bytes).map_err(|_| Error::new())?;
+        Ok(Self {
+            signing_key: u64::from_le_bytes(bytes),
+        })
+    }
+}
+
+/// Deterministic RNG which outputs a constant byte, or fails if it's zero
+struct ConstRng(u8);
+
+impl RngCore for ConstRng {
+    fn next_u32(&mut self) -> u32 {
+        rand_core::impls::next_u32_via_fill(self)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        rand_core::impls::next_u64_via_fill(self)
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.try_fill_bytes(dest).unwrap()
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand_core::Error> {
+        if self.0 == 0 {
+            return Err(rand_core::Error::from(
+                core::num::NonZeroU32::new(rand_core::Error::CUSTOM_START).unwrap(),
+            ));
+        }
+
+        dest.fill(self.0);
+        Ok(())
+    }
+}
+
+impl CryptoRng for ConstRng {}
+
+#[test]
+fn generate() {
+    let keypair = ToyKeypair::generate(&mut ConstRng(1));
+    assert_eq!(keypair.signing_key, 0x0101_0101_0101_0101);
+    assert_eq!(keypair.verifying_key(), 0x0101_0101_0101_0102);
+    assert_eq!(ToyKeypair::try_generate(&mut ConstRng(1)).unwrap(), keypair);
+}
+
+#[test]
+fn try_generate_rng_failure() {
+    assert!(ToyKeypair::try_generate(&mut ConstRng(0)).is_err());
+}
+
+#[test]
+#[should_panic(expected = "key generation failed")]
+fn generate_panics_on_rng_failure() {
+    ToyKeypair::generate(&mut ConstRng(0));
+}
@@ -0,0 +1,118 @@
+//! Tests for `PolicyVerifier`
+
+use signature::{Error, ErrorKind, PolicyVerifier, Verifier, VerifyingKeyInfo};
+
+/// Dummy signature which is valid iff it equals the message
+struct DummySignature(Vec<u8>);
+
+/// Dummy verifying key
+struct DummyVerifier {
+    key_size: usize,
+    digest: Option<&'static str>,
+}
+
+impl VerifyingKeyInfo for DummyVerifier {
+    fn key_size(&self) -> usize {
+        self.key_size
+    }
+
+    fn digest_algorithm(&self) -> Option<&str> {
+        self.digest
+    }
+}
+
+impl Verifier<DummySignature> for DummyVerifier {
+    fn verify(&self, msg: &[u8], signature: &DummySignature) -> Result<(), Error> {
+        if msg == signature.0.as_slice() {
+            Ok(())
+        } else {
+            Err(ErrorKind::InvalidSignature.into())
+        }
+    }
+}
+
+/// P-256 with SHA-256
+const P256: DummyVerifier = DummyVerifier {
+    key_size: 256,
+    digest: Some("SHA-256"),
+};
+
+const MSG: &[u8] = b"testing";
+
+fn kind(result: Result<(), Error>) -> ErrorKind {
+    result.unwrap_err().kind()
+}
+
+#[test]
+fn no_constraints() {
+    let verifier = PolicyVerifier::new(P256);
+    assert!(verifier.verify(MSG, &DummySignature(MSG.to_vec())).is_ok());
+    assert_eq!(
+        kind(verifier.verify(MSG, &DummySignature(Vec::new()))),
+        ErrorKind::InvalidSignature
+    );
+}
+
+#[test]
+fn min_key_size() {
+    let signature = DummySignature(MSG.to_vec());
+    assert!(PolicyVerifier::new(P256)
+        .min_key_size(256)
+        .verify(MSG, &signature)
+        .is_ok());
+    assert_eq!(
+        kind(
+            PolicyVerifier::new(P256)
+                .min_key_size(384)
+                .verify(MSG, &signature)
+        ),
+        ErrorKind::InvalidKey
+    );
+}
+
+#[test]
+fn allowed_digests() {
+    let signature = DummySignature(MSG.to_vec());
+    assert!(PolicyVerifier::new(P256)
+        .allowed_digests(&["sha-256", "sha-384"])
+        .verify(MSG, &signature)
+        .is_ok());
+    assert_eq!(
+        kind(
+            PolicyVerifier::new(P256)
+                .allowed_digests(&["SHA-384"])
+                .verify(MSG, &signature)
+        ),
+        ErrorKind::UnsupportedDigest
+    );
+
+    let no_digest = DummyVerifier {
+        key_size: 256,
+        digest: None,
+    };
+    assert_eq!(
+        kind(
+            PolicyVerifier::new(no_digest)
+                .allowed_digests(&["SHA-256"])
+                .verify(MSG, &signature)
+        ),
+        ErrorKind::UnsupportedDigest
+    );
+}
+
+#[test]
+fn expiry() {
+    let signature = DummySignature(MSG.to_vec());
+    assert!(PolicyVerifier::new(P256)
+        .expires_at(100, || 100)
+        .verify(MSG, &signature)
+        .is_ok());
+    assert_eq!(
+        kind(
+            PolicyVerifier::new(P256)
+                .expires_at(100, || 101)
+                .verify(MSG, &signature)
+        ),
+        ErrorKind::InvalidKey
+    );
+}
@@ -0,0 +1,56 @@
+//! Tests for the prehash length checks of `PrehashSigner`
+
+use signature::{hazmat::PrehashSigner, Error, ErrorKind};
+
+/// Dummy signature which contains the signed prehash
+#[derive(Debug, PartialEq)]
+struct DummySignature(Vec<u8>);
+
+/// Dummy signer which expects prehashes of a fixed length
+struct FixedLenSigner;
+
+impl PrehashSigner<DummySignature> for FixedLenSigner {
+    fn sign_prehash(&self, prehash: &[u8]) -> Result<DummySignature, Error> {
+        self.check_prehash_len(prehash)?;
+        Ok(DummySignature(prehash.to_vec()))
+    }
+
+    fn prehash_len(&self) -> Option<usize> {
+        Some(32)
+    }
+}
+
+/// Dummy signer which accepts prehashes of any length
+struct AnyLenSigner;
+
+impl PrehashSigner<DummySignature> for AnyLenSigner {
+    fn sign_prehash(&self, prehash: &[u8]) -> Result<DummySignature, Error> {
+        self.check_prehash_len(prehash)?;
+        Ok(DummySignature(prehash.to_vec()))
+    }
+}
+
+#[test]
+fn check_prehash_len() {
+    assert_eq!(FixedLenSigner.prehash_len(), Some(32));
+    assert!(FixedLenSigner.check_prehash_len(&[0; 32]).is_ok());
+    assert_eq!(
+        FixedLenSigner.sign_prehash(&[1; 32]).unwrap(),
+        DummySignature(vec![1; 32])
+    );
+
+    for len in [0, 31, 33, 64] {
+        let err = FixedLenSigner.sign_prehash(&vec![0; len]).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnsupportedDigest);
+    }
+}
+
+#[test]
+fn any_prehash_len() {
+    assert_eq!(AnyLenSigner.prehash_len(), None);
+
+    for len in [0, 20, 32, 64] {
+        assert!(AnyLenSigner.check_prehash_len(&vec![0; len]).is_ok());
+        assert!(AnyLenSigner.sign_prehash(&vec![0; len]).is_ok());
+    }
+}
@@ -0,0 +1,134 @@
+//! Tests for the `io::Read` adapters of `StreamSigner` and `StreamVerifier`
+
+#![cfg(feature = "std")]
+
+use signature::{Error, StreamSigner, StreamState, StreamVerifier};
+use std::io::{self, Read};
+
+/// Dummy signature which contains the signed message
+#[derive(Debug, PartialEq)]
+struct DummySignature(Vec<u8>);
+
+/// State which records the message input into it
+#[derive(Default)]
+struct Recorder(Vec<u8>);
+
+impl StreamState for Recorder {
+    fn update(&mut self, data: &[u8]) {
+        self.0.extend_from_slice(data);
+    }
+}
+
+/// Dummy key for which signatures are valid iff they equal the message
+struct DummyKey;
+
+impl StreamSigner<DummySignature> for DummyKey {
+    type State = Recorder;
+
+    fn begin_sign(&self) -> Recorder {
+        Recorder::default()
+    }
+
+    fn try_finalize_sign(&self, state: Recorder) -> Result<DummySignature, Error> {
+        Ok(DummySignature(state.0))
+    }
+}
+
+impl StreamVerifier<DummySignature> for DummyKey {
+    type State = Recorder;
+
+    fn begin_verify(&self) -> Recorder {
+        Recorder::default()
+    }
+
+    fn finalize_verify(&self, state: Recorder, signature: &DummySignature) -> Result<(), Error> {
+        if state.0 == signature.0 {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+/// Reader which returns at most 3 bytes at a time, is interrupted before
+/// every read, and fails once `fail_at` bytes were read, if set.
+struct Flaky {
+    data: Vec<u8>,
+    pos: usize,
+    interrupted: bool,
+    fail_at: Option<usize>,
+}
+
+impl Flaky {
+    fn new(data: &[u8], fail_at: Option<usize>) -> Self {
+        Self {
+            data: data.to_vec(),
+            pos: 0,
+            interrupted: false,
+            fail_at,
+        }
+    }
+}
+
+impl Read for Flaky {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.interrupted = !self.interrupted;
+        if self.interrupted {
+            return Err(io::ErrorKind::Interrupted.into());
+        }
+
+        if self.fail_at.map_or(false, |fail_at| self.pos >= fail_at) {
+            return Err(io::Error::new(io::ErrorKind::Other, "disk on fire"));
+        }
+
+        let n = buf.len().min(3).min(self.data.len() - self.pos);
+        buf[..n].copy_from_slice(&self.data[self.pos..self.pos + n]);
+        self.pos += n;
+        Ok(n)
+    }
+}
+
+/// Message which is longer than the internal read buffer
+fn message() -> Vec<u8> {
+    (0..10_000u32).map(|i| i as u8).collect()
+}
+
+#[test]
+fn sign_and_verify_reader() {
+    let msg = message();
+    let signature = DummyKey.try_sign_reader(&mut msg.as_slice()).unwrap();
+    assert_eq!(signature, DummySignature(msg.clone()));
+    assert!(DummyKey
+        .verify_reader(&mut msg.as_slice(), &signature)
+        .is_ok());
+    assert!(DummyKey.verify_reader(&mut &msg[1..], &signature).is_err());
+}
+
+#[test]
+fn retry_interrupted_reads() {
+    let msg = b"hello, world";
+    let signature = DummyKey
+        .try_sign_reader(&mut Flaky::new(msg, None))
+        .unwrap();
+    assert_eq!(signature, DummySignature(msg.to_vec()));
+
+    assert!(DummyKey
+        .verify_reader(&mut Flaky::new(msg, None), &signature)
+        .is_ok());
+}
+
+#[test]
+fn io_error_is_source() {
+    use std::error::Error as _;
+
+    let msg = b"hello, world";
+    let err = DummyKey
+        .try_sign_reader(&mut Flaky::new(msg, Some(6)))
+        .unwrap_err();
+    assert_eq!(err.source().unwrap().to_string(), "disk on fire");
+
+    let signature = DummySignature(msg.to_vec());
+    assert!(DummyKey
+        .verify_reader(&mut Flaky::new(msg, Some(6)), &signature)
+        .is_err());
+}
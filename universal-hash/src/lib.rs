@@ -159,6 +159,56 @@ pub trait UniversalHash: BlockSizeUser + Sized {
     }
 }
 
+/// Universal hash functions which can be reset to their initial state under
+/// a new key, e.g. to authenticate the next packet of a connection.
+///
+/// The default implementation replaces `self` with a newly initialized
+/// instance. Implementations may override it to reuse precomputed state,
+/// e.g. key-independent tables.
+pub trait ResetWithKey: KeyInit {
+    /// Reset the state and replace the key with `key`.
+    #[inline]
+    fn reset_with_key(&mut self, key: &Key<Self>) {
+        *self = Self::new(key);
+    }
+}
+
+/// Universal hash functions which can process several independent messages
+/// at once, e.g. many small packets on a QUIC server.
+///
+/// Backends supporting interleaved processing of multiple states (e.g. using
+/// SIMD) can override the default implementation, which processes the
+/// messages one after another.
+pub trait UniversalHashBatch: UniversalHash {
+    /// Input each message into the corresponding hasher, padding it as in
+    /// [`UniversalHash::update_padded`].
+    ///
+    /// # Panics
+    /// If `hashers` and `messages` have different lengths.
+    #[inline]
+    fn update_padded_batch(hashers: &mut [Self], messages: &[&[u8]]) {
+        assert_eq!(hashers.len(), messages.len());
+
+        for (hasher, message) in hashers.iter_mut().zip(messages) {
+            hasher.update_padded(message);
+        }
+    }
+
+    /// Input each slice of blocks into the corresponding hasher, as in
+    /// [`UniversalHash::update`].
+    ///
+    /// # Panics
+    /// If `hashers` and `blocks` have different lengths.
+    #[inline]
+    fn update_batch(hashers: &mut [Self], blocks: &[&[Block<Self>]]) {
+        assert_eq!(hashers.len(), blocks.len());
+
+        for (hasher, blocks) in hashers.iter_mut().zip(blocks) {
+            hasher.update(blocks);
+        }
+    }
+}
+
 /// Error type used by the [`UniversalHash::verify`] method
 /// to indicate that UHF output is not equal the expected value.
 #[derive(Default, Debug, Copy, Clone, Eq, PartialEq)]